@@ -1,18 +1,31 @@
 use actix_cors::Cors;
 use actix_web::{middleware, web, App, HttpResponse, HttpServer, Responder};
-use host::{generate_pong_proof, verify_pong_proof, PongProof, ReceiptKind};
-use pong_core::CompactLog;
+use host::{
+    compress_pong_proof, generate_pong_proof_with_rules, verify_pong_proof, PongProof, ReceiptKind,
+};
+use pong_core::{decode_binary_log, CompactLog, Ruleset};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 // Request/Response types
 
 #[derive(Deserialize)]
 struct ProveRequest {
-    /// The compact game log to validate
-    log: CompactLog,
+    /// The compact game log to validate (JSON encoding)
+    #[serde(default)]
+    log: Option<CompactLog>,
+    /// The compact game log in the binary encoding, hex encoded
+    #[serde(default)]
+    binary_log: Option<String>,
     /// Optional receipt format (defaults to succinct)
     #[serde(default)]
     receipt_kind: Option<ReceiptKind>,
+    /// Optional ruleset, e.g. `{"max_paddle_accel": 1500, "reaction_ms": 150}` (defaults to standard);
+    /// `scoring` selects the match format (defaults to first to 3)
+    #[serde(default)]
+    rules: Option<Ruleset>,
 }
 
 #[derive(Debug, Serialize)]
@@ -42,19 +55,146 @@ struct VerifyResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct UpgradeRequest {
+    /// The proof whose receipt should be compressed
+    proof: PongProof,
+    /// Target receipt format (succinct or groth16)
+    to: ReceiptKind,
+}
+
+#[derive(Debug, Serialize)]
+struct UpgradeResponse {
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum JobStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct UpgradeJob {
+    job_id: String,
+    status: JobStatus,
+    game_id: u32,
+    from: ReceiptKind,
+    to: ReceiptKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<PongProof>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    /// When the job completed or failed
+    #[serde(skip)]
+    finished_at: Option<Instant>,
+}
+
+/// How long a finished job is kept
+const FINISHED_JOB_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// In-memory store of receipt upgrade jobs
+///
+/// Job IDs are 128 random bits, hex encoded, so the ID handed to the client
+/// is what keeps other clients from reading or guessing its result. A
+/// finished job can be polled any number of times until it is removed after
+/// [`FINISHED_JOB_TTL`], so a long-running server does not grow without bound.
+#[derive(Default)]
+struct UpgradeJobs {
+    jobs: Mutex<HashMap<String, UpgradeJob>>,
+}
+
+impl UpgradeJobs {
+    /// A fresh, unguessable job ID
+    fn new_id() -> Result<String, String> {
+        let mut id = [0u8; 16];
+        getrandom::getrandom(&mut id).map_err(|e| format!("Failed to generate a job ID: {}", e))?;
+        Ok(hex::encode(id))
+    }
+
+    /// Register a new running job
+    fn insert(&self, job: UpgradeJob) {
+        let mut jobs = self.jobs.lock().unwrap();
+        Self::expire(&mut jobs);
+        jobs.insert(job.job_id.clone(), job);
+    }
+
+    /// The job's current state
+    fn get(&self, job_id: &str) -> Option<UpgradeJob> {
+        let mut jobs = self.jobs.lock().unwrap();
+        Self::expire(&mut jobs);
+        jobs.get(job_id).cloned()
+    }
+
+    /// Drop finished jobs older than the TTL
+    fn expire(jobs: &mut HashMap<String, UpgradeJob>) {
+        jobs.retain(|_, job| job.finished_at.is_none_or(|at| at.elapsed() < FINISHED_JOB_TTL));
+    }
+
+    fn update(&self, job_id: &str, result: Result<PongProof, String>) {
+        let mut jobs = self.jobs.lock().unwrap();
+        if let Some(job) = jobs.get_mut(job_id) {
+            job.finished_at = Some(Instant::now());
+            match result {
+                Ok(proof) => {
+                    job.status = JobStatus::Completed;
+                    job.proof = Some(proof);
+                }
+                Err(e) => {
+                    job.status = JobStatus::Failed;
+                    job.error = Some(e);
+                }
+            }
+        }
+    }
+}
+
 // API Handlers
 
+impl ProveRequest {
+    /// The request's log, whichever encoding it was sent in
+    fn compact_log(&self) -> Result<CompactLog, String> {
+        match (&self.log, &self.binary_log) {
+            (Some(log), None) => Ok(log.clone()),
+            (None, Some(encoded)) => {
+                let bytes = hex::decode(encoded).map_err(|e| format!("Invalid binary_log hex: {}", e))?;
+                decode_binary_log(&bytes).map_err(|e| format!("Invalid binary_log: {}", e))
+            }
+            _ => Err("Exactly one of 'log' or 'binary_log' must be provided".to_string()),
+        }
+    }
+}
+
 /// POST /api/prove
 /// Generate a proof for a pong game log
 async fn prove(req: web::Json<ProveRequest>) -> impl Responder {
     let receipt_kind = req.receipt_kind.unwrap_or_default();
+    let rules = req.rules.unwrap_or_default();
+    let log = match req.compact_log() {
+        Ok(log) => log,
+        Err(e) => {
+            tracing::error!("Rejected prove request: {}", e);
+            return HttpResponse::BadRequest().json(ProveResponse {
+                success: false,
+                proof: None,
+                error: Some(e),
+            });
+        }
+    };
     tracing::info!(
-        "Received prove request for game: {}, receipt_kind: {}",
-        req.log.game_id,
-        receipt_kind
+        "Received prove request for game: {}, receipt_kind: {}, rules: {}",
+        log.game_id,
+        receipt_kind,
+        rules
     );
 
-    match generate_pong_proof(&req.log, receipt_kind) {
+    match generate_pong_proof_with_rules(&log, receipt_kind, rules) {
         Ok(proof) => {
             tracing::info!(
                 "Successfully generated proof for game: {} (fair: {})",
@@ -120,6 +260,95 @@ async fn verify(req: web::Json<VerifyRequest>) -> impl Responder {
     }
 }
 
+/// POST /api/upgrade
+/// Start a job that compresses an existing proof to a smaller receipt kind
+async fn upgrade(
+    jobs: web::Data<UpgradeJobs>,
+    req: web::Json<UpgradeRequest>,
+) -> impl Responder {
+    let UpgradeRequest { proof, to } = req.into_inner();
+    tracing::info!(
+        "Received upgrade request for game: {}, {} -> {}",
+        proof.game_id,
+        proof.receipt_kind,
+        to
+    );
+
+    // Reject impossible upgrades up front instead of creating a failed job
+    if to <= proof.receipt_kind {
+        return HttpResponse::BadRequest().json(UpgradeResponse {
+            success: false,
+            job_id: None,
+            error: Some(format!(
+                "Cannot compress a {} receipt to {}",
+                proof.receipt_kind, to
+            )),
+        });
+    }
+
+    let job_id = match UpgradeJobs::new_id() {
+        Ok(job_id) => job_id,
+        Err(e) => {
+            tracing::error!("{}", e);
+            return HttpResponse::InternalServerError().json(UpgradeResponse {
+                success: false,
+                job_id: None,
+                error: Some(e),
+            });
+        }
+    };
+    jobs.insert(UpgradeJob {
+        job_id: job_id.clone(),
+        status: JobStatus::Running,
+        game_id: proof.game_id,
+        from: proof.receipt_kind,
+        to,
+        proof: None,
+        error: None,
+        finished_at: None,
+    });
+
+    // Compression is CPU bound; run it on the blocking pool and record the result
+    let jobs = jobs.clone();
+    let id = job_id.clone();
+    actix_web::rt::spawn(async move {
+        let result = web::block(move || {
+            compress_pong_proof(&proof, to).map_err(|e| e.to_string())
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.to_string()));
+
+        match &result {
+            Ok(_) => tracing::info!("Upgrade job {} completed", id),
+            Err(e) => tracing::error!("Upgrade job {} failed: {}", id, e),
+        }
+        jobs.update(&id, result);
+    });
+
+    HttpResponse::Accepted().json(UpgradeResponse {
+        success: true,
+        job_id: Some(job_id),
+        error: None,
+    })
+}
+
+/// GET /api/upgrade/{job_id}
+/// Poll the status of a receipt upgrade job (a finished job is kept until it expires)
+async fn upgrade_status(
+    jobs: web::Data<UpgradeJobs>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let job_id = path.into_inner();
+    match jobs.get(&job_id) {
+        Some(job) => HttpResponse::Ok().json(job),
+        None => HttpResponse::NotFound().json(UpgradeResponse {
+            success: false,
+            job_id: Some(job_id.clone()),
+            error: Some(format!("Unknown upgrade job: {}", job_id)),
+        }),
+    }
+}
+
 /// GET /health
 /// Health check endpoint
 async fn health() -> impl Responder {
@@ -144,7 +373,9 @@ async fn main() -> std::io::Result<()> {
     let bind_address = "0.0.0.0:8080";
     tracing::info!("Binding to {}", bind_address);
 
-    HttpServer::new(|| {
+    let upgrade_jobs = web::Data::new(UpgradeJobs::default());
+
+    HttpServer::new(move || {
         // Configure CORS to allow all origins
         let cors = Cors::default()
             .allow_any_origin()
@@ -156,10 +387,13 @@ async fn main() -> std::io::Result<()> {
         App::new()
             .wrap(cors)
             .wrap(middleware::Logger::default())
+            .app_data(upgrade_jobs.clone())
             .app_data(web::JsonConfig::default().limit(10_485_760)) // 10MB limit
             .route("/health", web::get().to(health))
             .route("/api/prove", web::post().to(prove))
             .route("/api/verify", web::post().to(verify))
+            .route("/api/upgrade", web::post().to(upgrade))
+            .route("/api/upgrade/{job_id}", web::get().to(upgrade_status))
     })
     .bind(bind_address)?
     .run()
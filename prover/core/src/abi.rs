@@ -0,0 +1,270 @@
+// ABI-stable journal layout for smart-contract consumers
+//
+// The pong guest commits [`ValidateLogOutput`] with risc0 serde: little-endian
+// words, an optional reason string and variable-length game breakdowns. That
+// is easy to decode with risc0 and awkward anywhere else. [`AbiJournal`] is a
+// fixed 93-byte layout of the fields a contract needs, big-endian, committed
+// by the ABI journal guest as the raw journal bytes. The journal digest in
+// the receipt claim is therefore `SHA-256(AbiJournal::encode())`, which a
+// contract can recompute from the fields it was given before checking a seal.
+//
+// | Offset | Size | Field |
+// |--------|------|-------|
+// | 0 | 4 | `version` ([`ABI_JOURNAL_VERSION`]) |
+// | 4 | 1 | `fair` (0 or 1) |
+// | 5 | 4 | `error_code` (see [`error_code`]) |
+// | 9 | 4 | `left_score` |
+// | 13 | 4 | `right_score` |
+// | 17 | 4 | `events_len` |
+// | 21 | 32 | `log_hash` |
+// | 53 | 4 | `game_id` |
+// | 57 | 32 | `config_digest` (see [`config_digest`]) |
+// | 89 | 1 | `outcome`: 0 none, 1 left win, 2 right win, 3 draw |
+// | 90 | 1 | `end_reason`: 0 none (rejected), 1 score, 2 clock, 3 abandoned |
+// | 91 | 1 | `games_won`: 1 if the scores count games won (best-of-N), 0 if points |
+// | 92 | 1 | `violator`: 0 none, 1 left, 2 right, 3 both |
+//
+// A fair journal is only a completed match if `end_reason` is score or
+// clock. An abandoned one proves only its valid prefix, which ended at the
+// violation in `error_code` by the paddle in `violator`, or at the end of
+// the log (both 0): a contract that awards forfeits reads them from there.
+//
+// The layout is frozen for a given version: fields are only ever added in a
+// new version, and error codes are only ever appended.
+
+use crate::reason::*;
+use crate::{EndReason, Outcome, Ruleset, ValidateLogOutput, Violator};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Layout version written in the first field of every ABI journal
+pub const ABI_JOURNAL_VERSION: u32 = 1;
+
+/// Length of an encoded ABI journal in bytes
+pub const ABI_JOURNAL_LEN: usize = 93;
+
+/// Domain separation prefix for [`config_digest`]
+pub const CONFIG_DIGEST_PREFIX: &[u8; 9] = b"PONGCFGv1";
+
+/// Error code of a reason the guest does not know about (never committed by a released guest)
+pub const ERROR_UNKNOWN: u32 = u32::MAX;
+
+/// Reasons the pong guest commits, by error code: reason `i` has code `i + 1`
+/// (code 0 means no reason). Append only.
+///
+/// The entries are the constants the guest commits (see `reason.rs`), so a
+/// reworded reason keeps its code.
+pub const ERROR_REASONS: [&str; 21] = [
+    REASON_TOO_MANY_EVENTS,
+    REASON_UNSUPPORTED_LOG_VERSION,
+    REASON_INVALID_RULESET,
+    REASON_POINTS_TO_WIN,
+    REASON_WIN_BY,
+    REASON_BEST_OF,
+    REASON_DRAW_WITHOUT_CLOCK,
+    REASON_NO_EVENTS,
+    REASON_ODD_EVENTS,
+    REASON_ZERO_VELOCITY,
+    REASON_INVALID_KINEMATICS,
+    REASON_PADDLE_TOO_FAST,
+    REASON_PADDLE_OUT_OF_BOUNDS,
+    REASON_REACTION_DELAY,
+    REASON_PADDLE_ACCEL,
+    REASON_GOLDEN_POINT_MISSING,
+    REASON_MATCH_NOT_WON,
+    REASON_BALL_TOO_FAST,
+    REASON_INVALID_DISCLOSURE,
+    REASON_UNSALTED_DISCLOSURE,
+    REASON_NO_WINNER,
+];
+
+/// Error code of a journal reason: 0 for none, [`ERROR_UNKNOWN`] if it is not in [`ERROR_REASONS`]
+///
+/// A fair journal may carry a reason too: the violation that ended an
+/// abandoned match's valid prefix.
+pub fn error_code(reason: Option<&str>) -> u32 {
+    match reason {
+        None => 0,
+        Some(reason) => ERROR_REASONS
+            .iter()
+            .position(|&r| r == reason)
+            .map_or(ERROR_UNKNOWN, |i| i as u32 + 1),
+    }
+}
+
+/// Reason for an error code (None for 0 and unknown codes)
+pub fn error_reason(code: u32) -> Option<&'static str> {
+    let index = code.checked_sub(1)? as usize;
+    ERROR_REASONS.get(index).copied()
+}
+
+/// SHA-256 over the log version and ruleset words, big-endian, with [`CONFIG_DIGEST_PREFIX`]
+///
+/// The ruleset words are [`Ruleset::to_words`]. A contract that accepts one
+/// configuration stores its digest and compares it.
+pub fn config_digest(log_version: u32, rules: &Ruleset) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(CONFIG_DIGEST_PREFIX);
+    hasher.update(log_version.to_be_bytes());
+    for word in rules.to_words() {
+        hasher.update(word.to_be_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Journal of the ABI journal guest (see the layout above)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbiJournal {
+    pub version: u32,
+    /// Whether the log was fair (for an abandoned match, its valid prefix)
+    pub fair: bool,
+    pub error_code: u32,
+    pub left_score: u32,
+    pub right_score: u32,
+    pub events_len: u32,
+    pub log_hash: [u8; 32],
+    pub game_id: u32,
+    pub config_digest: [u8; 32],
+    /// Winner, or a draw (None if rejected or abandoned)
+    pub outcome: Option<Outcome>,
+    /// Why the match ended (None if rejected)
+    pub end_reason: Option<EndReason>,
+    /// Whether `left_score`/`right_score` count games won (best-of-N) rather than points
+    pub games_won: bool,
+    /// Whose violation ended an abandoned match's valid prefix
+    pub violator: Option<Violator>,
+}
+
+pub(crate) fn outcome_byte(outcome: Option<Outcome>) -> u8 {
+    match outcome {
+        None => 0,
+        Some(Outcome::LeftWin) => 1,
+        Some(Outcome::RightWin) => 2,
+        Some(Outcome::Draw) => 3,
+    }
+}
+
+pub(crate) fn end_reason_byte(end_reason: Option<EndReason>) -> u8 {
+    match end_reason {
+        None => 0,
+        Some(EndReason::Score) => 1,
+        Some(EndReason::Clock) => 2,
+        Some(EndReason::Abandoned) => 3,
+    }
+}
+
+pub(crate) fn violator_byte(violator: Option<Violator>) -> u8 {
+    match violator {
+        None => 0,
+        Some(Violator::Left) => 1,
+        Some(Violator::Right) => 2,
+        Some(Violator::Both) => 3,
+    }
+}
+
+impl AbiJournal {
+    /// The ABI fields of a validation output
+    pub fn from_output(out: &ValidateLogOutput) -> Self {
+        Self {
+            version: ABI_JOURNAL_VERSION,
+            fair: out.fair,
+            error_code: error_code(out.reason.as_deref()),
+            left_score: out.left_score,
+            right_score: out.right_score,
+            events_len: out.events_len,
+            log_hash: out.log_hash_sha256,
+            game_id: out.game_id,
+            config_digest: config_digest(out.log_version, &out.rules),
+            outcome: out.outcome,
+            end_reason: out.end_reason,
+            games_won: out.rules.scoring.best_of > 1,
+            violator: out.violator,
+        }
+    }
+
+    /// Journal bytes, exactly as the guest commits them
+    pub fn encode(&self) -> [u8; ABI_JOURNAL_LEN] {
+        let mut bytes = [0u8; ABI_JOURNAL_LEN];
+        bytes[0..4].copy_from_slice(&self.version.to_be_bytes());
+        bytes[4] = self.fair as u8;
+        bytes[5..9].copy_from_slice(&self.error_code.to_be_bytes());
+        bytes[9..13].copy_from_slice(&self.left_score.to_be_bytes());
+        bytes[13..17].copy_from_slice(&self.right_score.to_be_bytes());
+        bytes[17..21].copy_from_slice(&self.events_len.to_be_bytes());
+        bytes[21..53].copy_from_slice(&self.log_hash);
+        bytes[53..57].copy_from_slice(&self.game_id.to_be_bytes());
+        bytes[57..89].copy_from_slice(&self.config_digest);
+        bytes[89] = outcome_byte(self.outcome);
+        bytes[90] = end_reason_byte(self.end_reason);
+        bytes[91] = self.games_won as u8;
+        bytes[92] = violator_byte(self.violator);
+        bytes
+    }
+
+    /// Decode journal bytes, rejecting other lengths, versions and out of range flags
+    pub fn decode(bytes: &[u8]) -> Result<Self, &'static str> {
+        let bytes: &[u8; ABI_JOURNAL_LEN] = bytes.try_into().map_err(|_| "ABI journal has the wrong length")?;
+        let word = |at: usize| u32::from_be_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+        let hash = |at: usize| {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&bytes[at..at + 32]);
+            hash
+        };
+
+        let version = word(0);
+        if version != ABI_JOURNAL_VERSION {
+            return Err("Unsupported ABI journal version");
+        }
+        let fair = match bytes[4] {
+            0 => false,
+            1 => true,
+            _ => return Err("ABI journal fair flag must be 0 or 1"),
+        };
+        let outcome = match bytes[89] {
+            0 => None,
+            1 => Some(Outcome::LeftWin),
+            2 => Some(Outcome::RightWin),
+            3 => Some(Outcome::Draw),
+            _ => return Err("ABI journal outcome must be 0 to 3"),
+        };
+        let end_reason = match bytes[90] {
+            0 => None,
+            1 => Some(EndReason::Score),
+            2 => Some(EndReason::Clock),
+            3 => Some(EndReason::Abandoned),
+            _ => return Err("ABI journal end reason must be 0 to 3"),
+        };
+        let games_won = match bytes[91] {
+            0 => false,
+            1 => true,
+            _ => return Err("ABI journal games_won flag must be 0 or 1"),
+        };
+        let violator = match bytes[92] {
+            0 => None,
+            1 => Some(Violator::Left),
+            2 => Some(Violator::Right),
+            3 => Some(Violator::Both),
+            _ => return Err("ABI journal violator must be 0 to 3"),
+        };
+        Ok(Self {
+            version,
+            fair,
+            error_code: word(5),
+            left_score: word(9),
+            right_score: word(13),
+            events_len: word(17),
+            log_hash: hash(21),
+            game_id: word(53),
+            config_digest: hash(57),
+            outcome,
+            end_reason,
+            games_won,
+            violator,
+        })
+    }
+
+    /// Journal digest as it appears in the receipt claim: SHA-256 of [`encode`](Self::encode)
+    pub fn digest(&self) -> [u8; 32] {
+        Sha256::digest(self.encode()).into()
+    }
+}
@@ -0,0 +1,178 @@
+// Batch aggregation: one receipt for many games, with a Merkle root over their results
+//
+// The aggregation guest verifies each game's receipt by composition and
+// commits the Merkle root of their [`GameLeaf`]s. A verifier checks the one
+// aggregate receipt, then any single game against the root with its
+// [`InclusionProof`], without touching the other receipts.
+//
+// Leaves and nodes are SHA-256 with distinct prefixes. A node without a
+// sibling (the last one on a level of odd length) is carried up unchanged
+// rather than paired with itself, which would give `[a, b, c]` and
+// `[a, b, c, c]` the same root.
+
+use crate::abi::{end_reason_byte, violator_byte};
+use crate::{EndReason, ProvenGame, ValidateLogOutput, Violator};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Domain separation prefix for [`GameLeaf::hash`]
+pub const LEAF_HASH_PREFIX: &[u8; 9] = b"PONGLEFv2";
+
+/// Domain separation prefix for interior Merkle nodes
+pub const NODE_HASH_PREFIX: &[u8; 9] = b"PONGNODv1";
+
+/// The part of a game's journal committed to in an aggregate
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameLeaf {
+    pub game_id: u32,
+    pub log_hash: [u8; 32],
+    pub left_score: u32,
+    pub right_score: u32,
+    /// Whether the log was fair (for an abandoned game, its valid prefix)
+    pub fair: bool,
+    /// Why the game ended; an abandoned game's scores are where it stopped, with no winner
+    pub end_reason: Option<EndReason>,
+    /// Whose violation ended an abandoned game's valid prefix
+    pub violator: Option<Violator>,
+}
+
+impl GameLeaf {
+    /// Leaf for a game's journal
+    pub fn from_output(out: &ValidateLogOutput) -> Self {
+        Self {
+            game_id: out.game_id,
+            log_hash: out.log_hash_sha256,
+            left_score: out.left_score,
+            right_score: out.right_score,
+            fair: out.fair,
+            end_reason: out.end_reason,
+            violator: out.violator,
+        }
+    }
+
+    /// SHA-256 of the leaf fields, little-endian, with [`LEAF_HASH_PREFIX`]
+    pub fn hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(LEAF_HASH_PREFIX);
+        hasher.update(self.game_id.to_le_bytes());
+        hasher.update(self.log_hash);
+        hasher.update(self.left_score.to_le_bytes());
+        hasher.update(self.right_score.to_le_bytes());
+        hasher.update([self.fair as u8]);
+        hasher.update([end_reason_byte(self.end_reason), violator_byte(self.violator)]);
+        hasher.finalize().into()
+    }
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(NODE_HASH_PREFIX);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Hash one level of the tree into the next
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [last] => *last,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Merkle root over leaf hashes (None if there are none)
+pub fn merkle_root(leaves: &[[u8; 32]]) -> Option<[u8; 32]> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied()
+}
+
+/// Proof that one game is part of an aggregate
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct InclusionProof {
+    pub leaf: GameLeaf,
+    /// Position of the leaf among the aggregate's games
+    pub index: u32,
+    /// Number of games in the aggregate
+    pub leaves: u32,
+    /// Sibling hashes from the leaf up (levels where the node has no sibling are skipped)
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl InclusionProof {
+    /// Inclusion proof for leaf `index` of `leaves`
+    pub fn new(leaves: &[GameLeaf], index: usize) -> Option<Self> {
+        let leaf = *leaves.get(index)?;
+        let mut level: Vec<[u8; 32]> = leaves.iter().map(GameLeaf::hash).collect();
+        let mut i = index;
+        let mut siblings = Vec::new();
+        while level.len() > 1 {
+            let sibling = i ^ 1;
+            if sibling < level.len() {
+                siblings.push(level[sibling]);
+            }
+            level = next_level(&level);
+            i /= 2;
+        }
+        Some(Self {
+            leaf,
+            index: index as u32,
+            leaves: leaves.len() as u32,
+            siblings,
+        })
+    }
+
+    /// Whether this proof places its leaf under `root`
+    pub fn verify(&self, root: &[u8; 32]) -> bool {
+        if self.index >= self.leaves {
+            return false;
+        }
+        let mut hash = self.leaf.hash();
+        let mut i = self.index;
+        let mut len = self.leaves;
+        let mut siblings = self.siblings.iter();
+        while len > 1 {
+            if i ^ 1 < len {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                hash = if i.is_multiple_of(2) {
+                    node_hash(&hash, sibling)
+                } else {
+                    node_hash(sibling, &hash)
+                };
+            }
+            i /= 2;
+            len = len.div_ceil(2);
+        }
+        siblings.next().is_none() && hash == *root
+    }
+}
+
+/// Public output of an aggregate proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggregateOutput {
+    /// [`merkle_root`] of the games' [`GameLeaf`] hashes, in input order
+    pub root: [u8; 32],
+    /// Number of games aggregated
+    pub games: u32,
+    /// Pong guest image IDs the games were verified against; a verifier
+    /// must check these are releases it trusts
+    pub pong_image_ids: Vec<[u32; 8]>,
+}
+
+/// Input to the aggregation guest (serde)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AggregateInput {
+    /// Pong guest image IDs the games may have been proven by
+    pub pong_image_ids: Vec<[u32; 8]>,
+    /// Games to aggregate, each at most once
+    pub games: Vec<ProvenGame>,
+}
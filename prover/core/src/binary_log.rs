@@ -0,0 +1,139 @@
+// Binary compact log encoding
+//
+// A transport encoding of `CompactLog`, independent of the log version `v`
+// (which selects the physics revision) it carries.
+//
+// ## Layout
+// - magic:    4 bytes, "PONG"
+// - format:   u8, binary format version (1)
+// - v:        u8, log version of the encoded `CompactLog`
+// - game_id:  u32 little-endian
+// - count:    varint, number of events
+// - events:   `count` zigzag varints
+//
+// Events alternate left/right paddle positions exactly as in the JSON event array.
+// Each one is stored as the difference from the previous position of the same
+// paddle (starting from 0), so a paddle that does not move costs one byte and
+// small moves cost two or three instead of a quoted 8-digit decimal string.
+//
+// Deltas use wrapping arithmetic, which makes the encoding lossless for every
+// i64 even though valid paddle positions fit comfortably in 32 bits. Decoding
+// yields canonical decimal strings, so a JSON log round-trips byte-for-byte as
+// long as its event strings were canonical to begin with (as the frontend writes them).
+
+use crate::{CompactLog, LogError, I};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Magic bytes at the start of every binary compact log
+pub const BINARY_LOG_MAGIC: [u8; 4] = *b"PONG";
+
+/// Binary format version written by [`encode_binary_log`]
+pub const BINARY_LOG_FORMAT: u8 = 1;
+
+/// Fixed header size: magic + format + v + game_id
+const HEADER_LEN: usize = 4 + 1 + 1 + 4;
+
+/// Whether `bytes` looks like a binary compact log (as opposed to JSON)
+pub fn is_binary_log(bytes: &[u8]) -> bool {
+    bytes.starts_with(&BINARY_LOG_MAGIC)
+}
+
+/// Encode a compact log in the binary format
+pub fn encode_binary_log(log: &CompactLog) -> Result<Vec<u8>, LogError> {
+    let v = u8::try_from(log.v).map_err(|_| LogError::VersionOutOfRange(log.v))?;
+    let events = log.parse_events()?;
+
+    // Most events encode to 1-3 bytes
+    let mut out = Vec::with_capacity(HEADER_LEN + 10 + events.len() * 3);
+    out.extend_from_slice(&BINARY_LOG_MAGIC);
+    out.push(BINARY_LOG_FORMAT);
+    out.push(v);
+    out.extend_from_slice(&log.game_id.to_le_bytes());
+    write_varint(&mut out, events.len() as u64);
+
+    let mut prev: [I; 2] = [0, 0];
+    for (i, &e) in events.iter().enumerate() {
+        let side = i % 2;
+        write_varint(&mut out, zigzag(e.wrapping_sub(prev[side])));
+        prev[side] = e;
+    }
+
+    Ok(out)
+}
+
+/// Decode a binary compact log back into its JSON representation
+pub fn decode_binary_log(bytes: &[u8]) -> Result<CompactLog, LogError> {
+    if !is_binary_log(bytes) {
+        return Err(LogError::BadMagic);
+    }
+    if bytes.len() < HEADER_LEN {
+        return Err(LogError::Truncated);
+    }
+    if bytes[4] != BINARY_LOG_FORMAT {
+        return Err(LogError::UnsupportedFormat(bytes[4]));
+    }
+    let v = bytes[5] as u32;
+    let game_id = u32::from_le_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]);
+
+    let mut pos = HEADER_LEN;
+    let count = read_varint(bytes, &mut pos)?;
+
+    // Every event takes at least one byte; reject absurd counts before allocating
+    let remaining = (bytes.len() - pos) as u64;
+    if count > remaining {
+        return Err(LogError::EventCountTooLarge(count));
+    }
+
+    let mut events = Vec::with_capacity(count as usize);
+    let mut prev: [I; 2] = [0, 0];
+    for i in 0..count as usize {
+        let side = i % 2;
+        let e = prev[side].wrapping_add(unzigzag(read_varint(bytes, &mut pos)?));
+        events.push(e.to_string());
+        prev[side] = e;
+    }
+
+    if pos != bytes.len() {
+        return Err(LogError::TrailingBytes(bytes.len() - pos));
+    }
+
+    Ok(CompactLog { v, events, game_id })
+}
+
+#[inline(always)]
+fn zigzag(n: I) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+#[inline(always)]
+fn unzigzag(n: u64) -> I {
+    ((n >> 1) as I) ^ -((n & 1) as I)
+}
+
+/// LEB128 unsigned varint
+fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u64, LogError> {
+    let mut n: u64 = 0;
+    for shift in (0..70).step_by(7) {
+        let b = *bytes.get(*pos).ok_or(LogError::Truncated)?;
+        *pos += 1;
+
+        // The 10th byte may only contribute the single remaining bit
+        if shift == 63 && b > 1 {
+            return Err(LogError::VarintOverflow);
+        }
+        n |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok(n);
+        }
+    }
+    Err(LogError::VarintOverflow)
+}
@@ -0,0 +1,122 @@
+// Checkpoints: the replay state between event pairs, for proving a log in segments
+//
+// A long log can be proven as a chain of segments, each replaying a range of
+// event pairs. A segment starts either at the beginning of the game or from
+// the [`Checkpoint`] an earlier segment ended at, and commits a
+// [`SegmentOutput`]: the digest of where it started, the checkpoint it ended
+// at, and a hash of the events it replayed. The join guest checks the chain
+// links up and covers the whole log, then commits the same
+// [`ValidateLogOutput`](crate::ValidateLogOutput) a single proof would.
+
+use crate::abi::violator_byte;
+use crate::{MatchScore, Ruleset, Violator, I};
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Domain separation prefix for [`Checkpoint::digest`]
+pub const CHECKPOINT_PREFIX: &[u8; 9] = b"PONGCKPv1";
+
+/// Domain separation prefix for [`compute_segment_hash`]
+pub const SEGMENT_HASH_PREFIX: &[u8; 9] = b"PONGSEGv1";
+
+/// Ball and paddle state in Q16.16, as of the last serve or bounce
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixState {
+    pub t0: I,
+    pub x: I,
+    pub y: I,
+    pub vx: I,
+    pub vy: I,
+    pub speed: I,
+    pub left_y: I,
+    pub right_y: I,
+    pub dir: i32, // -1 or +1
+}
+
+/// Everything the replay carries from one event pair to the next
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub game_id: u32,
+    pub log_version: u32,
+    pub rules: Ruleset,
+    /// Event pairs consumed so far, including any skipped once the match was decided
+    pub pairs: u32,
+    pub state: FixState,
+    /// Average paddle velocities over the previous interval, and its length (acceleration rule)
+    pub prev_v_l: I,
+    pub prev_v_r: I,
+    pub prev_dt: I,
+    pub score: MatchScore,
+    /// Pairs replayed without a violation
+    pub valid_pairs: u32,
+    /// The violation that stopped the replay, if any
+    pub stopped: Option<String>,
+    /// Whose move the violation was, if it was a paddle's
+    pub violator: Option<Violator>,
+}
+
+impl Checkpoint {
+    /// SHA-256 over every field, linking a segment to the one it resumes from
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(CHECKPOINT_PREFIX);
+        for word in [self.game_id, self.log_version, self.pairs, self.valid_pairs] {
+            hasher.update(word.to_le_bytes());
+        }
+        for word in self.rules.to_words() {
+            hasher.update(word.to_le_bytes());
+        }
+        let s = &self.state;
+        for value in [s.t0, s.x, s.y, s.vx, s.vy, s.speed, s.left_y, s.right_y] {
+            hasher.update(value.to_le_bytes());
+        }
+        hasher.update(s.dir.to_le_bytes());
+        for value in [self.prev_v_l, self.prev_v_r, self.prev_dt] {
+            hasher.update(value.to_le_bytes());
+        }
+        self.score.hash_into(&mut hasher);
+        match &self.stopped {
+            None => hasher.update([0u8]),
+            Some(msg) => {
+                hasher.update([1u8]);
+                hasher.update((msg.len() as u32).to_le_bytes());
+                hasher.update(msg.as_bytes());
+            }
+        }
+        hasher.update([violator_byte(self.violator)]);
+        hasher.finalize().into()
+    }
+}
+
+/// Where a segment starts: a new game, or an earlier segment's checkpoint
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum SegmentStart {
+    New {
+        game_id: u32,
+        log_version: u32,
+        rules: Ruleset,
+    },
+    Resume(Checkpoint),
+}
+
+/// Public output of a segment proof
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SegmentOutput {
+    /// Digest of the checkpoint the segment resumed from (`None`: the start of the game)
+    pub resumed_from: Option<[u8; 32]>,
+    /// State after the segment's last pair
+    pub end: Checkpoint,
+    /// [`compute_segment_hash`] of the segment's event bytes
+    pub events_hash: [u8; 32],
+    /// Events in the segment (always whole pairs)
+    pub events_len: u32,
+}
+
+/// Hash of one segment's events, in the guest wire encoding
+pub fn compute_segment_hash(event_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(SEGMENT_HASH_PREFIX);
+    hasher.update(event_bytes);
+    hasher.finalize().into()
+}
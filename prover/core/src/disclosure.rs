@@ -0,0 +1,150 @@
+// Disclosure modes: how much of a game's result the journal reveals
+//
+// The full journal ([`ValidateLogOutput`]) shows the margin, the per-game
+// breakdown and the event count. Some consumers only need to know who won,
+// or the final score, so the input can select a smaller journal: a
+// [`DisclosedOutput`] with just those fields, the game ID, the rules and the
+// log commitment. The mode is sent to the guest as one input word after the
+// salt slot; full disclosure commits the usual journal unchanged.
+//
+// A disclosed journal starts with [`DISCLOSED_OUTPUT_TAG`] where a full one
+// starts with its `fair` flag (0 or 1), so the two can never be confused.
+//
+// A partial journal still commits the log hash, and an unsalted hash of a
+// low-entropy log gives the hidden fields away to anyone who can guess the
+// events, so partial modes are only accepted with a salt (see
+// [`check_disclosure`]).
+
+use crate::{
+    EndReason, Outcome, Ruleset, ValidateLogOutput, Violator, REASON_INVALID_DISCLOSURE, REASON_UNSALTED_DISCLOSURE,
+};
+use alloc::fmt;
+use serde::{Deserialize, Serialize};
+
+/// First word of every [`DisclosedOutput`] ("PDSC")
+pub const DISCLOSED_OUTPUT_TAG: u32 = u32::from_be_bytes(*b"PDSC");
+
+/// Which fields of the result a proof commits
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Disclosure {
+    /// The whole [`ValidateLogOutput`]
+    #[default]
+    Full,
+    /// Whether the game was fair and who won
+    WinnerOnly,
+    /// Whether the game was fair, who won and the final score
+    ScoreOnly,
+}
+
+impl Disclosure {
+    /// Look up a mode by name (`full`, `winner-only` or `score-only`)
+    pub fn from_name(name: &str) -> Option<Disclosure> {
+        match name {
+            "full" => Some(Self::Full),
+            "winner-only" => Some(Self::WinnerOnly),
+            "score-only" => Some(Self::ScoreOnly),
+            _ => None,
+        }
+    }
+
+    /// Encoding in the guest input
+    pub fn to_word(self) -> u32 {
+        match self {
+            Self::Full => 0,
+            Self::WinnerOnly => 1,
+            Self::ScoreOnly => 2,
+        }
+    }
+
+    /// Decode the guest input word (None if it is not a mode)
+    pub fn from_word(word: u32) -> Option<Disclosure> {
+        match word {
+            0 => Some(Self::Full),
+            1 => Some(Self::WinnerOnly),
+            2 => Some(Self::ScoreOnly),
+            _ => None,
+        }
+    }
+}
+
+/// Check the disclosure word read by the guest
+///
+/// Returns the mode to commit, or the reason the guest commits (as a full,
+/// invalid journal) when the word is not a mode or a partial mode comes
+/// without a salt.
+pub fn check_disclosure(word: u32, salted: bool) -> Result<Disclosure, &'static str> {
+    match Disclosure::from_word(word) {
+        None => Err(REASON_INVALID_DISCLOSURE),
+        Some(Disclosure::Full) => Ok(Disclosure::Full),
+        Some(_) if !salted => Err(REASON_UNSALTED_DISCLOSURE),
+        Some(disclosure) => Ok(disclosure),
+    }
+}
+
+impl fmt::Display for Disclosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => write!(f, "full"),
+            Self::WinnerOnly => write!(f, "winner-only"),
+            Self::ScoreOnly => write!(f, "score-only"),
+        }
+    }
+}
+
+/// Journal of a winner-only or score-only proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DisclosedOutput {
+    /// Always [`DISCLOSED_OUTPUT_TAG`]
+    pub tag: u32,
+    /// [`Disclosure::WinnerOnly`] or [`Disclosure::ScoreOnly`]
+    pub disclosure: Disclosure,
+    /// Whether the log was fair (for an abandoned match, its valid prefix)
+    pub fair: bool,
+    /// Winner, or a draw (None if rejected or abandoned)
+    pub outcome: Option<Outcome>,
+    /// Why the match ended, so an abandoned match is never read as a finished one
+    pub end_reason: Option<EndReason>,
+    /// Whose violation ended an abandoned match's valid prefix
+    pub violator: Option<Violator>,
+    /// `(left_score, right_score)`, for score-only proofs
+    pub score: Option<(u32, u32)>,
+    /// Game ID, for replay protection
+    pub game_id: u32,
+    /// Rules the game was validated under
+    pub rules: Ruleset,
+    /// Salted log commitment (partial modes always need a salt)
+    pub log_hash_sha256: [u8; 32],
+}
+
+impl DisclosedOutput {
+    /// The fields of `out` that `disclosure` reveals (None for [`Disclosure::Full`])
+    pub fn from_output(out: &ValidateLogOutput, disclosure: Disclosure) -> Option<Self> {
+        let score = match disclosure {
+            Disclosure::Full => return None,
+            Disclosure::WinnerOnly => None,
+            Disclosure::ScoreOnly => Some((out.left_score, out.right_score)),
+        };
+        Some(Self {
+            tag: DISCLOSED_OUTPUT_TAG,
+            disclosure,
+            fair: out.fair,
+            outcome: out.outcome,
+            end_reason: out.end_reason,
+            violator: out.violator,
+            score,
+            game_id: out.game_id,
+            rules: out.rules,
+            log_hash_sha256: out.log_hash_sha256,
+        })
+    }
+
+    /// Whether the tag and the fields present agree with the disclosure mode
+    pub fn is_well_formed(&self) -> bool {
+        self.tag == DISCLOSED_OUTPUT_TAG
+            && match self.disclosure {
+                Disclosure::Full => false,
+                Disclosure::WinnerOnly => self.score.is_none(),
+                Disclosure::ScoreOnly => self.score.is_some(),
+            }
+    }
+}
@@ -0,0 +1,232 @@
+// Elo ladder, updated from the pong proofs of its games
+//
+// The whole ladder state (ratings, the games already rated, and the ladder's
+// settings) hashes to a [`Leaderboard::root`]. The rating guest reads the
+// previous state, verifies each game's receipt by composition, applies
+// [`Leaderboard::apply`] and commits the old and new roots, so every rating
+// change is backed by a proof.
+//
+// Game journals do not identify the players, so a [`Pairing`] binds them
+// through the game ID, as a bracket does: the game must be played with
+// [`Leaderboard::game_id`], derived from the ladder, the round and both
+// players. A game between other players, or for another ladder, has a
+// different ID and cannot be rated for the pairing.
+//
+// Ratings are Q16.16 and the expected score is computed with integer
+// arithmetic only, so the host and the guest agree bit for bit.
+
+use crate::tournament::derive_game_id;
+use crate::{Outcome, PlayerId, ProvenGame, Ruleset, ValidateLogOutput, I};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Domain separation prefix for [`Leaderboard::root`]
+pub const LEADERBOARD_PREFIX: &[u8; 9] = b"PONGLDRv2";
+
+/// Domain separation prefix for [`Leaderboard::game_id`]
+pub const LADDER_GAME_PREFIX: &[u8; 9] = b"PONGLGMv1";
+
+/// Rating of a new player, in Q16.16
+pub const INITIAL_RATING: I = 1500 << 16;
+
+/// Elo K-factor (largest change from one game), in Q16.16
+pub const K_FACTOR: I = 32 << 16;
+
+/// Rating differences beyond this many points count as this many
+const MAX_RATING_DIFF: I = 800 << 16;
+
+/// log2(10) in Q16.16
+const LOG2_10_Q16: I = 217706;
+
+/// 2^(2^-k) in Q16.16 for k = 1..=16, for [`exp2`]
+const EXP2_FRAC_Q16: [I; 16] = [
+    92682, 77936, 71468, 68438, 66971, 66250, 65892, 65714, 65625, 65580, 65558, 65547, 65542, 65539,
+    65537, 65537,
+];
+
+/// 2^x for x in Q16.16 (|x| well under 32), result in Q16.16
+fn exp2(x: I) -> I {
+    let whole = x >> 16; // floor
+    let frac = x & 0xFFFF;
+    let mut result: I = 1 << 16;
+    for (k, factor) in EXP2_FRAC_Q16.iter().enumerate() {
+        if frac & (1 << (15 - k)) != 0 {
+            result = (result * factor) >> 16;
+        }
+    }
+    if whole >= 0 {
+        result << whole
+    } else {
+        result >> -whole
+    }
+}
+
+/// Expected score of a player rated `rating` against `opponent`, in Q16.16 (0 to 1)
+///
+/// `1 / (1 + 10^((opponent - rating) / 400))`, with the difference capped at
+/// 800 points.
+pub fn expected_score(rating: I, opponent: I) -> I {
+    let diff = (opponent - rating).clamp(-MAX_RATING_DIFF, MAX_RATING_DIFF);
+    let power = exp2(diff * LOG2_10_Q16 / (400 << 16));
+    (1 << 32) / ((1 << 16) + power)
+}
+
+/// A player's rating
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rating {
+    pub player: PlayerId,
+    /// Q16.16
+    pub rating: I,
+}
+
+/// Who the ladder paired for a game (game journals do not identify players)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pairing {
+    /// Ladder round the game was paired in; the same players get a new game ID each round
+    pub round: u32,
+    pub left: PlayerId,
+    pub right: PlayerId,
+}
+
+/// Ladder state
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    /// Organizer-chosen ladder ID, so the same pairing gets different game
+    /// IDs on different ladders
+    pub id: u32,
+    /// Rules every rated game must be played under
+    pub rules: Ruleset,
+    /// Ratings, sorted by player
+    pub ratings: Vec<Rating>,
+    /// Game IDs already rated, sorted; a game counts once
+    pub rated_games: Vec<u32>,
+}
+
+impl Leaderboard {
+    /// An empty ladder `id` for games played under `rules`
+    pub fn new(id: u32, rules: Ruleset) -> Self {
+        Self {
+            id,
+            rules,
+            ratings: Vec::new(),
+            rated_games: Vec::new(),
+        }
+    }
+
+    /// A player's rating ([`INITIAL_RATING`] if they have not played)
+    pub fn rating(&self, player: PlayerId) -> I {
+        match self.ratings.binary_search_by_key(&player, |r| r.player) {
+            Ok(i) => self.ratings[i].rating,
+            Err(_) => INITIAL_RATING,
+        }
+    }
+
+    fn set_rating(&mut self, player: PlayerId, rating: I) {
+        match self.ratings.binary_search_by_key(&player, |r| r.player) {
+            Ok(i) => self.ratings[i].rating = rating,
+            Err(i) => self.ratings.insert(i, Rating { player, rating }),
+        }
+    }
+
+    /// Game ID the game of `pairing` must be played with
+    ///
+    /// The first 4 bytes (little-endian) of SHA-256 over the ladder ID, the
+    /// round and both players.
+    pub fn game_id(&self, pairing: &Pairing) -> u32 {
+        derive_game_id(LADDER_GAME_PREFIX, [self.id, pairing.round, pairing.left, pairing.right])
+    }
+
+    /// Rate one proven game
+    ///
+    /// The game must be played with the pairing's [`Leaderboard::game_id`],
+    /// fair, played under the ladder's rules, finished (a win or a draw;
+    /// abandoned matches are not rated) and not rated before.
+    pub fn apply(&mut self, pairing: &Pairing, out: &ValidateLogOutput) -> Result<(), &'static str> {
+        let game_id = self.game_id(pairing);
+        if out.game_id != game_id {
+            return Err("Game was not played for this pairing");
+        }
+        if pairing.left == pairing.right {
+            return Err("A player cannot play themselves");
+        }
+        if !out.fair {
+            return Err("Rated game was not fair");
+        }
+        if out.rules != self.rules {
+            return Err("Rated game was played under different rules");
+        }
+        // Score of the left player
+        let left_score: I = match out.outcome {
+            Some(Outcome::LeftWin) => 1 << 16,
+            Some(Outcome::RightWin) => 0,
+            Some(Outcome::Draw) => 1 << 15,
+            None => return Err("Rated game has no result"),
+        };
+        let slot = match self.rated_games.binary_search(&game_id) {
+            Ok(_) => return Err("Game has already been rated"),
+            Err(slot) => slot,
+        };
+
+        let left = self.rating(pairing.left);
+        let right = self.rating(pairing.right);
+        // Zero-sum: whatever the left player gains, the right player loses
+        let change = (K_FACTOR * (left_score - expected_score(left, right))) >> 16;
+        self.set_rating(pairing.left, left + change);
+        self.set_rating(pairing.right, right - change);
+        self.rated_games.insert(slot, game_id);
+        Ok(())
+    }
+
+    /// SHA-256 over the ladder ID, rules, ratings and rated games, committing to the whole ladder
+    pub fn root(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(LEADERBOARD_PREFIX);
+        hasher.update(self.id.to_le_bytes());
+        for word in self.rules.to_words() {
+            hasher.update(word.to_le_bytes());
+        }
+        hasher.update((self.ratings.len() as u32).to_le_bytes());
+        for r in &self.ratings {
+            hasher.update(r.player.to_le_bytes());
+            hasher.update(r.rating.to_le_bytes());
+        }
+        hasher.update((self.rated_games.len() as u32).to_le_bytes());
+        for game_id in &self.rated_games {
+            hasher.update(game_id.to_le_bytes());
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// A proven game and who the ladder paired for it, as passed to the rating guest
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RatedGame {
+    pub pairing: Pairing,
+    pub game: ProvenGame,
+}
+
+/// Input to the rating guest (serde)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LeaderboardInput {
+    /// Ladder state before the batch; its root is committed as `prev_root`
+    pub leaderboard: Leaderboard,
+    /// Pong guest image IDs the games may have been proven by
+    pub pong_image_ids: Vec<[u32; 8]>,
+    /// Games to rate, in order
+    pub games: Vec<RatedGame>,
+}
+
+/// Public output of a rating proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardOutput {
+    /// [`Leaderboard::root`] before the batch
+    pub prev_root: [u8; 32],
+    /// [`Leaderboard::root`] after the batch
+    pub root: [u8; 32],
+    /// Game IDs rated, in order
+    pub games: Vec<u32>,
+    /// Pong guest image IDs the games were verified against; a verifier
+    /// must check these are releases it trusts
+    pub pong_image_ids: Vec<[u32; 8]>,
+}
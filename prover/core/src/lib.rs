@@ -4,25 +4,201 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use alloc::fmt;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+mod abi;
+mod aggregate;
+mod checkpoint;
+mod disclosure;
+mod leaderboard;
+mod binary_log;
+mod physics;
+mod reason;
+mod rules;
+mod scoring;
+mod session;
+mod tournament;
+mod trig;
+
+pub use abi::{
+    config_digest, error_code, error_reason, AbiJournal, ABI_JOURNAL_LEN, ABI_JOURNAL_VERSION, CONFIG_DIGEST_PREFIX,
+    ERROR_REASONS, ERROR_UNKNOWN,
+};
+pub use aggregate::{
+    merkle_root, AggregateInput, AggregateOutput, GameLeaf, InclusionProof, LEAF_HASH_PREFIX, NODE_HASH_PREFIX,
+};
+pub use checkpoint::{
+    compute_segment_hash, Checkpoint, FixState, SegmentOutput, SegmentStart, CHECKPOINT_PREFIX,
+    SEGMENT_HASH_PREFIX,
+};
+pub use disclosure::{check_disclosure, DisclosedOutput, Disclosure, DISCLOSED_OUTPUT_TAG};
+pub use leaderboard::{
+    expected_score, Leaderboard, LeaderboardInput, LeaderboardOutput, Pairing, RatedGame, Rating, INITIAL_RATING,
+    K_FACTOR, LADDER_GAME_PREFIX, LEADERBOARD_PREFIX,
+};
+pub use binary_log::{decode_binary_log, encode_binary_log, is_binary_log, BINARY_LOG_FORMAT, BINARY_LOG_MAGIC};
+pub use physics::{Collision, Physics, MAX_BALL_SPEED};
+pub use reason::{
+    REASON_BALL_TOO_FAST, REASON_BEST_OF, REASON_DRAW_WITHOUT_CLOCK, REASON_GOLDEN_POINT_MISSING,
+    REASON_INVALID_DISCLOSURE, REASON_INVALID_KINEMATICS, REASON_INVALID_RULESET, REASON_MATCH_NOT_WON,
+    REASON_NO_EVENTS, REASON_NO_WINNER, REASON_ODD_EVENTS, REASON_PADDLE_ACCEL, REASON_PADDLE_OUT_OF_BOUNDS, REASON_PADDLE_TOO_FAST,
+    REASON_POINTS_TO_WIN, REASON_REACTION_DELAY, REASON_TOO_MANY_EVENTS, REASON_UNSALTED_DISCLOSURE,
+    REASON_UNSUPPORTED_LOG_VERSION, REASON_WIN_BY, REASON_ZERO_VELOCITY,
+};
+pub use rules::{Ruleset, RULESET_WORDS};
+pub use scoring::{EndReason, GameScore, MatchScore, Outcome, ScoringRules, Serve, Violator, SCORING_WORDS};
+pub use session::{
+    check_session_events, check_session_header, session_game_id, SessionInput, SessionLog, SessionOutput,
+    SessionTotals, MAX_SESSION_GAMES, SESSION_HEADER_WORDS, SESSION_ID_PREFIX,
+};
+pub use tournament::{
+    Bracket, GameResult, PlayerId, TournamentInput, TournamentOutput, BRACKET_GAME_PREFIX, BRACKET_HASH_PREFIX,
+};
+pub use trig::{cordic_sin_cos, table_sin_cos, Trig, PI_Q16, TRIG_TABLE_MAX_DEG};
+
 /// Fixed-point type: Q16.16 format using i64
 pub type I = i64;
 
-/// Input structure for log validation (used by guest)
-#[derive(Serialize, Deserialize)]
+/// Domain separation prefix for [`compute_log_hash`]
+///
+/// v1 hashed 8 bytes per event; v2 hashes the 4-byte guest wire encoding.
+pub const LOG_HASH_PREFIX: &[u8; 9] = b"PONGLOGv2";
+
+/// Domain separation prefix for [`compute_salted_log_hash`]
+pub const SALTED_LOG_HASH_PREFIX: &[u8; 9] = b"PONGSLTv1";
+
+/// Length of a log salt in bytes
+pub const LOG_SALT_BYTES: usize = 32;
+
+/// Bytes per event in the guest wire encoding (i32 little-endian)
+pub const EVENT_BYTES: usize = 4;
+
+/// Maximum number of events allowed in a game log (~5000 volleys max)
+pub const MAX_EVENTS: u32 = 10000;
+
+/// Number of u32 words written before the event bytes:
+/// `[game_id, events_len, log_version]` followed by the [`Ruleset`] words
+pub const INPUT_HEADER_WORDS: usize = 3 + RULESET_WORDS;
+
+/// Input for log validation (used by host to feed the guest)
+///
+/// The guest does not deserialize this struct. The host writes
+/// [`header`](Self::header) as raw words, then [`salt_bytes`](Self::salt_bytes),
+/// the [`disclosure`](Self::disclosure) word and [`event_bytes`](Self::event_bytes),
+/// and the guest validates and hashes those bytes in place.
+#[derive(Debug, Clone)]
 pub struct ValidateLogInput {
     pub events: Vec<I>,
     /// Unique game identifier - used for serve angle entropy
     /// Generated randomly by client at game start
     pub game_id: u32,
+    /// `CompactLog::v`; selects the physics the guest replays with (see [`Physics`])
+    pub log_version: u32,
+    /// Rules enforced on top of the base physics
+    pub rules: Ruleset,
+    /// Private salt mixed into the log commitment (see [`log_commitment`]); never committed
+    pub salt: Option<[u8; LOG_SALT_BYTES]>,
+    /// Which fields of the result the journal reveals
+    pub disclosure: Disclosure,
+}
+
+impl ValidateLogInput {
+    /// Input for a log played under the standard rules, unsalted and fully disclosed
+    pub fn new(events: Vec<I>, game_id: u32, log_version: u32) -> Self {
+        Self {
+            events,
+            game_id,
+            log_version,
+            rules: Ruleset::STANDARD,
+            salt: None,
+            disclosure: Disclosure::Full,
+        }
+    }
+
+    /// Header words: `[game_id, events_len, log_version, rules...]`
+    pub fn header(&self) -> [u32; INPUT_HEADER_WORDS] {
+        let mut header = [0u32; INPUT_HEADER_WORDS];
+        header[..3].copy_from_slice(&[self.game_id, self.events.len() as u32, self.log_version]);
+        header[3..].copy_from_slice(&self.rules.to_words());
+        header
+    }
+
+    /// Salt slot written after the header: the salt, or all zeros for none
+    ///
+    /// The all-zero salt is therefore reserved; see [`salt_from_bytes`].
+    pub fn salt_bytes(&self) -> [u8; LOG_SALT_BYTES] {
+        self.salt.unwrap_or([0; LOG_SALT_BYTES])
+    }
+
+    /// Events in the guest wire encoding
+    pub fn event_bytes(&self) -> Result<Vec<u8>, LogError> {
+        encode_events(&self.events)
+    }
+}
+
+/// Encode events for the guest: each as an i32, little-endian
+///
+/// Valid Q16.16 paddle positions are below 480 << 16, far inside i32, so
+/// anything that does not fit is rejected here rather than sent to the guest.
+pub fn encode_events(events: &[I]) -> Result<Vec<u8>, LogError> {
+    let mut out = Vec::with_capacity(events.len() * EVENT_BYTES);
+    for (index, &e) in events.iter().enumerate() {
+        let e = i32::try_from(e).map_err(|_| LogError::EventOutOfRange { index })?;
+        out.extend_from_slice(&e.to_le_bytes());
+    }
+    Ok(out)
+}
+
+/// Check an input header before any events are read
+///
+/// Returns the physics and rules to replay with, or the reason the guest
+/// commits when it rejects the log without replaying it.
+pub fn check_header(header: &[u32; INPUT_HEADER_WORDS]) -> Result<(Physics, Ruleset), &'static str> {
+    let [_, events_len, log_version, ..] = *header;
+    // Bound the allocation before reading any events
+    if events_len > MAX_EVENTS {
+        return Err(REASON_TOO_MANY_EVENTS);
+    }
+    let physics = Physics::for_log_version(log_version).ok_or(REASON_UNSUPPORTED_LOG_VERSION)?;
+    let mut rule_words = [0u32; RULESET_WORDS];
+    rule_words.copy_from_slice(&header[3..]);
+    let rules = Ruleset::from_words(rule_words).ok_or(REASON_INVALID_RULESET)?;
+    rules.scoring.validate()?;
+
+    // Empty games are invalid - no gameplay occurred
+    if events_len == 0 {
+        return Err(REASON_NO_EVENTS);
+    }
+    // A forfeit log may end in a torn pair, which is left out of the replay
+    if !events_len.is_multiple_of(2) && !rules.scoring.forfeit {
+        return Err(REASON_ODD_EVENTS);
+    }
+    Ok((physics, rules))
+}
+
+/// The salt in a salt slot read by the guest (None if all zeros)
+pub fn salt_from_bytes(bytes: [u8; LOG_SALT_BYTES]) -> Option<[u8; LOG_SALT_BYTES]> {
+    (bytes != [0; LOG_SALT_BYTES]).then_some(bytes)
+}
+
+/// Decode one event from the guest wire encoding
+#[inline(always)]
+pub fn decode_event(bytes: &[u8]) -> I {
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as I
 }
 
 /// Output structure from log validation (returned by guest)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ValidateLogOutput {
+    /// Whether the replayed events obeyed the rules
+    ///
+    /// For an abandoned match this only covers the valid prefix (up to
+    /// `last_valid_pair`): the log may go on to break the rules, which
+    /// `reason` and `violator` then record.
     pub fair: bool,
+    /// Why the log was rejected, or which violation ended an abandoned match's valid prefix
     pub reason: Option<String>,
     pub left_score: u32,
     pub right_score: u32,
@@ -30,18 +206,61 @@ pub struct ValidateLogOutput {
     pub log_hash_sha256: [u8; 32],
     /// Game ID included in output for replay protection
     pub game_id: u32,
+    /// Log version the game was replayed under (0 if rejected before replay)
+    pub log_version: u32,
+    /// Rules the game was validated under
+    pub rules: Ruleset,
+    /// Score of each game in the match, in order (empty if rejected)
+    ///
+    /// `left_score`/`right_score` are the match result: points when the match
+    /// is a single game, games won when it is best-of-N. A timed match that
+    /// ran out of clock records the game in progress as it stood.
+    pub games: Vec<GameScore>,
+    /// Why the match ended: on score or on the game clock (None if rejected)
+    pub end_reason: Option<EndReason>,
+    /// Winner, or a draw (None if rejected or abandoned)
+    pub outcome: Option<Outcome>,
+    /// For an abandoned match, the index of the last event pair replayed
+    /// without a violation: the prefix up to it is what was proven fair
+    pub last_valid_pair: Option<u32>,
+    /// For an abandoned match that ended in a paddle violation, whose move it
+    /// was (None if the log just stopped, or for any other output)
+    pub violator: Option<Violator>,
 }
 
 impl ValidateLogOutput {
-    pub fn ok(left: u32, right: u32, events_len: u32, hash: [u8; 32], game_id: u32) -> Self {
+    /// Output for a fair game, with the result and breakdown taken from `score`
+    ///
+    /// The breakdown includes the game in progress of an abandoned match.
+    pub fn ok(
+        score: MatchScore,
+        events_len: u32,
+        hash: [u8; 32],
+        game_id: u32,
+        log_version: u32,
+        rules: Ruleset,
+    ) -> Self {
+        let (left_score, right_score) = score.result();
+        let end_reason = score.end_reason();
+        let outcome = score.outcome();
+        let in_progress = score.game_in_progress();
+        let mut games = score.games;
+        games.extend(in_progress);
         Self {
             fair: true,
             reason: None,
-            left_score: left,
-            right_score: right,
+            left_score,
+            right_score,
             events_len,
             log_hash_sha256: hash,
             game_id,
+            log_version,
+            rules,
+            games,
+            end_reason,
+            outcome,
+            last_valid_pair: None,
+            violator: None,
         }
     }
 
@@ -54,12 +273,29 @@ impl ValidateLogOutput {
             events_len: 0,
             log_hash_sha256: [0u8; 32],
             game_id: 0,
+            log_version: 0,
+            rules: Ruleset::STANDARD,
+            games: Vec::new(),
+            end_reason: None,
+            outcome: None,
+            last_valid_pair: None,
+            violator: None,
         }
     }
 }
 
+/// A game's journal and the image ID its receipt verifies against, as passed
+/// to guests that verify pong proofs by composition
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProvenGame {
+    pub image_id: [u32; 8],
+    pub output: ValidateLogOutput,
+}
+
 /// Compact log format (used by host for parsing JSON)
-#[derive(Deserialize)]
+///
+/// This is the JSON encoding; see [`encode_binary_log`] for the binary encoding.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct CompactLog {
     pub v: u32,
     pub events: Vec<String>,
@@ -67,29 +303,101 @@ pub struct CompactLog {
     pub game_id: u32,
 }
 
+impl CompactLog {
+    /// Parse the decimal event strings as Q16.16 values
+    pub fn parse_events(&self) -> Result<Vec<I>, LogError> {
+        self.events
+            .iter()
+            .enumerate()
+            .map(|(index, s)| s.parse().map_err(|_| LogError::InvalidEvent { index }))
+            .collect()
+    }
+}
+
+/// Errors from parsing or converting compact logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogError {
+    /// An event string is not a valid i64
+    InvalidEvent { index: usize },
+    /// An event does not fit the 32-bit guest wire encoding
+    EventOutOfRange { index: usize },
+    /// Binary log does not start with [`BINARY_LOG_MAGIC`]
+    BadMagic,
+    /// Binary log uses a format version this build does not understand
+    UnsupportedFormat(u8),
+    /// Log version does not fit in the binary header
+    VersionOutOfRange(u32),
+    /// Binary log ended in the middle of a field
+    Truncated,
+    /// A varint is longer than 10 bytes or overflows 64 bits
+    VarintOverflow,
+    /// Declared event count is larger than the data could possibly hold
+    EventCountTooLarge(u64),
+    /// Extra bytes after the last event
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogError::InvalidEvent { index } => write!(f, "Error parsing event {}", index),
+            LogError::EventOutOfRange { index } => {
+                write!(f, "Event {} is out of range for a paddle position", index)
+            }
+            LogError::BadMagic => write!(f, "Not a binary compact log (bad magic)"),
+            LogError::UnsupportedFormat(v) => write!(f, "Unsupported binary log format: {}", v),
+            LogError::VersionOutOfRange(v) => write!(f, "Log version {} does not fit in one byte", v),
+            LogError::Truncated => write!(f, "Binary log is truncated"),
+            LogError::VarintOverflow => write!(f, "Invalid varint in binary log"),
+            LogError::EventCountTooLarge(n) => write!(f, "Declared event count {} exceeds log size", n),
+            LogError::TrailingBytes(n) => write!(f, "{} unexpected trailing bytes in binary log", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LogError {}
+
 /// Compute SHA-256 hash of game log events
 /// This hash binds the proof to specific game events
-pub fn compute_log_hash(events: &Vec<I>) -> [u8; 32] {
+///
+/// Hashes the guest wire encoding of the events (see [`encode_events`])
+/// directly, so the guest can commit to the exact bytes it read without copying.
+/// The guest build patches `sha2` to use the zkVM SHA-256 accelerator; the
+/// output is the same on both sides.
+pub fn compute_log_hash(event_bytes: &[u8]) -> [u8; 32] {
     let mut h = Sha256::new();
 
-    // Build buffer for batch hashing (more efficient with SHA-256 accelerator)
     // Version prefix: 9 bytes
-    // Events: variable length (8 bytes per i64)
-    let mut buf = Vec::with_capacity(9 + events.len() * 8);
-
-    // Version prefix
-    buf.extend_from_slice(b"PONGLOGv1");
-
-    // Events as little-endian 8 bytes per I (i64)
-    for v in events.iter() {
-        buf.extend_from_slice(&v.to_le_bytes());
-    }
+    h.update(LOG_HASH_PREFIX);
 
-    // Single batch update (optimal for SHA-256 accelerator)
-    h.update(&buf);
+    // Events: 4 bytes per event (i32 little-endian)
+    h.update(event_bytes);
 
     let out = h.finalize();
     let mut arr = [0u8; 32];
     arr.copy_from_slice(&out);
     arr
 }
+
+/// SHA-256 of the events with a private salt, under [`SALTED_LOG_HASH_PREFIX`]
+///
+/// Logs are low-entropy (paddles often sit exactly at the center), so an
+/// unsalted hash of a short game can be confirmed or guessed from its game ID.
+/// With a random salt the commitment reveals nothing without the salt, which
+/// the prover shares only with whoever needs to check the log.
+pub fn compute_salted_log_hash(salt: &[u8; LOG_SALT_BYTES], event_bytes: &[u8]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(SALTED_LOG_HASH_PREFIX);
+    h.update(salt);
+    h.update(event_bytes);
+    h.finalize().into()
+}
+
+/// The log commitment the guest puts in `log_hash_sha256`: salted if there is a salt
+pub fn log_commitment(salt: Option<&[u8; LOG_SALT_BYTES]>, event_bytes: &[u8]) -> [u8; 32] {
+    match salt {
+        None => compute_log_hash(event_bytes),
+        Some(salt) => compute_salted_log_hash(salt, event_bytes),
+    }
+}
@@ -0,0 +1,58 @@
+// Physics a log is replayed under, selected by log version
+//
+// ## Log v1
+// - CORDIC trig
+// - Face-only hit test: the ball hits if its centre is within
+//   `PADDLE_HEIGHT / 2 + BALL_RADIUS` of the paddle centre when it reaches
+//   the contact plane, which treats the paddle ends as square corners
+// - Uncapped ball speed: every return speeds the ball up, and a rally long
+//   enough to leave the fixed-point range is rejected rather than replayed
+//
+// ## Log v2
+// - Lookup-table trig (see `trig.rs`)
+// - Swept hit test against the paddle rectangle: the ball's circle is swept
+//   past the contact plane, so a ball that misses the face can still strike a
+//   paddle corner, or glance off a top/bottom edge on its way past
+// - Ball speed capped at [`MAX_BALL_SPEED`], so rallies of any length replay
+//
+// Older versions are never changed, so existing logs keep validating exactly
+// as they were recorded.
+
+use crate::trig::Trig;
+
+/// Ball speed cap of log v2 (pixels/second)
+pub const MAX_BALL_SPEED: u32 = 4000;
+
+/// How the ball is tested against the paddle, selected by log version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Collision {
+    /// Contact-plane test with a square `PADDLE_HEIGHT / 2 + BALL_RADIUS` reach (log v1)
+    Face,
+    /// Swept circle against the paddle rectangle: face, edges and corners (log v2)
+    Swept,
+}
+
+/// Physics used to replay a log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Physics {
+    pub trig: Trig,
+    pub collision: Collision,
+    /// Speed at which returns stop speeding the ball up (pixels/second), or
+    /// None for an uncapped ball (log v1)
+    pub max_ball_speed: Option<u32>,
+}
+
+impl Physics {
+    /// Physics for compact logs of version `v`, if the version is supported
+    pub fn for_log_version(v: u32) -> Option<Physics> {
+        match v {
+            1 => Some(Physics { trig: Trig::Cordic, collision: Collision::Face, max_ball_speed: None }),
+            2 => Some(Physics {
+                trig: Trig::Table,
+                collision: Collision::Swept,
+                max_ball_speed: Some(MAX_BALL_SPEED),
+            }),
+            _ => None,
+        }
+    }
+}
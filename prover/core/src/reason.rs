@@ -0,0 +1,30 @@
+// Reasons committed in `ValidateLogOutput::reason`
+//
+// The guests and the core checks commit these exact strings, and the ABI
+// journal maps them to frozen error codes (see `abi::ERROR_REASONS`). Keeping
+// them in one place means a reworded message changes both sides at once;
+// a released reason must never be reworded, only superseded by a new one.
+
+pub const REASON_TOO_MANY_EVENTS: &str = "Too many events (exceeds MAX_EVENTS limit)";
+pub const REASON_UNSUPPORTED_LOG_VERSION: &str = "Unsupported log version";
+pub const REASON_INVALID_RULESET: &str = "Invalid ruleset";
+pub const REASON_POINTS_TO_WIN: &str = "Invalid scoring rules: points_to_win must be at least 1";
+pub const REASON_WIN_BY: &str = "Invalid scoring rules: win_by must be between 1 and points_to_win";
+pub const REASON_BEST_OF: &str = "Invalid scoring rules: best_of must be odd";
+pub const REASON_DRAW_WITHOUT_CLOCK: &str = "Invalid scoring rules: draws need a time limit";
+pub const REASON_NO_EVENTS: &str = "No events provided - game never started";
+pub const REASON_ODD_EVENTS: &str = "Events must be pairs";
+pub const REASON_ZERO_VELOCITY: &str = "Invalid velocity: vx is zero";
+pub const REASON_INVALID_KINEMATICS: &str = "Invalid kinematics";
+pub const REASON_PADDLE_TOO_FAST: &str = "Paddle moved too fast";
+pub const REASON_PADDLE_OUT_OF_BOUNDS: &str = "Paddle out of bounds";
+pub const REASON_REACTION_DELAY: &str = "Paddle moved before reaction delay";
+pub const REASON_PADDLE_ACCEL: &str = "Paddle accelerated too fast";
+pub const REASON_GOLDEN_POINT_MISSING: &str = "Timed match tied at the deadline - golden point not played";
+/// A classic match nobody won, as the baseline guest and the frontend word it
+pub const REASON_NO_WINNER: &str = "Invalid final score - neither player reached POINTS_TO_WIN";
+/// A match under other scoring rules nobody won
+pub const REASON_MATCH_NOT_WON: &str = "Invalid final score - the match was not won under the scoring rules";
+pub const REASON_BALL_TOO_FAST: &str = "Ball too fast - the rally outran the fixed-point range";
+pub const REASON_INVALID_DISCLOSURE: &str = "Invalid disclosure mode";
+pub const REASON_UNSALTED_DISCLOSURE: &str = "Partial disclosure needs a salted log commitment";
@@ -0,0 +1,447 @@
+// Scoring rules: when a game and a match are won, and who serves next
+//
+// The classic format is a single game, first to 3 points, with each serve
+// going toward the player who just scored. Other formats are part of the
+// [`Ruleset`](crate::Ruleset), so the guest commits which one a result was
+// played under, along with the score of every game in the match.
+//
+// ## Timed matches
+// With a time limit the match also ends when the game clock passes it. The
+// game in progress is recorded as it stands and counts for whoever leads it;
+// the player who has won more games (or, in a single game, leads) wins. A
+// tie is a draw if the rules allow draws, and otherwise goes to a golden
+// point: the next point scored decides the match. In a best-of-N match the
+// game in progress is recorded at the deadline and the golden point is
+// played as a one-point deciding game, so it breaks the tie in games won
+// even when the interrupted game already counted for its leader.
+//
+// ## Forfeits
+// In forfeit mode a log that stops (or turns invalid) before the match is
+// decided is not rejected: the valid prefix is committed as abandoned, with
+// the score as it stood and no winner. Who forfeited is for the caller to say.
+
+use crate::reason::{REASON_BEST_OF, REASON_DRAW_WITHOUT_CLOCK, REASON_POINTS_TO_WIN, REASON_WIN_BY};
+use alloc::fmt;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Number of u32 words [`ScoringRules`] occupies in the guest input header
+pub const SCORING_WORDS: usize = 7;
+
+/// Who receives the serve after a point
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Serve {
+    /// Toward the player who just scored
+    ToScorer,
+    /// Alternating sides every point, starting with the first serve's direction
+    Alternate,
+}
+
+/// How points make up games, and games make up a match
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoringRules {
+    /// Points needed to win a game
+    pub points_to_win: u32,
+    /// Lead needed to win a game: 1 = first to `points_to_win`, 2 = win by two
+    /// (play continues past `points_to_win` until one player leads by two)
+    pub win_by: u32,
+    /// Games in the match (odd); the first player to win a majority wins the match
+    pub best_of: u32,
+    pub serve: Serve,
+    /// Game clock limit in Q16.16 seconds; 0 = untimed
+    #[serde(default)]
+    pub time_limit: u32,
+    /// Whether a timed match tied at the deadline ends in a draw (otherwise golden point)
+    #[serde(default)]
+    pub allow_draw: bool,
+    /// Whether an undecided log is committed as an abandoned match instead of rejected
+    #[serde(default)]
+    pub forfeit: bool,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+impl ScoringRules {
+    /// One game, first to 3 points, serve toward the scorer
+    pub const CLASSIC: ScoringRules = ScoringRules {
+        points_to_win: 3,
+        win_by: 1,
+        best_of: 1,
+        serve: Serve::ToScorer,
+        time_limit: 0,
+        allow_draw: false,
+        forfeit: false,
+    };
+
+    /// Check the rules describe a match that can be won
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.points_to_win == 0 {
+            return Err(REASON_POINTS_TO_WIN);
+        }
+        if self.win_by == 0 || self.win_by > self.points_to_win {
+            return Err(REASON_WIN_BY);
+        }
+        if self.best_of.is_multiple_of(2) {
+            return Err(REASON_BEST_OF);
+        }
+        if self.allow_draw && self.time_limit == 0 {
+            return Err(REASON_DRAW_WITHOUT_CLOCK);
+        }
+        Ok(())
+    }
+
+    /// Games a player must win to take the match
+    pub fn games_to_win(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Encoding in the guest input header
+    pub fn to_words(&self) -> [u32; SCORING_WORDS] {
+        let serve = match self.serve {
+            Serve::ToScorer => 0,
+            Serve::Alternate => 1,
+        };
+        [
+            self.points_to_win,
+            self.win_by,
+            self.best_of,
+            serve,
+            self.time_limit,
+            self.allow_draw as u32,
+            self.forfeit as u32,
+        ]
+    }
+
+    /// Decode from the guest input header (`None` for an unknown serve rule or flag)
+    pub fn from_words(words: [u32; SCORING_WORDS]) -> Option<Self> {
+        let [points_to_win, win_by, best_of, serve, time_limit, allow_draw, forfeit] = words;
+        let serve = match serve {
+            0 => Serve::ToScorer,
+            1 => Serve::Alternate,
+            _ => return None,
+        };
+        let flag = |word| match word {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        };
+        let allow_draw = flag(allow_draw)?;
+        let forfeit = flag(forfeit)?;
+        Some(Self {
+            points_to_win,
+            win_by,
+            best_of,
+            serve,
+            time_limit,
+            allow_draw,
+            forfeit,
+        })
+    }
+}
+
+impl fmt::Display for ScoringRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.best_of > 1 {
+            write!(f, "best of {} games, ", self.best_of)?;
+        }
+        write!(f, "first to {}", self.points_to_win)?;
+        if self.win_by > 1 {
+            write!(f, ", win by {}", self.win_by)?;
+        }
+        if self.serve == Serve::Alternate {
+            write!(f, ", alternating serve")?;
+        }
+        if self.time_limit > 0 {
+            let secs = self.time_limit >> 16;
+            let millis = ((self.time_limit & 0xFFFF) as u64 * 1000) >> 16;
+            if millis == 0 {
+                write!(f, ", {} s clock", secs)?;
+            } else {
+                write!(f, ", {}.{:03} s clock", secs, millis)?;
+            }
+            if self.allow_draw {
+                write!(f, ", draws allowed")?;
+            }
+        }
+        if self.forfeit {
+            write!(f, ", forfeits accepted")?;
+        }
+        Ok(())
+    }
+}
+
+/// Final score of one game
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameScore {
+    pub left: u32,
+    pub right: u32,
+}
+
+impl fmt::Display for GameScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.left, self.right)
+    }
+}
+
+/// Why a match ended
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    /// A player won the match on points
+    Score,
+    /// The game clock passed the time limit
+    Clock,
+    /// The log stopped before the match was decided (forfeit mode)
+    Abandoned,
+}
+
+/// Result of a finished match
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    LeftWin,
+    RightWin,
+    /// Tied when the clock ran out, with draws allowed
+    Draw,
+}
+
+/// Which paddle's move ended an abandoned match's valid prefix
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violator {
+    Left,
+    Right,
+    /// Both paddles broke the same rule on the same pair
+    Both,
+}
+
+impl fmt::Display for EndReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndReason::Score => write!(f, "score"),
+            EndReason::Clock => write!(f, "clock"),
+            EndReason::Abandoned => write!(f, "abandoned"),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::LeftWin => write!(f, "left wins"),
+            Outcome::RightWin => write!(f, "right wins"),
+            Outcome::Draw => write!(f, "draw"),
+        }
+    }
+}
+
+impl Violator {
+    /// The side(s) that broke a rule, from whether each paddle did
+    pub fn of(left: bool, right: bool) -> Option<Self> {
+        match (left, right) {
+            (true, true) => Some(Violator::Both),
+            (true, false) => Some(Violator::Left),
+            (false, true) => Some(Violator::Right),
+            (false, false) => None,
+        }
+    }
+}
+
+impl fmt::Display for Violator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violator::Left => write!(f, "left paddle"),
+            Violator::Right => write!(f, "right paddle"),
+            Violator::Both => write!(f, "both paddles"),
+        }
+    }
+}
+
+/// Score of a match in progress under some [`ScoringRules`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MatchScore {
+    rules: ScoringRules,
+    /// Points in the game in progress
+    pub left: u32,
+    pub right: u32,
+    /// Games finished so far, in order
+    pub games: Vec<GameScore>,
+    points_played: u32,
+    clock_expired: bool,
+    end: Option<EndReason>,
+}
+
+impl MatchScore {
+    pub fn new(rules: ScoringRules) -> Self {
+        Self {
+            rules,
+            left: 0,
+            right: 0,
+            games: Vec::new(),
+            points_played: 0,
+            clock_expired: false,
+            end: None,
+        }
+    }
+
+    /// Award a point, closing the game if it is won. Returns true once the match is over.
+    pub fn point(&mut self, left_scored: bool) -> bool {
+        if left_scored {
+            self.left += 1;
+        } else {
+            self.right += 1;
+        }
+        self.points_played += 1;
+
+        if self.clock_expired {
+            // Golden point after a tie at the deadline: whoever scores it wins
+            self.close_game();
+            self.end = Some(EndReason::Clock);
+            return self.is_over();
+        }
+
+        let (leader, trailer) = if self.left > self.right {
+            (self.left, self.right)
+        } else {
+            (self.right, self.left)
+        };
+        if leader >= self.rules.points_to_win && leader - trailer >= self.rules.win_by {
+            self.close_game();
+        }
+
+        let (left, right) = self.games_won();
+        let needed = self.rules.games_to_win();
+        if left >= needed || right >= needed {
+            self.end = Some(EndReason::Score);
+        }
+        self.is_over()
+    }
+
+    /// The game clock has passed the time limit. Returns true if that ends
+    /// the match, or false if it is tied and goes to a golden point.
+    pub fn expire_clock(&mut self) -> bool {
+        self.clock_expired = true;
+        let (left, right) = self.games_won_with_current();
+        if left != right || self.rules.allow_draw {
+            self.close_game();
+            self.end = Some(EndReason::Clock);
+        } else if self.rules.best_of > 1 {
+            // The interrupted game stands, and the golden point is a game of its own
+            self.close_game();
+        }
+        self.is_over()
+    }
+
+    /// The match stopped before it was decided: the score stands, with no winner
+    pub fn abandon(&mut self) {
+        self.end = Some(EndReason::Abandoned);
+    }
+
+    /// Score of the game in progress, if any points were played in it
+    pub fn game_in_progress(&self) -> Option<GameScore> {
+        (self.left + self.right > 0).then_some(GameScore {
+            left: self.left,
+            right: self.right,
+        })
+    }
+
+    /// Whether the game clock has passed the time limit
+    pub fn clock_expired(&self) -> bool {
+        self.clock_expired
+    }
+
+    /// Record the game in progress, if any points were played in it
+    fn close_game(&mut self) {
+        if self.left + self.right > 0 {
+            self.games.push(GameScore {
+                left: self.left,
+                right: self.right,
+            });
+        }
+        self.left = 0;
+        self.right = 0;
+    }
+
+    /// Games won by (left, right); a game tied when the clock ran out counts for neither
+    pub fn games_won(&self) -> (u32, u32) {
+        let left = self.games.iter().filter(|g| g.left > g.right).count() as u32;
+        let right = self.games.iter().filter(|g| g.right > g.left).count() as u32;
+        (left, right)
+    }
+
+    /// Games won, counting the game in progress for whoever leads it
+    fn games_won_with_current(&self) -> (u32, u32) {
+        let (left, right) = self.games_won();
+        (
+            left + (self.left > self.right) as u32,
+            right + (self.right > self.left) as u32,
+        )
+    }
+
+    /// Whether the match has ended
+    pub fn is_over(&self) -> bool {
+        self.end.is_some()
+    }
+
+    /// Why the match ended, once it has
+    pub fn end_reason(&self) -> Option<EndReason> {
+        self.end
+    }
+
+    /// Result of the match, once it has ended (`None` if abandoned)
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.end? == EndReason::Abandoned {
+            return None;
+        }
+        let (left, right) = self.games_won();
+        Some(match left.cmp(&right) {
+            core::cmp::Ordering::Greater => Outcome::LeftWin,
+            core::cmp::Ordering::Less => Outcome::RightWin,
+            core::cmp::Ordering::Equal => Outcome::Draw,
+        })
+    }
+
+    /// Match result as (left, right): points for a single game, games won for best-of-N
+    pub fn result(&self) -> (u32, u32) {
+        match (self.rules.best_of, self.games.first()) {
+            (1, Some(game)) => (game.left, game.right),
+            (1, None) => (self.left, self.right),
+            _ => self.games_won(),
+        }
+    }
+
+    /// Feed every field into a checkpoint digest (see [`Checkpoint::digest`](crate::Checkpoint::digest))
+    pub(crate) fn hash_into(&self, hasher: &mut Sha256) {
+        let end = match self.end {
+            None => 0u32,
+            Some(EndReason::Score) => 1,
+            Some(EndReason::Clock) => 2,
+            Some(EndReason::Abandoned) => 3,
+        };
+        for word in self.rules.to_words() {
+            hasher.update(word.to_le_bytes());
+        }
+        for word in [self.left, self.right, self.points_played, self.clock_expired as u32, end] {
+            hasher.update(word.to_le_bytes());
+        }
+        hasher.update((self.games.len() as u32).to_le_bytes());
+        for game in &self.games {
+            hasher.update(game.left.to_le_bytes());
+            hasher.update(game.right.to_le_bytes());
+        }
+    }
+
+    /// Direction of the next serve (+1 = toward the right player)
+    ///
+    /// `scorer_dir` is the side of the player who just scored, and
+    /// `first_serve_dir` the direction of the match's opening serve.
+    pub fn serve_dir(&self, scorer_dir: i32, first_serve_dir: i32) -> i32 {
+        match self.rules.serve {
+            Serve::ToScorer => scorer_dir,
+            Serve::Alternate if self.points_played.is_multiple_of(2) => first_serve_dir,
+            Serve::Alternate => -first_serve_dir,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+// Sessions: several consecutive games in one log, proven in one guest run
+//
+// A [`SessionLog`] holds the events of each game in play order. Game IDs are
+// not chosen per game but derived from the session seed with
+// [`session_game_id`], so one random seed at the start of the session gives
+// every game its own serve entropy and replay protection. Each game is
+// validated exactly as a single-game log with that ID would be, and the
+// journal lists every game's output next to the session totals.
+
+use crate::{
+    encode_events, EndReason, LogError, Outcome, Physics, Ruleset, ValidateLogOutput, Violator, I, MAX_EVENTS,
+    RULESET_WORDS,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Domain separation prefix for [`session_game_id`]
+pub const SESSION_ID_PREFIX: &[u8; 9] = b"PONGSESv1";
+
+/// Maximum number of games in a session
+pub const MAX_SESSION_GAMES: u32 = 64;
+
+/// Number of u32 words written before the per-game event counts:
+/// `[session_seed, games, log_version]` followed by the [`Ruleset`] words
+pub const SESSION_HEADER_WORDS: usize = 3 + RULESET_WORDS;
+
+/// Game ID of game `index` (from 0) of a session: the first four bytes of
+/// SHA-256 over the prefix, the seed and the index (all little-endian)
+pub fn session_game_id(session_seed: u32, index: u32) -> u32 {
+    let mut hasher = Sha256::new();
+    hasher.update(SESSION_ID_PREFIX);
+    hasher.update(session_seed.to_le_bytes());
+    hasher.update(index.to_le_bytes());
+    let digest: [u8; 32] = hasher.finalize().into();
+    u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]])
+}
+
+/// Session log format (JSON), the multi-game counterpart of [`CompactLog`](crate::CompactLog)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SessionLog {
+    pub v: u32,
+    /// Random seed chosen at the start of the session; game IDs derive from it
+    pub session_seed: u32,
+    /// Events of each game, in play order
+    pub games: Vec<Vec<String>>,
+}
+
+impl SessionLog {
+    /// Parse every game's events as Q16.16
+    pub fn parse_games(&self) -> Result<Vec<Vec<I>>, LogError> {
+        self.games
+            .iter()
+            .map(|events| {
+                events
+                    .iter()
+                    .enumerate()
+                    .map(|(index, s)| s.parse().map_err(|_| LogError::InvalidEvent { index }))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Derived ID of each game
+    pub fn game_ids(&self) -> Vec<u32> {
+        (0..self.games.len() as u32).map(|i| session_game_id(self.session_seed, i)).collect()
+    }
+}
+
+/// Input for session validation (used by host to feed the guest)
+///
+/// Like [`ValidateLogInput`](crate::ValidateLogInput), the guest reads raw
+/// words: [`header`](Self::header), then the event count of each game, then
+/// every game's events in the wire encoding, back to back.
+#[derive(Debug, Clone)]
+pub struct SessionInput {
+    pub session_seed: u32,
+    pub log_version: u32,
+    pub rules: Ruleset,
+    /// Events of each game, Q16.16
+    pub games: Vec<Vec<I>>,
+}
+
+impl SessionInput {
+    /// Header words: `[session_seed, games, log_version]` then the ruleset words
+    pub fn header(&self) -> [u32; SESSION_HEADER_WORDS] {
+        let mut header = [0u32; SESSION_HEADER_WORDS];
+        header[..3].copy_from_slice(&[self.session_seed, self.games.len() as u32, self.log_version]);
+        header[3..].copy_from_slice(&self.rules.to_words());
+        header
+    }
+
+    /// Event count of each game
+    pub fn events_lens(&self) -> Vec<u32> {
+        self.games.iter().map(|g| g.len() as u32).collect()
+    }
+
+    /// Events of all games in the guest wire encoding
+    pub fn event_bytes(&self) -> Result<Vec<u8>, LogError> {
+        let mut bytes = Vec::new();
+        for events in &self.games {
+            bytes.extend(encode_events(events)?);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Check a session header, before the event counts are read
+pub fn check_session_header(header: &[u32; SESSION_HEADER_WORDS]) -> Result<Ruleset, &'static str> {
+    let [_, games, log_version, ..] = *header;
+    if games == 0 {
+        return Err("No games in session");
+    }
+    if games > MAX_SESSION_GAMES {
+        return Err("Too many games in session (exceeds MAX_SESSION_GAMES limit)");
+    }
+    Physics::for_log_version(log_version).ok_or("Unsupported log version")?;
+    let mut rule_words = [0u32; RULESET_WORDS];
+    rule_words.copy_from_slice(&header[3..]);
+    let rules = Ruleset::from_words(rule_words).ok_or("Invalid ruleset")?;
+    rules.scoring.validate()?;
+    Ok(rules)
+}
+
+/// Check the event counts of a session's games, before any event is read
+///
+/// Bounds the whole allocation; each game is then checked like a single log.
+pub fn check_session_events(events_lens: &[u32]) -> Result<(), &'static str> {
+    let total: u64 = events_lens.iter().map(|&n| n as u64).sum();
+    if total > MAX_EVENTS as u64 {
+        return Err("Too many events in session (exceeds MAX_EVENTS limit)");
+    }
+    Ok(())
+}
+
+/// Results across a session's games (left and right are the sides, as in each game)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionTotals {
+    pub games: u32,
+    /// Games that validated as fair
+    pub fair_games: u32,
+    /// Fair games by outcome (abandoned games have none)
+    pub left_wins: u32,
+    pub right_wins: u32,
+    pub draws: u32,
+    /// Fair games abandoned before they were decided (only their prefix was fair)
+    pub abandoned: u32,
+    /// Abandoned games whose valid prefix ended in a violation by each side
+    pub left_violations: u32,
+    pub right_violations: u32,
+    /// Sum of each fair game's left/right score, abandoned games as they stood
+    pub left_score: u32,
+    pub right_score: u32,
+}
+
+impl SessionTotals {
+    /// Add up the outputs of a session's games
+    pub fn of(games: &[ValidateLogOutput]) -> Self {
+        let mut totals = Self {
+            games: games.len() as u32,
+            ..Self::default()
+        };
+        for out in games.iter().filter(|out| out.fair) {
+            totals.fair_games += 1;
+            match out.outcome {
+                Some(Outcome::LeftWin) => totals.left_wins += 1,
+                Some(Outcome::RightWin) => totals.right_wins += 1,
+                Some(Outcome::Draw) => totals.draws += 1,
+                None => {}
+            }
+            if out.end_reason == Some(EndReason::Abandoned) {
+                totals.abandoned += 1;
+            }
+            match out.violator {
+                Some(Violator::Left) => totals.left_violations += 1,
+                Some(Violator::Right) => totals.right_violations += 1,
+                Some(Violator::Both) => {
+                    totals.left_violations += 1;
+                    totals.right_violations += 1;
+                }
+                None => {}
+            }
+            totals.left_score += out.left_score;
+            totals.right_score += out.right_score;
+        }
+        totals
+    }
+}
+
+/// Public output of a session proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SessionOutput {
+    pub session_seed: u32,
+    pub log_version: u32,
+    pub rules: Ruleset,
+    /// True if every game was fair
+    pub fair: bool,
+    /// Why the session was rejected, or the first unfair game's reason (prefixed with its index)
+    pub reason: Option<String>,
+    /// Each game's output, as its own proof would commit it (empty if the session was rejected)
+    pub games: Vec<ValidateLogOutput>,
+    pub totals: SessionTotals,
+}
+
+impl SessionOutput {
+    /// Output for a session whose header was accepted, from its games' outputs in order
+    pub fn from_games(session_seed: u32, log_version: u32, rules: Ruleset, games: Vec<ValidateLogOutput>) -> Self {
+        let reason = games.iter().enumerate().find(|(_, out)| !out.fair).map(|(index, out)| {
+            format!("Game {}: {}", index, out.reason.as_deref().unwrap_or("Unknown"))
+        });
+        Self {
+            session_seed,
+            log_version,
+            rules,
+            fair: reason.is_none(),
+            reason,
+            totals: SessionTotals::of(&games),
+            games,
+        }
+    }
+
+    pub fn invalid(session_seed: u32, msg: &str) -> Self {
+        Self {
+            session_seed,
+            log_version: 0,
+            rules: Ruleset::STANDARD,
+            fair: false,
+            reason: Some(msg.into()),
+            games: Vec::new(),
+            totals: SessionTotals::default(),
+        }
+    }
+}
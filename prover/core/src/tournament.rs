@@ -0,0 +1,193 @@
+// Single-elimination brackets, proven from the pong proofs of their games
+//
+// A [`Bracket`] seeds players into slots. The tournament guest verifies each
+// game's receipt by composition, feeds the winners through
+// [`Bracket::champion`] and commits a [`TournamentOutput`]. Game journals do
+// not identify the players, so the bracket binds them through the game ID:
+// each match must be played with [`Bracket::game_id`], derived from the
+// bracket, the match and the two players in its slot, and the lower slot of a
+// match plays left. A game between other players, or from another bracket,
+// has a different ID and does not fit the slot.
+
+use crate::{Outcome, ProvenGame, Ruleset, ValidateLogOutput};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Domain separation prefix for [`Bracket::digest`]
+pub const BRACKET_HASH_PREFIX: &[u8; 9] = b"PONGBRKv2";
+
+/// Domain separation prefix for [`Bracket::game_id`]
+pub const BRACKET_GAME_PREFIX: &[u8; 9] = b"PONGBGMv1";
+
+/// Player identifier assigned by the organizer
+pub type PlayerId = u32;
+
+/// A single-elimination bracket
+///
+/// Matches are numbered round by round, top to bottom: the first round's
+/// `players.len() / 2` matches pair slots `2m` and `2m + 1`, and each later
+/// match pairs the winners of two consecutive matches of the round before.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Bracket {
+    /// Organizer-chosen tournament ID, so the same players get different
+    /// game IDs in different brackets
+    pub id: u32,
+    /// Players in seed slot order (a power of two, at least 2)
+    pub players: Vec<PlayerId>,
+    /// Rules every game must have been played under
+    pub rules: Ruleset,
+}
+
+/// Where playing out a bracket has got to
+enum Progress {
+    /// Game ID of the next match to play
+    Next(u32),
+    Champion(PlayerId),
+}
+
+/// The winner of one game, as read from its journal
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameResult {
+    pub game_id: u32,
+    pub left_won: bool,
+}
+
+impl GameResult {
+    /// Result of a proven game, if it was fair, played under `rules` and has a winner
+    pub fn from_output(out: &ValidateLogOutput, rules: &Ruleset) -> Result<Self, &'static str> {
+        if !out.fair {
+            return Err("Bracket game was not fair");
+        }
+        if out.rules != *rules {
+            return Err("Bracket game was played under different rules");
+        }
+        let left_won = match out.outcome {
+            Some(Outcome::LeftWin) => true,
+            Some(Outcome::RightWin) => false,
+            // Draws and abandoned matches send nobody through
+            _ => return Err("Bracket game has no winner"),
+        };
+        Ok(Self {
+            game_id: out.game_id,
+            left_won,
+        })
+    }
+}
+
+impl Bracket {
+    /// Check the bracket is well formed: a power of two of distinct players
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let n = self.players.len();
+        if n < 2 || !n.is_power_of_two() {
+            return Err("Bracket needs a power of two players, at least 2");
+        }
+        if self.players.iter().enumerate().any(|(i, p)| self.players[..i].contains(p)) {
+            return Err("Bracket seeds a player twice");
+        }
+        Ok(())
+    }
+
+    /// Game ID match `index` must be played with, `left` against `right`
+    ///
+    /// The first 4 bytes (little-endian) of SHA-256 over the bracket ID, the
+    /// match index and both players.
+    pub fn game_id(&self, index: u32, left: PlayerId, right: PlayerId) -> u32 {
+        derive_game_id(BRACKET_GAME_PREFIX, [self.id, index, left, right])
+    }
+
+    /// Play the bracket out from one result per match (in match order), returning the champion
+    ///
+    /// Each result must be the game [`Bracket::game_id`] names for its match;
+    /// the winner moves on to the next round.
+    pub fn champion(&self, results: &[GameResult]) -> Result<PlayerId, &'static str> {
+        match self.progress(results)? {
+            Progress::Champion(champion) => Ok(champion),
+            Progress::Next(_) => Err("Bracket needs a result for every match"),
+        }
+    }
+
+    /// Game ID of the next match, given the results of the matches before it
+    /// (in match order), or None once the bracket has a champion
+    pub fn next_game_id(&self, results: &[GameResult]) -> Result<Option<u32>, &'static str> {
+        match self.progress(results)? {
+            Progress::Next(game_id) => Ok(Some(game_id)),
+            Progress::Champion(_) => Ok(None),
+        }
+    }
+
+    fn progress(&self, results: &[GameResult]) -> Result<Progress, &'static str> {
+        self.validate()?;
+
+        let mut round = self.players.clone();
+        let mut results = results.iter();
+        let mut index = 0;
+        while round.len() > 1 {
+            let mut next = Vec::with_capacity(round.len() / 2);
+            for pair in round.chunks(2) {
+                let game_id = self.game_id(index, pair[0], pair[1]);
+                let Some(result) = results.next() else {
+                    return Ok(Progress::Next(game_id));
+                };
+                if result.game_id != game_id {
+                    return Err("Game does not match its bracket slot");
+                }
+                next.push(if result.left_won { pair[0] } else { pair[1] });
+                index += 1;
+            }
+            round = next;
+        }
+        if results.next().is_some() {
+            return Err("Bracket has more results than matches");
+        }
+        Ok(Progress::Champion(round[0]))
+    }
+
+    /// SHA-256 over the bracket ID, players and rules, identifying the bracket in the journal
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(BRACKET_HASH_PREFIX);
+        hasher.update(self.id.to_le_bytes());
+        hasher.update((self.players.len() as u32).to_le_bytes());
+        for player in &self.players {
+            hasher.update(player.to_le_bytes());
+        }
+        for word in self.rules.to_words() {
+            hasher.update(word.to_le_bytes());
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// First 4 bytes (little-endian) of SHA-256 over `prefix` and `words`, for
+/// game IDs that bind a match to its players
+pub(crate) fn derive_game_id(prefix: &[u8; 9], words: [u32; 4]) -> u32 {
+    let mut hasher = Sha256::new();
+    hasher.update(prefix);
+    for word in words {
+        hasher.update(word.to_le_bytes());
+    }
+    let hash: [u8; 32] = hasher.finalize().into();
+    u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+/// Input to the tournament guest (serde)
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TournamentInput {
+    pub bracket: Bracket,
+    /// Pong guest image IDs the games may have been proven by
+    pub pong_image_ids: Vec<[u32; 8]>,
+    /// One game per match, in match order, each played with its [`Bracket::game_id`]
+    pub games: Vec<ProvenGame>,
+}
+
+/// Public output of a tournament proof
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TournamentOutput {
+    /// [`Bracket::digest`] of the bracket that was played
+    pub bracket_hash: [u8; 32],
+    pub champion: PlayerId,
+    /// Pong guest image IDs the games were verified against; a verifier
+    /// must check these are releases it trusts
+    pub pong_image_ids: Vec<[u32; 8]>,
+}
@@ -0,0 +1,130 @@
+// Versioned sine/cosine for serve and bounce angles
+//
+// ## Log v1: CORDIC
+// - Angles in Q16.16 radians
+// - 8 iterations, ~0.23° accuracy
+// - Kept bit-for-bit so v1 logs replay exactly as they always have
+//
+// ## Log v2: lookup table
+// - Angles in Q16.16 degrees
+// - One sine entry per whole degree in [0°, 90°], written out below;
+//   cosine reads the same table mirrored
+// - Whole degrees (every serve angle) hit a table entry exactly
+// - Fractional degrees (bounce angles) interpolate linearly between the two
+//   neighbouring entries, which keeps the error within a few Q16.16 units
+//
+// Both are pure integer arithmetic at runtime, so results are identical on
+// every platform and inside the zkVM.
+
+use crate::I;
+
+/// π in Q16.16 (π × 65536 ≈ 205887)
+pub const PI_Q16: I = 205887;
+
+/// Largest angle (degrees, either sign) the lookup table covers
+pub const TRIG_TABLE_MAX_DEG: i32 = 90;
+
+const TABLE_LEN: usize = TRIG_TABLE_MAX_DEG as usize + 1;
+
+/// sin(d°) in Q16.16 for d in 0..=90, rounded to the nearest unit
+///
+/// Written out rather than computed so the physics (and the guest image ID)
+/// never depend on the build machine's floating point.
+#[rustfmt::skip]
+const SIN_Q16: [I; TABLE_LEN] = [
+    0, 1144, 2287, 3430, 4572, 5712, 6850, 7987, 9121, 10252,
+    11380, 12505, 13626, 14742, 15855, 16962, 18064, 19161, 20252, 21336,
+    22415, 23486, 24550, 25607, 26656, 27697, 28729, 29753, 30767, 31772,
+    32768, 33754, 34729, 35693, 36647, 37590, 38521, 39441, 40348, 41243,
+    42126, 42995, 43852, 44695, 45525, 46341, 47143, 47930, 48703, 49461,
+    50203, 50931, 51643, 52339, 53020, 53684, 54332, 54963, 55578, 56175,
+    56756, 57319, 57865, 58393, 58903, 59396, 59870, 60326, 60764, 61183,
+    61584, 61966, 62328, 62672, 62997, 63303, 63589, 63856, 64104, 64332,
+    64540, 64729, 64898, 65048, 65177, 65287, 65376, 65446, 65496, 65526,
+    65536,
+];
+
+/// Trig implementation used to replay a log (see [`Physics`](crate::Physics))
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trig {
+    /// [`cordic_sin_cos`] on Q16.16 radians (log v1)
+    Cordic,
+    /// [`table_sin_cos`] on Q16.16 degrees (log v2)
+    Table,
+}
+
+// CORDIC sin/cos with ITER=8 (optimized for performance)
+// Constants in Q16.16 format for i64 fixed-point
+const ITER: usize = 8;
+const ATAN_Q16: [I; ITER] = [
+    51472,   // atan(2^0)  = 45°     in Q16.16
+    30386,   // atan(2^-1) = 26.565° in Q16.16
+    16055,   // atan(2^-2) = 14.036° in Q16.16
+    8150,    // atan(2^-3) = 7.125°  in Q16.16
+    4091,    // atan(2^-4) = 3.576°  in Q16.16
+    2047,    // atan(2^-5) = 1.790°  in Q16.16
+    1024,    // atan(2^-6) = 0.895°  in Q16.16
+    512,     // atan(2^-7) = 0.448°  in Q16.16
+];
+const K_Q16: I = 39797;  // CORDIC gain ~0.6073 in Q16.16
+
+/// (sin, cos) of an angle in Q16.16 radians using 8-iteration CORDIC
+#[inline(always)]
+pub fn cordic_sin_cos(angle: I) -> (I, I) {
+    // Validate input angle is reasonable (±4π is more than sufficient for game physics)
+    const MAX_ANGLE: I = PI_Q16 * 8;
+    assert!(
+        angle.abs() < MAX_ANGLE,
+        "CORDIC: angle out of valid range (|angle| must be < 8π)"
+    );
+
+    let mut x = K_Q16;
+    let mut y: I = 0;
+    let mut z = angle;
+    for (shift, &atan) in ATAN_Q16.iter().enumerate() {
+        let di: I = if z >= 0 { 1 } else { -1 };
+        let x_shift = x >> shift;
+        let y_shift = y >> shift;
+
+        // CORDIC rotation step
+        // These multiplications cannot overflow: di is ±1, values are small after shifts
+        let x_term = di * y_shift;
+        let y_term = di * x_shift;
+        let z_term = di * atan;
+
+        x -= x_term;
+        y += y_term;
+        z -= z_term;
+    }
+    (y, x)
+}
+
+/// (sin, cos) of an angle in Q16.16 degrees using the precomputed table
+///
+/// Panics if the angle is outside ±[`TRIG_TABLE_MAX_DEG`].
+#[inline(always)]
+pub fn table_sin_cos(angle_deg: I) -> (I, I) {
+    const MAX_ANGLE: I = (TRIG_TABLE_MAX_DEG as I) << 16;
+    assert!(
+        (-MAX_ANGLE..=MAX_ANGLE).contains(&angle_deg),
+        "Trig table: angle out of valid range (|angle| must be <= 90°)"
+    );
+
+    let magnitude = angle_deg.abs();
+    let idx = (magnitude >> 16) as usize;
+    let frac = magnitude & 0xFFFF;
+
+    // At exactly 90° there is no next entry, but frac is zero there
+    let next = if idx + 1 < TABLE_LEN { idx + 1 } else { idx };
+    let sin = lerp(SIN_Q16[idx], SIN_Q16[next], frac);
+    let cos = lerp(SIN_Q16[TABLE_LEN - 1 - idx], SIN_Q16[TABLE_LEN - 1 - next], frac);
+
+    // sin is odd, cos is even
+    if angle_deg < 0 { (-sin, cos) } else { (sin, cos) }
+}
+
+/// Linear interpolation between two table entries, `frac` in Q16.16 [0, 1)
+#[inline(always)]
+fn lerp(a: I, b: I, frac: I) -> I {
+    a + (((b - a) * frac) >> 16)
+}
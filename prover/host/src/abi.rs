@@ -0,0 +1,70 @@
+use crate::{executor_env, pong_input, prover_opts, ReceiptKind};
+use core::{AbiJournal, CompactLog, Ruleset};
+use methods::{ABI_JOURNAL_ELF, ABI_JOURNAL_ID};
+use risc0_zkvm::{default_prover, Receipt};
+use serde::{Deserialize, Serialize};
+
+/// Proof of a game whose journal is the fixed-width [`AbiJournal`] layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbiProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Decoded journal
+    pub journal: AbiJournal,
+}
+
+/// Generate a proof for a game log with the ABI journal guest.
+///
+/// The log is validated exactly as by [`generate_pong_proof_with_rules`](crate::generate_pong_proof_with_rules);
+/// only the journal encoding differs.
+///
+/// # Returns
+/// * `Ok(AbiProof)` - The proof with its decoded journal
+/// * `Err` - If the log version is unsupported, an event does not parse, or proving fails
+pub fn generate_abi_proof(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+) -> Result<AbiProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Generating ABI journal proof for game {} with receipt kind: {}",
+        log.game_id,
+        receipt_kind
+    );
+
+    let input = pong_input(log, rules)?;
+    let env = executor_env(&input)?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, ABI_JOURNAL_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to generate proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let journal = AbiJournal::decode(&receipt.journal.bytes)?;
+
+    tracing::info!("ABI journal proof generated successfully (receipt kind: {})", receipt_kind);
+
+    Ok(AbiProof {
+        receipt,
+        receipt_kind,
+        journal,
+    })
+}
+
+/// Verify an ABI journal proof against the ABI journal guest, and that its stored journal matches the receipt
+pub fn verify_abi_proof(proof: &AbiProof) -> Result<(), Box<dyn std::error::Error>> {
+    proof
+        .receipt
+        .verify(ABI_JOURNAL_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    let journal = AbiJournal::decode(&proof.receipt.journal.bytes)?;
+    if journal != proof.journal {
+        return Err("ABI journal does not match the receipt".into());
+    }
+    Ok(())
+}
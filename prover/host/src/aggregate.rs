@@ -0,0 +1,135 @@
+use crate::composition::{check_pong_image_ids, pong_image_ids, proven_game};
+use crate::{prover_opts, PongProof, ReceiptKind};
+use core::{AggregateInput, AggregateOutput, GameLeaf, InclusionProof};
+use methods::{AGGREGATE_ELF, AGGREGATE_ID};
+use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use serde::{Deserialize, Serialize};
+
+/// One receipt standing for many game proofs, with an inclusion proof per game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Decoded journal: Merkle root over the games, their count and the pong image IDs
+    pub output: AggregateOutput,
+
+    /// Inclusion proof of each game against `output.root`, in aggregation order
+    pub inclusion_proofs: Vec<InclusionProof>,
+}
+
+impl AggregateProof {
+    /// Inclusion proof for a game, if it is part of this aggregate
+    pub fn inclusion_proof(&self, game_id: u32) -> Option<&InclusionProof> {
+        self.inclusion_proofs.iter().find(|p| p.leaf.game_id == game_id)
+    }
+}
+
+/// Aggregate game proofs into a single receipt.
+///
+/// The proofs are verified here and then again inside the aggregation guest
+/// by composition. Its journal is the Merkle root over each game's
+/// `(game_id, log_hash, scores, fair)`, so a verifier checks one receipt and
+/// then any game against the root with its inclusion proof.
+///
+/// # Returns
+/// * `Ok(AggregateProof)` - The aggregate receipt with an inclusion proof per game
+/// * `Err` - If there are no proofs, a game appears twice, or a proof does not verify
+pub fn generate_aggregate_proof(
+    proofs: &[PongProof],
+    receipt_kind: ReceiptKind,
+) -> Result<AggregateProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Aggregating {} pong proofs with receipt kind: {}",
+        proofs.len(),
+        receipt_kind
+    );
+    if proofs.is_empty() {
+        return Err("No proofs to aggregate".into());
+    }
+
+    let mut games = Vec::with_capacity(proofs.len());
+    for (i, proof) in proofs.iter().enumerate() {
+        if proofs[..i].iter().any(|p| p.game_id == proof.game_id) {
+            return Err(format!("Game {} appears twice", proof.game_id).into());
+        }
+        games.push(proven_game(proof)?);
+    }
+    let leaves: Vec<GameLeaf> = games.iter().map(|g| GameLeaf::from_output(&g.output)).collect();
+
+    let input = AggregateInput {
+        pong_image_ids: pong_image_ids(&games),
+        games,
+    };
+    let mut builder = ExecutorEnv::builder();
+    for proof in proofs {
+        builder.add_assumption(proof.receipt.clone());
+    }
+    let env = builder.write(&input)?.build()?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, AGGREGATE_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to generate aggregate proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let output: AggregateOutput = receipt.journal.decode()?;
+
+    let inclusion_proofs = (0..leaves.len())
+        .filter_map(|i| InclusionProof::new(&leaves, i))
+        .collect::<Vec<_>>();
+    if !inclusion_proofs.iter().all(|p| p.verify(&output.root)) {
+        return Err("Guest committed a different Merkle root".into());
+    }
+
+    tracing::info!("Aggregate proof generated successfully ({} games)", output.games);
+
+    Ok(AggregateProof {
+        receipt,
+        receipt_kind,
+        output,
+        inclusion_proofs,
+    })
+}
+
+/// Verify an aggregate proof.
+///
+/// Checks the receipt against the aggregation guest, that the stored output
+/// matches the journal, that every pong image ID the games were verified
+/// against belongs to a registered, non-revoked guest release, and that each
+/// stored inclusion proof leads to the committed root.
+pub fn verify_aggregate_proof(proof: &AggregateProof) -> Result<(), Box<dyn std::error::Error>> {
+    proof
+        .receipt
+        .verify(AGGREGATE_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    let output: AggregateOutput = proof.receipt.journal.decode()?;
+    if output != proof.output {
+        return Err("Aggregate output does not match the journal".into());
+    }
+    check_pong_image_ids(&output.pong_image_ids)?;
+
+    for inclusion in &proof.inclusion_proofs {
+        if inclusion.leaves != output.games || !inclusion.verify(&output.root) {
+            return Err(format!("Inclusion proof for game {} does not match the root", inclusion.leaf.game_id).into());
+        }
+    }
+    Ok(())
+}
+
+/// Write an aggregate proof to `path` as pretty-printed JSON
+pub fn save_aggregate_proof(proof: &AggregateProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(proof)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Read an aggregate proof from a JSON file at `path`
+pub fn load_aggregate_proof(path: &str) -> Result<AggregateProof, Box<dyn std::error::Error>> {
+    let json = std::fs::read_to_string(path)?;
+    let proof: AggregateProof = serde_json::from_str(&json)?;
+    Ok(proof)
+}
@@ -0,0 +1,61 @@
+// Helpers for guests that verify pong proofs by composition (tournament, leaderboard)
+//
+// A composing guest re-serializes each game's `ValidateLogOutput` to verify it
+// against the game's receipt, so it can only take games from releases that
+// commit the current journal layout. Older games have to be proven again.
+use crate::{registry, verify_pong_proof, GuestRelease, JournalLayout, PongProof, GUEST_REGISTRY};
+use core::{ProvenGame, ValidateLogOutput};
+
+/// Verify a pong proof and wrap its journal for a composing guest
+pub(crate) fn proven_game(proof: &PongProof) -> Result<ProvenGame, Box<dyn std::error::Error>> {
+    verify_pong_proof(proof).map_err(|e| format!("Proof of game {} does not verify: {}", proof.game_id, e))?;
+
+    let image_id = registry::claimed_image_id(&proof.receipt)?;
+    let release =
+        registry::release_for_image_id(GUEST_REGISTRY, image_id).ok_or("Game was proven by an unregistered guest")?;
+    check_journal_layout(release)?;
+    let output: ValidateLogOutput = proof.receipt.journal.decode()?;
+    Ok(ProvenGame {
+        image_id: image_id
+            .as_words()
+            .try_into()
+            .map_err(|_| "Image ID is not 8 words")?,
+        output,
+    })
+}
+
+/// The distinct image IDs of `games`, in first-seen order
+pub(crate) fn pong_image_ids<'a>(games: impl IntoIterator<Item = &'a ProvenGame>) -> Vec<[u32; 8]> {
+    let mut ids: Vec<[u32; 8]> = Vec::new();
+    for game in games {
+        if !ids.contains(&game.image_id) {
+            ids.push(game.image_id);
+        }
+    }
+    ids
+}
+
+/// Check every pong image ID a composing guest committed belongs to a registered, non-revoked release
+pub(crate) fn check_pong_image_ids(image_ids: &[[u32; 8]]) -> Result<(), Box<dyn std::error::Error>> {
+    for &image_id in image_ids {
+        let release = registry::release_for_image_id(GUEST_REGISTRY, image_id.into())
+            .ok_or("Games were proven by an unregistered guest")?;
+        if release.revoked {
+            return Err(format!("Games were proven by revoked guest version {}", release.version).into());
+        }
+        check_journal_layout(release)?;
+    }
+    Ok(())
+}
+
+/// Check a composing guest can read the journals of `release`
+fn check_journal_layout(release: &GuestRelease) -> Result<(), Box<dyn std::error::Error>> {
+    if release.journal != JournalLayout::CURRENT {
+        return Err(format!(
+            "Games proven by guest version {} commit an older journal layout and must be proven again",
+            release.version
+        )
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use crate::{prove_receipt, registry, JournalLayout, ReceiptKind, CURRENT_GUEST_VERSION, GUEST_REGISTRY};
+use core::{
+    CompactLog, DisclosedOutput, Disclosure, EndReason, Outcome, Ruleset, ValidateLogInput, ValidateLogOutput,
+    Violator, LOG_SALT_BYTES,
+};
+use risc0_zkvm::Receipt;
+use serde::{Deserialize, Serialize};
+
+/// Decoded journal of the full-log guest, in whichever shape the proof disclosed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisclosedJournal {
+    /// The usual journal of a full-disclosure proof
+    Full(ValidateLogOutput),
+    /// The journal of a winner-only or score-only proof
+    Partial(DisclosedOutput),
+}
+
+impl DisclosedJournal {
+    /// Which fields the journal reveals
+    pub fn disclosure(&self) -> Disclosure {
+        match self {
+            Self::Full(_) => Disclosure::Full,
+            Self::Partial(out) => out.disclosure,
+        }
+    }
+
+    pub fn fair(&self) -> bool {
+        match self {
+            Self::Full(out) => out.fair,
+            Self::Partial(out) => out.fair,
+        }
+    }
+
+    /// Winner, or a draw (None if rejected or abandoned)
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self {
+            Self::Full(out) => out.outcome,
+            Self::Partial(out) => out.outcome,
+        }
+    }
+
+    /// Why the match ended (None if rejected)
+    pub fn end_reason(&self) -> Option<EndReason> {
+        match self {
+            Self::Full(out) => out.end_reason,
+            Self::Partial(out) => out.end_reason,
+        }
+    }
+
+    /// Whose violation ended an abandoned match's valid prefix
+    pub fn violator(&self) -> Option<Violator> {
+        match self {
+            Self::Full(out) => out.violator,
+            Self::Partial(out) => out.violator,
+        }
+    }
+
+    /// `(left_score, right_score)`, unless the proof is winner-only
+    pub fn score(&self) -> Option<(u32, u32)> {
+        match self {
+            Self::Full(out) => Some((out.left_score, out.right_score)),
+            Self::Partial(out) => out.score,
+        }
+    }
+
+    pub fn game_id(&self) -> u32 {
+        match self {
+            Self::Full(out) => out.game_id,
+            Self::Partial(out) => out.game_id,
+        }
+    }
+
+    pub fn rules(&self) -> Ruleset {
+        match self {
+            Self::Full(out) => out.rules,
+            Self::Partial(out) => out.rules,
+        }
+    }
+
+    /// Log commitment, salted if the proof was
+    pub fn log_hash(&self) -> [u8; 32] {
+        match self {
+            Self::Full(out) => out.log_hash_sha256,
+            Self::Partial(out) => out.log_hash_sha256,
+        }
+    }
+}
+
+/// Proof of a game that reveals only the fields its disclosure mode selects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisclosedProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Version of the guest that produced the receipt
+    pub guest_version: u32,
+
+    /// Decoded journal
+    pub journal: DisclosedJournal,
+}
+
+/// Generate a proof that discloses only part of the game's result.
+///
+/// The full-log guest validates the log as usual, then commits the fields
+/// `disclosure` selects: for [`Disclosure::WinnerOnly`] whether the game was
+/// fair and who won, for [`Disclosure::ScoreOnly`] the final score as well,
+/// each with the game ID, the rules and the log commitment. The margin, the
+/// per-game breakdown and the event count stay private. `salt` salts the log
+/// commitment as in [`crate::generate_salted_pong_proof`] and is required
+/// for the partial modes: an unsalted commitment would let anyone holding a
+/// candidate log check it, and with it the hidden fields.
+///
+/// # Returns
+/// * `Ok(DisclosedProof)` - The proof with its decoded journal
+/// * `Err` - If a partial mode has no salt, the salt is all zeros, the log version is unsupported, or proving fails
+pub fn generate_disclosed_pong_proof(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+    disclosure: Disclosure,
+    salt: Option<[u8; LOG_SALT_BYTES]>,
+) -> Result<DisclosedProof, Box<dyn std::error::Error>> {
+    if salt.is_some_and(|salt| core::salt_from_bytes(salt).is_none()) {
+        return Err("The all-zero salt is reserved for unsalted logs".into());
+    }
+    if disclosure != Disclosure::Full && salt.is_none() {
+        return Err(format!("A {} proof needs a salt for its log commitment", disclosure).into());
+    }
+    tracing::info!(
+        "Generating {} pong proof for game {} with receipt kind: {}",
+        disclosure,
+        log.game_id,
+        receipt_kind
+    );
+
+    let input = ValidateLogInput {
+        salt,
+        disclosure,
+        ..crate::pong_input(log, rules)?
+    };
+    let receipt = prove_receipt(&input, receipt_kind)?;
+    let journal = decode_disclosed_journal(&receipt, disclosure, JournalLayout::CURRENT)?;
+
+    Ok(DisclosedProof {
+        receipt,
+        receipt_kind,
+        guest_version: CURRENT_GUEST_VERSION,
+        journal,
+    })
+}
+
+/// Decode the journal of a full-log guest receipt, expecting the `disclosure` shape.
+///
+/// A partial journal starts with [`core::DISCLOSED_OUTPUT_TAG`] and a full one
+/// with its `fair` flag, so neither decodes as the other. `layout` is the
+/// journal layout of the release that produced the receipt. This does not
+/// verify the receipt.
+pub fn decode_disclosed_journal(
+    receipt: &Receipt,
+    disclosure: Disclosure,
+    layout: JournalLayout,
+) -> Result<DisclosedJournal, Box<dyn std::error::Error>> {
+    let tagged = receipt.journal.bytes.get(..4) == Some(&core::DISCLOSED_OUTPUT_TAG.to_le_bytes()[..]);
+    match disclosure {
+        Disclosure::Full => {
+            if tagged {
+                return Err("Journal is a partial disclosure, not the full result".into());
+            }
+            Ok(DisclosedJournal::Full(layout.decode(&receipt.journal)?))
+        }
+        Disclosure::WinnerOnly | Disclosure::ScoreOnly => {
+            if !tagged {
+                return Err(format!("Journal is the full result, not a {} disclosure", disclosure).into());
+            }
+            let out = layout.decode_disclosed(&receipt.journal)?;
+            if !out.is_well_formed() {
+                return Err("Malformed disclosed journal".into());
+            }
+            if out.disclosure != disclosure {
+                return Err(format!("Journal is a {} disclosure, not {}", out.disclosure, disclosure).into());
+            }
+            Ok(DisclosedJournal::Partial(out))
+        }
+    }
+}
+
+/// Verify a disclosed proof against the registered guest that produced it,
+/// and that its stored journal matches the receipt's
+pub fn verify_disclosed_proof(proof: &DisclosedProof) -> Result<(), Box<dyn std::error::Error>> {
+    let release = registry::verify_registered(GUEST_REGISTRY, &proof.receipt)?;
+    if release.version != proof.guest_version {
+        return Err(format!(
+            "Proof claims guest version {} but was produced by version {}",
+            proof.guest_version, release.version
+        )
+        .into());
+    }
+
+    let journal = decode_disclosed_journal(&proof.receipt, proof.journal.disclosure(), release.journal)?;
+    if journal != proof.journal {
+        return Err("Disclosed journal does not match the receipt".into());
+    }
+    Ok(())
+}
+
+/// Check that `log` is the log a disclosed proof commits to.
+///
+/// Like [`crate::verify_log_commitment`], but a partial journal has no event
+/// count, so only the game ID and the (salted) commitment are compared. This
+/// does not verify the receipt; use [`verify_disclosed_proof`] for that.
+pub fn verify_disclosed_log_commitment(
+    proof: &DisclosedProof,
+    log: &CompactLog,
+    salt: Option<&[u8; LOG_SALT_BYTES]>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let game_id = proof.journal.game_id();
+    if log.game_id != game_id {
+        return Err(format!("Log is for game {} but the proof is for game {}", log.game_id, game_id).into());
+    }
+    let event_bytes = core::encode_events(&log.parse_events()?)?;
+    if core::log_commitment(salt, &event_bytes) != proof.journal.log_hash() {
+        return Err(match salt {
+            None => "Log does not match the proof's log hash (a salted proof needs its salt)",
+            Some(_) => "Log and salt do not match the proof's log hash",
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Write a disclosed proof to `path` as pretty-printed JSON
+pub fn save_disclosed_proof(proof: &DisclosedProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(proof)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Load a disclosed proof from raw file contents (JSON)
+pub fn load_disclosed_proof(bytes: &[u8]) -> Result<DisclosedProof, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_slice(bytes)?)
+}
@@ -0,0 +1,124 @@
+use crate::{groth16_seal, PongProof};
+use alloy_primitives::{keccak256, Bytes, FixedBytes};
+use alloy_sol_types::{sol_data, SolType};
+use risc0_zkvm::sha::{Digest, Digestible};
+use risc0_zkvm::{Groth16Receipt, Groth16ReceiptVerifierParameters, InnerReceipt, Receipt, ReceiptClaim};
+
+/// Signature of `IRiscZeroVerifier.verify`, RISC Zero's standard verifier
+/// interface implemented by the verifier router
+pub const EVM_VERIFY_SIGNATURE: &str = "verify(bytes,bytes32,bytes32)";
+
+// `sol!` expands to `::core` paths, which resolve to our `core` crate here,
+// so the call is encoded from its parameter types instead
+type VerifyParams = (sol_data::Bytes, sol_data::FixedBytes<32>, sol_data::FixedBytes<32>);
+
+/// Length of the verifier selector that prefixes an EVM seal
+pub const EVM_SELECTOR_LEN: usize = 4;
+
+/// Arguments of `IRiscZeroVerifier.verify` for a Groth16 pong proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmProof {
+    /// Verifier selector followed by the Groth16 seal
+    pub seal: Vec<u8>,
+    /// Image ID the receipt was proven against
+    pub image_id: [u8; 32],
+    /// SHA-256 of the journal bytes
+    pub journal_digest: [u8; 32],
+}
+
+/// Selector of the Groth16 verifier this build produces seals for.
+///
+/// The router dispatches on the first 4 bytes of the seal: the first 4 bytes of
+/// the digest of the Groth16 verifier parameters. It changes with the RISC Zero
+/// release, so a router must have the matching verifier registered.
+pub fn evm_selector() -> [u8; EVM_SELECTOR_LEN] {
+    let digest = Groth16ReceiptVerifierParameters::default().digest();
+    let mut selector = [0u8; EVM_SELECTOR_LEN];
+    selector.copy_from_slice(&digest.as_bytes()[..EVM_SELECTOR_LEN]);
+    selector
+}
+
+/// Function selector of [`EVM_VERIFY_SIGNATURE`]: the first 4 bytes of its Keccak-256
+fn verify_function_selector() -> [u8; 4] {
+    let hash = keccak256(EVM_VERIFY_SIGNATURE);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+impl EvmProof {
+    /// ABI-encoded `verify(seal, imageId, journalDigest)` call
+    pub fn calldata(&self) -> Vec<u8> {
+        let params = (
+            Bytes::copy_from_slice(&self.seal),
+            FixedBytes(self.image_id),
+            FixedBytes(self.journal_digest),
+        );
+        [verify_function_selector().as_slice(), &VerifyParams::abi_encode_params(&params)].concat()
+    }
+
+    /// Decode a `verify(seal, imageId, journalDigest)` call
+    pub fn from_calldata(calldata: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        let params = calldata
+            .strip_prefix(verify_function_selector().as_slice())
+            .ok_or("Not a verify call: wrong function selector")?;
+        let (seal, image_id, journal_digest) =
+            VerifyParams::abi_decode_params(params).map_err(|e| format!("Not a verify call: {}", e))?;
+        Ok(Self {
+            seal: seal.to_vec(),
+            image_id: image_id.0,
+            journal_digest: journal_digest.0,
+        })
+    }
+}
+
+/// Turn a Groth16 pong proof into the arguments of `IRiscZeroVerifier.verify`.
+///
+/// The image ID is the one in the receipt claim, so a segmented proof is
+/// exported against the segment-join guest.
+///
+/// # Returns
+/// * `Ok(EvmProof)` - Seal (with selector), image ID and journal digest
+/// * `Err` - If the receipt is not Groth16 or its claim is pruned
+pub fn evm_proof(proof: &PongProof) -> Result<EvmProof, Box<dyn std::error::Error>> {
+    let seal = groth16_seal(&proof.receipt)?;
+    let claim = proof
+        .receipt
+        .claim()
+        .map_err(|e| format!("Failed to read receipt claim: {}", e))?;
+    let image_id = claim
+        .as_value()
+        .map_err(|_| "Receipt claim is pruned; image ID is unavailable")?
+        .pre
+        .digest();
+
+    Ok(EvmProof {
+        seal: [evm_selector().as_slice(), &seal].concat(),
+        image_id: image_id.into(),
+        journal_digest: proof.receipt.journal.bytes.digest().into(),
+    })
+}
+
+/// Verify the arguments of an EVM verify call natively, as the router would.
+///
+/// Checks the selector, that `journal` hashes to the journal digest, and the
+/// Groth16 seal against the claim `ReceiptClaim::ok(image_id, journal)`.
+pub fn verify_evm_proof(proof: &EvmProof, journal: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let (selector, seal) = proof
+        .seal
+        .split_at_checked(EVM_SELECTOR_LEN)
+        .ok_or("Seal is too short for a selector")?;
+    if selector != evm_selector() {
+        return Err(format!("Unknown verifier selector 0x{}", hex::encode(selector)).into());
+    }
+    if journal.digest() != Digest::from(proof.journal_digest) {
+        return Err("Journal does not match the journal digest".into());
+    }
+
+    let image_id = Digest::from(proof.image_id);
+    let params = Groth16ReceiptVerifierParameters::default();
+    let claim = ReceiptClaim::ok(image_id, journal.to_vec());
+    let inner = Groth16Receipt::new(seal.to_vec(), claim.into(), params.digest());
+    Receipt::new(InnerReceipt::Groth16(inner), journal.to_vec())
+        .verify(image_id)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+    Ok(())
+}
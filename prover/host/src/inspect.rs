@@ -0,0 +1,282 @@
+use crate::{registry, JournalLayout, SavedProof, GUEST_REGISTRY};
+use core::{EndReason, GameScore, Outcome, ValidateLogOutput};
+use join_methods::JOIN_SEGMENTS_ID;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ID;
+use risc0_zkvm::sha::{Digest, Digestible};
+use risc0_zkvm::InnerReceipt;
+use serde::Serialize;
+use std::fmt;
+
+/// Everything we can learn about a saved proof without verifying it
+#[derive(Debug, Serialize)]
+pub struct ProofInspection {
+    /// Receipt kind as found in the receipt itself (composite, succinct, groth16 or fake)
+    pub receipt_kind: String,
+    /// Image ID of the guest that produced the receipt (hex)
+    pub image_id: Option<String>,
+    /// Whether `image_id` matches a guest built into this binary
+    pub image_id_matches_guest: bool,
+    /// Registered guest version for `image_id`, if any
+    pub guest_version: Option<u32>,
+    /// Whether that guest version has been revoked
+    pub revoked: bool,
+    /// Digest of the receipt claim (hex)
+    pub claim_digest: Option<String>,
+    /// Journal decoded as `ValidateLogOutput` in the layout of the release that produced it, if it decodes
+    pub journal: Option<ValidateLogOutput>,
+    /// Raw journal bytes (hex)
+    pub journal_bytes: String,
+    /// Size of the seal in bytes
+    pub seal_size: usize,
+    /// Number of segment receipts (composite receipts only)
+    pub segments: Option<usize>,
+    /// Whether the metadata stored alongside the receipt agrees with the journal
+    pub metadata_consistent: bool,
+    /// Human readable description of each disagreement (or decoding error)
+    pub issues: Vec<String>,
+}
+
+/// Inspect a saved proof: receipt kind, image ID, claim digest, journal and
+/// whether the outer `SavedProof` metadata agrees with what the journal commits.
+///
+/// This does not verify the receipt; use `verify_pong_proof` for that.
+pub fn inspect_proof(saved: &SavedProof) -> ProofInspection {
+    let receipt = &saved.proof.receipt;
+    let mut issues = Vec::new();
+
+    let (receipt_kind, segments) = match &receipt.inner {
+        InnerReceipt::Composite(inner) => ("composite", Some(inner.segments.len())),
+        InnerReceipt::Succinct(_) => ("succinct", None),
+        InnerReceipt::Groth16(_) => ("groth16", None),
+        InnerReceipt::Fake(_) => ("fake", None),
+        _ => ("unknown", None),
+    };
+
+    let (image_id, claim_digest) = match receipt.claim() {
+        Ok(claim) => {
+            let image_id = match claim.as_value() {
+                Ok(value) => Some(value.pre.digest()),
+                Err(_) => {
+                    issues.push("Receipt claim is pruned; image ID is unavailable".to_string());
+                    None
+                }
+            };
+            (image_id, Some(claim.digest()))
+        }
+        Err(e) => {
+            issues.push(format!("Failed to read receipt claim: {}", e));
+            (None, None)
+        }
+    };
+    // Either the full-log guest or the segment-join guest built into this binary
+    let image_id_matches_guest = image_id == Some(Digest::from(GUEST_CODE_FOR_ZK_PROOF_ID))
+        || image_id == Some(Digest::from(JOIN_SEGMENTS_ID));
+
+    let release = image_id.and_then(|id| registry::release_for_image_id(GUEST_REGISTRY, id));
+    match release {
+        Some(release) if release.version != saved.proof.guest_version => issues.push(format!(
+            "proof.guest_version is {} but the image ID belongs to version {}",
+            saved.proof.guest_version, release.version
+        )),
+        None if image_id.is_some() => {
+            issues.push("Image ID does not belong to any registered guest version".to_string())
+        }
+        _ => {}
+    }
+
+    // Read the journal in the layout of the release that produced it, or of
+    // the release the proof claims if the image ID is unknown
+    let layout = release
+        .or_else(|| registry::release(GUEST_REGISTRY, saved.proof.guest_version))
+        .map_or(JournalLayout::CURRENT, |r| r.journal);
+    let journal: Option<ValidateLogOutput> = match layout.decode(&receipt.journal) {
+        Ok(out) => Some(out),
+        Err(e) => {
+            issues.push(e.to_string());
+            None
+        }
+    };
+
+    if receipt_kind != saved.proof.receipt_kind.to_string() && receipt_kind != "fake" {
+        issues.push(format!(
+            "proof.receipt_kind is {} but the receipt is {}",
+            saved.proof.receipt_kind, receipt_kind
+        ));
+    }
+    if saved.guest_version != saved.proof.guest_version {
+        issues.push(format!(
+            "guest_version is {} but proof.guest_version is {}",
+            saved.guest_version, saved.proof.guest_version
+        ));
+    }
+    if saved.receipt_kind != saved.proof.receipt_kind.to_string() {
+        issues.push(format!(
+            "receipt_kind is {} but proof.receipt_kind is {}",
+            saved.receipt_kind, saved.proof.receipt_kind
+        ));
+    }
+    if let Some(out) = &journal {
+        check_metadata(saved, out, &mut issues);
+    }
+
+    ProofInspection {
+        receipt_kind: receipt_kind.to_string(),
+        image_id: image_id.map(|d| hex::encode(d.as_bytes())),
+        image_id_matches_guest,
+        guest_version: release.map(|r| r.version),
+        revoked: release.is_some_and(|r| r.revoked),
+        claim_digest: claim_digest.map(|d| hex::encode(d.as_bytes())),
+        journal,
+        journal_bytes: hex::encode(&receipt.journal.bytes),
+        seal_size: receipt.seal_size(),
+        segments,
+        metadata_consistent: issues.is_empty(),
+        issues,
+    }
+}
+
+/// Compare both the display metadata and the embedded `PongProof` fields against the journal
+fn check_metadata(saved: &SavedProof, out: &ValidateLogOutput, issues: &mut Vec<String>) {
+    let proof = &saved.proof;
+    let journal_hash = hex::encode(out.log_hash_sha256);
+
+    let mut mismatch = |field: &str, claimed: String, committed: String| {
+        if claimed != committed {
+            issues.push(format!(
+                "{} is {} but the journal commits {}",
+                field, claimed, committed
+            ));
+        }
+    };
+
+    mismatch("left_score", saved.left_score.to_string(), out.left_score.to_string());
+    mismatch("right_score", saved.right_score.to_string(), out.right_score.to_string());
+    mismatch("log_hash", saved.log_hash.clone(), journal_hash.clone());
+    mismatch("events_len", saved.events_len.to_string(), out.events_len.to_string());
+    mismatch("fair", saved.fair.to_string(), out.fair.to_string());
+    mismatch("reason", format!("{:?}", saved.reason), format!("{:?}", out.reason));
+    mismatch("game_id", saved.game_id.to_string(), out.game_id.to_string());
+    mismatch("log_version", saved.log_version.to_string(), out.log_version.to_string());
+    mismatch("rules", saved.rules.to_string(), out.rules.to_string());
+    mismatch("games", format_games(&saved.games), format_games(&out.games));
+    mismatch(
+        "end",
+        format_end(saved.end_reason, saved.outcome),
+        format_end(out.end_reason, out.outcome),
+    );
+    mismatch(
+        "last_valid_pair",
+        format!("{:?}", saved.last_valid_pair),
+        format!("{:?}", out.last_valid_pair),
+    );
+    mismatch("violator", format!("{:?}", saved.violator), format!("{:?}", out.violator));
+
+    mismatch("proof.left_score", proof.left_score.to_string(), out.left_score.to_string());
+    mismatch("proof.right_score", proof.right_score.to_string(), out.right_score.to_string());
+    mismatch("proof.log_hash", hex::encode(proof.log_hash), journal_hash);
+    mismatch("proof.events_len", proof.events_len.to_string(), out.events_len.to_string());
+    mismatch("proof.fair", proof.fair.to_string(), out.fair.to_string());
+    mismatch("proof.reason", format!("{:?}", proof.reason), format!("{:?}", out.reason));
+    mismatch("proof.game_id", proof.game_id.to_string(), out.game_id.to_string());
+    mismatch("proof.log_version", proof.log_version.to_string(), out.log_version.to_string());
+    mismatch("proof.rules", proof.rules.to_string(), out.rules.to_string());
+    mismatch("proof.games", format_games(&proof.games), format_games(&out.games));
+    mismatch(
+        "proof.end",
+        format_end(proof.end_reason, proof.outcome),
+        format_end(out.end_reason, out.outcome),
+    );
+    mismatch(
+        "proof.last_valid_pair",
+        format!("{:?}", proof.last_valid_pair),
+        format!("{:?}", out.last_valid_pair),
+    );
+    mismatch("proof.violator", format!("{:?}", proof.violator), format!("{:?}", out.violator));
+}
+
+/// Game scores as "3-1, 2-3, 3-0"
+pub fn format_games(games: &[GameScore]) -> String {
+    games.iter().map(|g| g.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// How a match ended, as "left wins on score", "draw on the clock" or "abandoned"
+pub fn format_end(end_reason: Option<EndReason>, outcome: Option<Outcome>) -> String {
+    match (outcome, end_reason) {
+        (_, Some(EndReason::Abandoned)) => "abandoned".to_string(),
+        (Some(outcome), Some(EndReason::Score)) => format!("{} on score", outcome),
+        (Some(outcome), Some(EndReason::Clock)) => format!("{} on the clock", outcome),
+        _ => "none".to_string(),
+    }
+}
+
+impl fmt::Display for ProofInspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unknown = || "unknown".to_string();
+
+        writeln!(f, "Receipt")?;
+        writeln!(f, "  Kind: {}", self.receipt_kind)?;
+        writeln!(f, "  Image ID: {}", self.image_id.clone().unwrap_or_else(unknown))?;
+        writeln!(
+            f,
+            "  Matches this guest: {}",
+            if self.image_id_matches_guest { "yes" } else { "no" }
+        )?;
+        match self.guest_version {
+            Some(version) => writeln!(
+                f,
+                "  Guest version: {}{}",
+                version,
+                if self.revoked { " (REVOKED)" } else { "" }
+            )?,
+            None => writeln!(f, "  Guest version: unregistered")?,
+        }
+        writeln!(f, "  Claim digest: {}", self.claim_digest.clone().unwrap_or_else(unknown))?;
+        writeln!(f, "  Seal size: {} bytes", self.seal_size)?;
+        if let Some(segments) = self.segments {
+            writeln!(f, "  Segments: {}", segments)?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "Journal")?;
+        match &self.journal {
+            Some(out) => {
+                writeln!(f, "  Fair: {}", out.fair)?;
+                if let Some(reason) = &out.reason {
+                    writeln!(f, "  Reason: {}", reason)?;
+                }
+                writeln!(f, "  Score: {}-{}", out.left_score, out.right_score)?;
+                writeln!(f, "  Events: {}", out.events_len)?;
+                writeln!(f, "  Log hash: 0x{}", hex::encode(out.log_hash_sha256))?;
+                writeln!(f, "  Game ID: {}", out.game_id)?;
+                writeln!(f, "  Log version: {}", out.log_version)?;
+                writeln!(f, "  Rules: {}", out.rules)?;
+                if out.games.len() > 1 {
+                    writeln!(f, "  Games: {}", format_games(&out.games))?;
+                }
+                if out.fair {
+                    writeln!(f, "  Result: {}", format_end(out.end_reason, out.outcome))?;
+                }
+                if let Some(pair) = out.last_valid_pair {
+                    writeln!(f, "  Last valid pair: {}", pair)?;
+                }
+                if let Some(violator) = out.violator {
+                    writeln!(f, "  Violator: {}", violator)?;
+                }
+            }
+            None => writeln!(f, "  (could not be decoded)")?,
+        }
+        writeln!(f, "  Raw bytes ({}): {}", self.journal_bytes.len() / 2, self.journal_bytes)?;
+        writeln!(f)?;
+
+        writeln!(f, "Metadata")?;
+        if self.metadata_consistent {
+            writeln!(f, "  Consistent with journal: yes")?;
+        } else {
+            writeln!(f, "  Consistent with journal: no")?;
+            for issue in &self.issues {
+                writeln!(f, "  - {}", issue)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use crate::composition::{check_pong_image_ids, pong_image_ids, proven_game};
+use crate::{prover_opts, PongProof, ReceiptKind};
+use core::{Leaderboard, LeaderboardInput, LeaderboardOutput, Pairing, RatedGame};
+use methods::{LEADERBOARD_ELF, LEADERBOARD_ID};
+use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use serde::{Deserialize, Serialize};
+
+/// Proof of a batch of rating updates, with the ladder state it produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Decoded journal: previous and new ladder roots, games rated and pong image IDs
+    pub output: LeaderboardOutput,
+
+    /// Ladder state after the batch (its root is `output.root`)
+    pub leaderboard: Leaderboard,
+}
+
+/// Generate a proof of rating `games` on top of `leaderboard`.
+///
+/// Each game is a pong proof and the players the ladder paired for it, played
+/// with the pairing's [`Leaderboard::game_id`]. The proofs are verified here
+/// and then again inside the rating guest by composition, which applies the
+/// Elo update to the ladder state and rejects games not played for their
+/// pairing, and unfair, unfinished or already rated games.
+///
+/// # Returns
+/// * `Ok(LeaderboardProof)` - The proof and the updated ladder
+/// * `Err` - If a game proof does not verify or a game cannot be rated
+pub fn generate_leaderboard_proof(
+    leaderboard: &Leaderboard,
+    games: &[(Pairing, PongProof)],
+    receipt_kind: ReceiptKind,
+) -> Result<LeaderboardProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Generating leaderboard proof for {} games with receipt kind: {}",
+        games.len(),
+        receipt_kind
+    );
+
+    // Rate the batch here first, for a clear error instead of a failed guest
+    let mut updated = leaderboard.clone();
+    let mut rated = Vec::with_capacity(games.len());
+    let mut receipts = Vec::with_capacity(games.len());
+    for (pairing, proof) in games {
+        let game = proven_game(proof)?;
+        updated
+            .apply(pairing, &game.output)
+            .map_err(|e| format!("Cannot rate game {}: {}", game.output.game_id, e))?;
+        rated.push(RatedGame { pairing: *pairing, game });
+        receipts.push(proof.receipt.clone());
+    }
+
+    let input = LeaderboardInput {
+        leaderboard: leaderboard.clone(),
+        pong_image_ids: pong_image_ids(rated.iter().map(|r| &r.game)),
+        games: rated,
+    };
+    let mut builder = ExecutorEnv::builder();
+    for receipt in receipts {
+        builder.add_assumption(receipt);
+    }
+    let env = builder.write(&input)?.build()?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, LEADERBOARD_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to generate leaderboard proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let output: LeaderboardOutput = receipt.journal.decode()?;
+    if output.root != updated.root() {
+        return Err("Guest computed a different leaderboard".into());
+    }
+
+    tracing::info!("Leaderboard proof generated successfully ({} games rated)", output.games.len());
+
+    Ok(LeaderboardProof {
+        receipt,
+        receipt_kind,
+        output,
+        leaderboard: updated,
+    })
+}
+
+/// Verify a leaderboard proof.
+///
+/// Checks the receipt against the rating guest, that the stored output and
+/// ladder state match the journal, and that every pong image ID the games
+/// were verified against belongs to a registered, non-revoked guest release.
+/// Callers chaining batches should also check `output.prev_root` is the root
+/// they last accepted.
+pub fn verify_leaderboard_proof(proof: &LeaderboardProof) -> Result<(), Box<dyn std::error::Error>> {
+    proof
+        .receipt
+        .verify(LEADERBOARD_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    let output: LeaderboardOutput = proof.receipt.journal.decode()?;
+    if output != proof.output {
+        return Err("Leaderboard output does not match the journal".into());
+    }
+    if proof.leaderboard.root() != output.root {
+        return Err("Leaderboard state does not match the committed root".into());
+    }
+
+    check_pong_image_ids(&output.pong_image_ids)
+}
@@ -1,91 +1,89 @@
-use core::{CompactLog, ValidateLogInput, ValidateLogOutput};
-use methods::{GUEST_CODE_FOR_ZK_PROOF_ELF, GUEST_CODE_FOR_ZK_PROOF_ID};
+use core::{
+    CompactLog, Disclosure, Ruleset, SegmentOutput, SegmentStart, ValidateLogInput, ValidateLogOutput, EVENT_BYTES, LOG_SALT_BYTES,
+};
+use join_methods::JOIN_SEGMENTS_ELF;
+use methods::{GUEST_CODE_FOR_ZK_PROOF_ELF, REPLAY_SEGMENT_ELF};
 use risc0_zkvm::{default_prover, ExecutorEnv, ProverOpts, Receipt};
-use serde::{Deserialize, Serialize};
-
-/// Receipt type for proof generation
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
-pub enum ReceiptKind {
-    /// Composite receipt - fastest to generate, largest size (multiple MB)
-    Composite,
-    /// Succinct receipt - STARK proof, medium size (~200 KB)
-    Succinct,
-    /// Groth16 receipt - SNARK proof, smallest size (~200-300 bytes)
-    Groth16,
-}
-
-impl Default for ReceiptKind {
-    fn default() -> Self {
-        ReceiptKind::Succinct
-    }
-}
 
-impl std::str::FromStr for ReceiptKind {
-    type Err = String;
+mod abi;
+mod aggregate;
+mod composition;
+mod disclosure;
+mod evm;
+mod inspect;
+mod leaderboard;
+mod session;
+mod tournament;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "composite" => Ok(ReceiptKind::Composite),
-            "succinct" => Ok(ReceiptKind::Succinct),
-            "groth16" => Ok(ReceiptKind::Groth16),
-            _ => Err(format!(
-                "Invalid receipt kind: '{}'. Must be 'composite', 'succinct', or 'groth16'",
-                s
-            )),
-        }
-    }
-}
+pub use abi::{generate_abi_proof, verify_abi_proof, AbiProof};
+pub use aggregate::{
+    generate_aggregate_proof, load_aggregate_proof, save_aggregate_proof, verify_aggregate_proof, AggregateProof,
+};
+pub use disclosure::{
+    decode_disclosed_journal, generate_disclosed_pong_proof, load_disclosed_proof, save_disclosed_proof,
+    verify_disclosed_log_commitment, verify_disclosed_proof, DisclosedJournal, DisclosedProof,
+};
+pub use evm::{evm_proof, evm_selector, verify_evm_proof, EvmProof, EVM_SELECTOR_LEN, EVM_VERIFY_SIGNATURE};
+pub use inspect::{format_end, format_games, inspect_proof, ProofInspection};
+pub use leaderboard::{generate_leaderboard_proof, verify_leaderboard_proof, LeaderboardProof};
+pub use session::{
+    generate_session_proof, load_session_log, save_session_proof, session_env, verify_session_proof, SessionProof,
+};
+pub use tournament::{generate_tournament_proof, verify_tournament_proof, TournamentProof};
+pub use pong_verifier::registry::{self, GuestRelease, CURRENT_GUEST_VERSION};
+pub use pong_verifier::{load_proof, save_proof, JournalLayout, PongProof, ReceiptKind, SavedProof, GUEST_REGISTRY};
 
-impl std::fmt::Display for ReceiptKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ReceiptKind::Composite => write!(f, "composite"),
-            ReceiptKind::Succinct => write!(f, "succinct"),
-            ReceiptKind::Groth16 => write!(f, "groth16"),
-        }
+/// Prover options that produce a receipt of the given kind
+fn prover_opts(kind: ReceiptKind) -> ProverOpts {
+    match kind {
+        ReceiptKind::Composite => ProverOpts::composite(),
+        ReceiptKind::Succinct => ProverOpts::succinct(),
+        ReceiptKind::Groth16 => ProverOpts::groth16(),
     }
 }
 
-impl From<ReceiptKind> for risc0_zkvm::ReceiptKind {
-    fn from(kind: ReceiptKind) -> Self {
-        match kind {
-            ReceiptKind::Composite => risc0_zkvm::ReceiptKind::Composite,
-            ReceiptKind::Succinct => risc0_zkvm::ReceiptKind::Succinct,
-            ReceiptKind::Groth16 => risc0_zkvm::ReceiptKind::Groth16,
-        }
-    }
+/// The 256-byte seal of a Groth16 receipt, as on-chain verifiers take it
+pub fn groth16_seal(receipt: &Receipt) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let groth16 = receipt
+        .inner
+        .groth16()
+        .map_err(|e| format!("Not a Groth16 receipt: {}", e))?;
+    Ok(groth16.seal.clone())
 }
 
-/// Output from pong game validation proof
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PongProof {
-    /// The cryptographic receipt (ZK proof)
-    pub receipt: Receipt,
-
-    /// The type of receipt generated
-    pub receipt_kind: ReceiptKind,
-
-    /// Game result: left player score
-    pub left_score: u32,
-
-    /// Game result: right player score
-    pub right_score: u32,
-
-    /// SHA-256 hash of game log
-    pub log_hash: [u8; 32],
-
-    /// Number of events processed
-    pub events_len: u32,
-
-    /// Whether the game was fair
-    pub fair: bool,
+/// Build the zkVM execution environment for validating a log.
+///
+/// Writes the input header words, the salt slot, the disclosure word, then the
+/// events in the guest wire encoding (4 bytes per event), which the guest reads
+/// without serde.
+///
+/// # Returns
+/// * `Ok(ExecutorEnv)` - Ready to pass to a prover or executor
+/// * `Err` - If an event does not fit the wire encoding
+pub fn executor_env(
+    input: &ValidateLogInput,
+) -> Result<ExecutorEnv<'static>, Box<dyn std::error::Error>> {
+    let event_bytes = input.event_bytes()?;
 
-    /// Error reason if unfair
-    pub reason: Option<String>,
+    let env = ExecutorEnv::builder()
+        .write_slice(&input.header())
+        .write_slice(&input.salt_bytes())
+        .write_slice(&[input.disclosure.to_word()])
+        .write_slice(&event_bytes)
+        .build()?;
+    Ok(env)
+}
 
-    /// Game ID - for replay protection
-    pub game_id: u32,
+/// Load a compact log from raw file contents.
+///
+/// Accepts both the JSON encoding and the binary encoding, telling them
+/// apart by the binary magic bytes.
+pub fn load_log(bytes: &[u8]) -> Result<CompactLog, Box<dyn std::error::Error>> {
+    if core::is_binary_log(bytes) {
+        Ok(core::decode_binary_log(bytes)?)
+    } else {
+        Ok(serde_json::from_slice(bytes)?)
+    }
 }
 
 /// Generate a proof for pong game validation.
@@ -103,6 +101,18 @@ pub struct PongProof {
 pub fn generate_pong_proof(
     log: &CompactLog,
     receipt_kind: ReceiptKind,
+) -> Result<PongProof, Box<dyn std::error::Error>> {
+    generate_pong_proof_with_rules(log, receipt_kind, Ruleset::STANDARD)
+}
+
+/// Generate a proof for pong game validation under an optional ruleset.
+///
+/// Like [`generate_pong_proof`], but the guest additionally enforces `rules`
+/// (e.g. [`Ruleset::HUMAN_PLAY`]) and commits them in the journal.
+pub fn generate_pong_proof_with_rules(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
 ) -> Result<PongProof, Box<dyn std::error::Error>> {
     tracing::info!(
         "Generating pong proof for game {} with receipt kind: {}",
@@ -110,33 +120,55 @@ pub fn generate_pong_proof(
         receipt_kind
     );
 
-    if log.v != 1 {
-        return Err(format!("Unsupported log version: {}", log.v).into());
-    }
+    let input = pong_input(log, rules)?;
+    prove_log(&input, receipt_kind)
+}
 
-    // Parse events as Q16.16 (i64)
-    let mut events: Vec<i64> = Vec::with_capacity(log.events.len());
-    for s in log.events.iter() {
-        let v_q16: i64 = s.parse().map_err(|e| format!("Error parsing event: {}", e))?;
-        events.push(v_q16);
+/// Generate a proof whose log commitment is salted.
+///
+/// Like [`generate_pong_proof_with_rules`], but the journal's log hash is
+/// [`core::compute_salted_log_hash`] of the events, which tells nothing about
+/// the log to anyone without `salt`. The salt is private input: it is neither
+/// in the journal nor in the returned proof. Keep it, and share it only with
+/// parties who need to check the log with [`verify_log_commitment`].
+///
+/// # Returns
+/// * `Ok(PongProof)` - The proof with receipt and game results
+/// * `Err` - If the salt is all zeros (reserved for unsalted logs) or proof generation fails
+pub fn generate_salted_pong_proof(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+    salt: [u8; LOG_SALT_BYTES],
+) -> Result<PongProof, Box<dyn std::error::Error>> {
+    if core::salt_from_bytes(salt).is_none() {
+        return Err("The all-zero salt is reserved for unsalted logs".into());
     }
+    tracing::info!(
+        "Generating salted pong proof for game {} with receipt kind: {}",
+        log.game_id,
+        receipt_kind
+    );
 
     let input = ValidateLogInput {
-        events,
-        game_id: log.game_id,
+        salt: Some(salt),
+        ..pong_input(log, rules)?
     };
+    prove_log(&input, receipt_kind)
+}
+
+/// Prove a log input with the full-log guest
+fn prove_log(input: &ValidateLogInput, receipt_kind: ReceiptKind) -> Result<PongProof, Box<dyn std::error::Error>> {
+    pong_proof(prove_receipt(input, receipt_kind)?, receipt_kind)
+}
 
+/// Run the full-log guest on an input and prove it
+fn prove_receipt(input: &ValidateLogInput, receipt_kind: ReceiptKind) -> Result<Receipt, Box<dyn std::error::Error>> {
     // Build execution environment
-    let env = ExecutorEnv::builder()
-        .write(&input)?
-        .build()?;
+    let env = executor_env(input)?;
 
     // Configure prover options with desired receipt kind
-    let opts = match receipt_kind {
-        ReceiptKind::Composite => ProverOpts::composite(),
-        ReceiptKind::Succinct => ProverOpts::succinct(),
-        ReceiptKind::Groth16 => ProverOpts::groth16(),
-    };
+    let opts = prover_opts(receipt_kind);
 
     // Generate proof
     let prover = default_prover();
@@ -144,12 +176,35 @@ pub fn generate_pong_proof(
         .prove_with_opts(env, GUEST_CODE_FOR_ZK_PROOF_ELF, &opts)
         .map_err(|e| format!("Failed to generate proof: {}", e))?;
 
-    let receipt = prove_info.receipt;
+    tracing::info!("Proof generated successfully (receipt kind: {})", receipt_kind);
 
-    // Decode output from journal
-    let out: ValidateLogOutput = receipt.journal.decode()?;
+    Ok(prove_info.receipt)
+}
 
-    tracing::info!("Proof generated successfully (receipt kind: {})", receipt_kind);
+/// Check the current guest accepts the log and build its input
+fn pong_input(log: &CompactLog, rules: Ruleset) -> Result<ValidateLogInput, Box<dyn std::error::Error>> {
+    let current = registry::release(GUEST_REGISTRY, CURRENT_GUEST_VERSION)
+        .ok_or("Current guest version is missing from the registry")?;
+    if !current.supports_log_version(log.v) {
+        return Err(format!(
+            "Unsupported log version: {} (guest version {} accepts {:?})",
+            log.v, current.version, current.log_versions
+        )
+        .into());
+    }
+
+    // Parse events as Q16.16 (i64)
+    let events = log.parse_events()?;
+
+    Ok(ValidateLogInput {
+        rules,
+        ..ValidateLogInput::new(events, log.game_id, log.v)
+    })
+}
+
+/// Wrap a receipt of the full-log or join guest, decoding its journal
+fn pong_proof(receipt: Receipt, receipt_kind: ReceiptKind) -> Result<PongProof, Box<dyn std::error::Error>> {
+    let out: ValidateLogOutput = receipt.journal.decode()?;
 
     Ok(PongProof {
         receipt,
@@ -161,6 +216,198 @@ pub fn generate_pong_proof(
         fair: out.fair,
         reason: out.reason,
         game_id: out.game_id,
+        guest_version: CURRENT_GUEST_VERSION,
+        log_version: out.log_version,
+        rules: out.rules,
+        games: out.games,
+        end_reason: out.end_reason,
+        outcome: out.outcome,
+        last_valid_pair: out.last_valid_pair,
+        violator: out.violator,
+    })
+}
+
+/// Build the zkVM execution environment for one segment of a log.
+///
+/// Writes the segment start (serde), the number of events, then the events
+/// in the guest wire encoding.
+pub fn segment_env(
+    start: &SegmentStart,
+    event_bytes: &[u8],
+) -> Result<ExecutorEnv<'static>, Box<dyn std::error::Error>> {
+    let events_len = (event_bytes.len() / EVENT_BYTES) as u32;
+
+    let env = ExecutorEnv::builder()
+        .write(start)?
+        .write_slice(&[events_len])
+        .write_slice(event_bytes)
+        .build()?;
+    Ok(env)
+}
+
+/// Prove one segment of a log: whole event pairs replayed from `start`.
+///
+/// Segment receipts are always succinct, so the join guest can verify them
+/// by composition.
+///
+/// # Returns
+/// * `Ok((Receipt, SegmentOutput))` - The receipt and its decoded journal
+/// * `Err` - If proof generation fails
+pub fn prove_segment(
+    start: &SegmentStart,
+    event_bytes: &[u8],
+) -> Result<(Receipt, SegmentOutput), Box<dyn std::error::Error>> {
+    let env = segment_env(start, event_bytes)?;
+    let prove_info = default_prover()
+        .prove_with_opts(env, REPLAY_SEGMENT_ELF, &ProverOpts::succinct())
+        .map_err(|e| format!("Failed to prove segment: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let out: SegmentOutput = receipt.journal.decode()?;
+    Ok((receipt, out))
+}
+
+/// Join proven segments into a proof of the whole log.
+///
+/// The join guest re-reads the full log, verifies each segment receipt by
+/// composition and checks they chain from the opening serve over every
+/// event pair. Its journal is the same `ValidateLogOutput` the full-log
+/// guest commits.
+///
+/// Salted inputs are rejected: segment journals commit unsalted hashes of
+/// their events, which a composite receipt carries along. So are inputs
+/// that limit disclosure, as segment journals carry the full checkpoints.
+pub fn join_segments(
+    input: &ValidateLogInput,
+    segments: Vec<(Receipt, SegmentOutput)>,
+    receipt_kind: ReceiptKind,
+) -> Result<PongProof, Box<dyn std::error::Error>> {
+    if input.salt.is_some() {
+        return Err("Salted logs cannot be proven in segments".into());
+    }
+    if input.disclosure != Disclosure::Full {
+        return Err(format!("Logs proven in segments always disclose the full result, not {}", input.disclosure).into());
+    }
+    let event_bytes = input.event_bytes()?;
+
+    let mut builder = ExecutorEnv::builder();
+    let mut outputs = Vec::with_capacity(segments.len());
+    for (receipt, out) in segments {
+        builder.add_assumption(receipt);
+        outputs.push(out);
+    }
+    let env = builder
+        .write_slice(&input.header())
+        .write_slice(&input.salt_bytes())
+        .write_slice(&[input.disclosure.to_word()])
+        .write_slice(&event_bytes)
+        .write(&outputs)?
+        .build()?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, JOIN_SEGMENTS_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to join segments: {}", e))?;
+
+    pong_proof(prove_info.receipt, receipt_kind)
+}
+
+/// Generate a pong proof by proving the log in segments and joining them.
+///
+/// Each segment replays at most `pairs_per_segment` event pairs, so long logs
+/// can be proven in pieces (and in parallel by other provers) without one
+/// guest execution holding the whole replay. The resulting proof carries the
+/// same journal as [`generate_pong_proof_with_rules`].
+///
+/// # Returns
+/// * `Ok(PongProof)` - The joined proof
+/// * `Err` - If `pairs_per_segment` is zero, the embedded image IDs are out of date, or proving fails
+pub fn generate_segmented_pong_proof(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+    pairs_per_segment: u32,
+) -> Result<PongProof, Box<dyn std::error::Error>> {
+    if pairs_per_segment == 0 {
+        return Err("Segments must hold at least one event pair".into());
+    }
+    tracing::info!(
+        "Generating segmented pong proof for game {} ({} pairs per segment)",
+        log.game_id,
+        pairs_per_segment
+    );
+
+    let input = pong_input(log, rules)?;
+
+    // A header the guest rejects is proven invalid by the join guest alone
+    let mut segments = Vec::new();
+    if core::check_header(&input.header()).is_ok() {
+        let event_bytes = input.event_bytes()?;
+        // A torn final event (forfeit mode) is left to the join guest
+        let whole = event_bytes.len() / (2 * EVENT_BYTES) * 2 * EVENT_BYTES;
+        let mut start = SegmentStart::New {
+            game_id: input.game_id,
+            log_version: input.log_version,
+            rules,
+        };
+        for chunk in event_bytes[..whole].chunks(pairs_per_segment as usize * 2 * EVENT_BYTES) {
+            let (receipt, out) = prove_segment(&start, chunk)?;
+            tracing::info!("Proved segment through event pair {}", out.end.pairs);
+            start = SegmentStart::Resume(out.end.clone());
+            segments.push((receipt, out));
+        }
+    }
+
+    let proof = join_segments(&input, segments, receipt_kind)?;
+    tracing::info!("Segmented proof generated successfully (receipt kind: {})", receipt_kind);
+    Ok(proof)
+}
+
+/// Compress an existing proof into a smaller receipt kind.
+///
+/// The receipt is lifted/compressed by the recursion prover without
+/// re-executing the guest, so the journal (and therefore the game result)
+/// is carried over unchanged.
+///
+/// # Arguments
+/// * `proof` - The pong proof to compress
+/// * `to` - The target receipt kind (Succinct or Groth16)
+///
+/// # Returns
+/// * `Ok(PongProof)` - The proof with the compressed receipt
+/// * `Err` - If `to` is not smaller than the current kind, or compression fails
+pub fn compress_pong_proof(
+    proof: &PongProof,
+    to: ReceiptKind,
+) -> Result<PongProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Compressing pong proof for game {} from {} to {}",
+        proof.game_id,
+        proof.receipt_kind,
+        to
+    );
+
+    if to <= proof.receipt_kind {
+        return Err(format!(
+            "Cannot compress a {} receipt to {} (target must be smaller than the current kind)",
+            proof.receipt_kind, to
+        )
+        .into());
+    }
+
+    // Refuse to spend compression time on a receipt that does not verify
+    verify_pong_proof(proof)?;
+
+    let prover = default_prover();
+    let receipt = prover
+        .compress(&prover_opts(to), &proof.receipt)
+        .map_err(|e| format!("Failed to compress receipt: {}", e))?;
+
+    tracing::info!("Proof compressed successfully (receipt kind: {})", to);
+
+    Ok(PongProof {
+        receipt,
+        receipt_kind: to,
+        ..proof.clone()
     })
 }
 
@@ -168,7 +415,8 @@ pub fn generate_pong_proof(
 ///
 /// This function verifies that a PongProof's receipt is valid by checking:
 /// 1. The receipt is cryptographically valid (signature verification)
-/// 2. The receipt was generated by the GUEST_CODE_FOR_ZK_PROOF program (image ID check)
+/// 2. The receipt was generated by a registered, non-revoked guest release (image ID check)
+/// 3. The release matches the guest version recorded in the proof
 ///
 /// # Arguments
 /// * `proof` - The pong proof to verify
@@ -179,12 +427,46 @@ pub fn generate_pong_proof(
 pub fn verify_pong_proof(proof: &PongProof) -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Verifying pong proof receipt for game {}", proof.game_id);
 
-    // Verify the receipt against the image ID
-    proof
-        .receipt
-        .verify(GUEST_CODE_FOR_ZK_PROOF_ID)
-        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+    // Verify the receipt against the image ID of the release that produced it
+    let release = registry::verify_registered(GUEST_REGISTRY, &proof.receipt)?;
+    if release.version != proof.guest_version {
+        return Err(format!(
+            "Proof claims guest version {} but was produced by version {}",
+            proof.guest_version, release.version
+        )
+        .into());
+    }
 
-    tracing::info!("Receipt verification successful");
+    tracing::info!("Receipt verification successful (guest version {})", release.version);
+    Ok(())
+}
+
+/// Check that `log` is the log a proof commits to.
+///
+/// Recomputes the commitment of `log`, salted with `salt` for a proof from
+/// [`generate_salted_pong_proof`], and compares it and the game ID and event
+/// count with the proof's journal. This does not verify the receipt; use
+/// [`verify_pong_proof`] for that.
+pub fn verify_log_commitment(
+    proof: &PongProof,
+    log: &CompactLog,
+    salt: Option<&[u8; LOG_SALT_BYTES]>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let out: ValidateLogOutput = proof.receipt.journal.decode()?;
+    if log.game_id != out.game_id {
+        return Err(format!("Log is for game {} but the proof is for game {}", log.game_id, out.game_id).into());
+    }
+    let event_bytes = core::encode_events(&log.parse_events()?)?;
+    let events_len = (event_bytes.len() / EVENT_BYTES) as u32;
+    if events_len != out.events_len {
+        return Err(format!("Log has {} events but the proof committed {}", events_len, out.events_len).into());
+    }
+    if core::log_commitment(salt, &event_bytes) != out.log_hash_sha256 {
+        return Err(match salt {
+            None => "Log does not match the proof's log hash (a salted proof needs its salt)",
+            Some(_) => "Log and salt do not match the proof's log hash",
+        }
+        .into());
+    }
     Ok(())
 }
@@ -1,41 +1,39 @@
-use core::CompactLog;
-use host::{generate_pong_proof, verify_pong_proof, PongProof, ReceiptKind};
-use serde::{Deserialize, Serialize};
+use core::{
+    encode_binary_log, is_binary_log, CompactLog, Disclosure, EndReason, Outcome, Ruleset, ScoringRules, Serve, SessionLog,
+    LOG_SALT_BYTES,
+};
+use host::{
+    compress_pong_proof, evm_proof, generate_aggregate_proof, save_aggregate_proof, format_end, format_games, generate_pong_proof_with_rules, generate_segmented_pong_proof,
+    generate_disclosed_pong_proof, generate_salted_pong_proof, generate_session_proof, inspect_proof, load_disclosed_proof,
+    load_log, load_proof, load_session_log, save_disclosed_proof, save_proof, save_session_proof,
+    verify_disclosed_log_commitment, verify_disclosed_proof, verify_log_commitment, verify_pong_proof, DisclosedProof,
+    ReceiptKind, SavedProof,
+};
+use join_methods::JOIN_SEGMENTS_ID;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ID;
 use std::env;
 use std::fs;
 use std::str::FromStr;
 use std::time::Instant;
 
-/// Saved proof file format
-#[derive(Serialize, Deserialize)]
-struct SavedProof {
-    /// The complete proof with receipt
-    proof: PongProof,
-    /// Metadata for display
-    left_score: u32,
-    right_score: u32,
-    log_hash: String,
-    events_len: u32,
-    fair: bool,
-    reason: Option<String>,
-    game_id: u32,
-    receipt_kind: String,
-    receipt_size_bytes: usize,
-}
-
 fn main() {
     // Initialize tracing
     tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::filter::EnvFilter::from_default_env())
         .init();
 
-    println!("🎮 RISC Zero Pong Proof System");
-    println!("{}", "=".repeat(70));
-    println!();
-
     // Parse CLI arguments
     let args: Vec<String> = env::args().collect();
 
+    // JSON and generated-source output must stay machine readable, so skip the banner
+    let json_output = args.iter().any(|a| a == "--json");
+    let source_output = args.get(1).is_some_and(|c| c == "image-id");
+    if !json_output && !source_output {
+        println!("🎮 RISC Zero Pong Proof System");
+        println!("{}", "=".repeat(70));
+        println!();
+    }
+
     if args.len() < 2 {
         print_usage(&args[0]);
         std::process::exit(1);
@@ -46,43 +44,252 @@ fn main() {
     match command.as_str() {
         "prove" => {
             if args.len() < 3 {
-                eprintln!("Usage: {} prove <log_file> [--format <type>] [output_file]", args[0]);
+                eprintln!(
+                    "Usage: {} prove <log_file> [--format <type>] [--rules <ruleset>] [--scoring <format>] [--segment-pairs <N>] [--salt <hex|random>] [--disclose <mode>] [output_file]",
+                    args[0]
+                );
                 std::process::exit(1);
             }
 
             let log_file = &args[2];
 
-            // Parse optional --format flag
+            // Parse optional --format, --rules, --scoring, --segment-pairs, --salt and --disclose flags
             let mut receipt_kind = ReceiptKind::default();
-            let mut output_file_idx = 3;
+            let mut rules = Ruleset::STANDARD;
+            let mut scoring = ScoringRules::CLASSIC;
+            let mut segment_pairs = None;
+            let mut salt = None;
+            let mut disclosure = Disclosure::Full;
+            let mut output_file = None;
 
-            if args.len() > 3 && (args[3] == "--format" || args[3] == "-f") {
-                if args.len() < 5 {
-                    eprintln!("❌ Error: --format requires a value (composite|succinct|groth16)");
-                    std::process::exit(1);
+            let mut rest = args[3..].iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--format" | "-f" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --format requires a value (composite|succinct|groth16)");
+                            std::process::exit(1);
+                        };
+                        receipt_kind = ReceiptKind::from_str(value).unwrap_or_else(|e| {
+                            eprintln!("❌ Error: {}", e);
+                            std::process::exit(1);
+                        });
+                    }
+                    "--rules" | "-r" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --rules requires a value (standard|human-play|spin)");
+                            std::process::exit(1);
+                        };
+                        rules = Ruleset::preset(value).unwrap_or_else(|| {
+                            eprintln!("❌ Error: Unknown ruleset '{}' (expected standard, human-play or spin)", value);
+                            std::process::exit(1);
+                        });
+                    }
+                    "--scoring" | "-s" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --scoring requires a value (e.g. first-to-11,win-by-2,best-of-3)");
+                            std::process::exit(1);
+                        };
+                        scoring = parse_scoring(value).unwrap_or_else(|e| {
+                            eprintln!("❌ Error: {}", e);
+                            std::process::exit(1);
+                        });
+                    }
+                    "--segment-pairs" => {
+                        let pairs = rest.next().and_then(|v| v.parse::<u32>().ok()).filter(|&n| n > 0);
+                        let Some(pairs) = pairs else {
+                            eprintln!("❌ Error: --segment-pairs requires a positive number of event pairs");
+                            std::process::exit(1);
+                        };
+                        segment_pairs = Some(pairs);
+                    }
+                    "--salt" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --salt requires {} hex-encoded bytes or 'random'", LOG_SALT_BYTES);
+                            std::process::exit(1);
+                        };
+                        let parsed = if value == "random" { random_salt() } else { parse_salt(value) };
+                        salt = Some(parsed.unwrap_or_else(|e| {
+                            eprintln!("❌ Error: {}", e);
+                            std::process::exit(1);
+                        }));
+                    }
+                    "--disclose" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --disclose requires a value (full|winner-only|score-only)");
+                            std::process::exit(1);
+                        };
+                        disclosure = Disclosure::from_name(value).unwrap_or_else(|| {
+                            eprintln!(
+                                "❌ Error: Unknown disclosure mode '{}' (expected full, winner-only or score-only)",
+                                value
+                            );
+                            std::process::exit(1);
+                        });
+                    }
+                    _ => output_file = Some(arg.as_str()),
                 }
-                receipt_kind = ReceiptKind::from_str(&args[4]).unwrap_or_else(|e| {
-                    eprintln!("❌ Error: {}", e);
-                    std::process::exit(1);
-                });
-                output_file_idx = 5;
             }
+            rules.scoring = scoring;
 
-            let output_file = args.get(output_file_idx).map(|s| s.as_str());
-
-            prove_command(log_file, receipt_kind, output_file);
+            prove_command(log_file, receipt_kind, rules, segment_pairs, salt, disclosure, output_file);
         }
 
         "verify" => {
             if args.len() < 3 {
-                eprintln!("Usage: {} verify <proof_file>", args[0]);
+                eprintln!("Usage: {} verify <proof_file> [--log <log_file>] [--salt <hex>]", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            let proof_file = &args[2];
+
+            // Parse optional --log and --salt flags
+            let mut log_file = None;
+            let mut salt = None;
+            let mut rest = args[3..].iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--log" | "-l" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --log requires a log file");
+                            std::process::exit(1);
+                        };
+                        log_file = Some(value.as_str());
+                    }
+                    "--salt" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --salt requires {} hex-encoded bytes", LOG_SALT_BYTES);
+                            std::process::exit(1);
+                        };
+                        salt = Some(parse_salt(value).unwrap_or_else(|e| {
+                            eprintln!("❌ Error: {}", e);
+                            std::process::exit(1);
+                        }));
+                    }
+                    _ => {
+                        eprintln!("❌ Error: Unknown verify option '{}'", arg);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if salt.is_some() && log_file.is_none() {
+                eprintln!("❌ Error: --salt is only used to check a log given with --log");
+                std::process::exit(1);
+            }
+
+            verify_command(proof_file, log_file, salt);
+        }
+
+        "compress" => {
+            if args.len() < 5 || (args[3] != "--to" && args[3] != "-t") {
+                eprintln!("Usage: {} compress <proof_file> --to <type> [output_file]", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            let proof_file = &args[2];
+            let target_kind = ReceiptKind::from_str(&args[4]).unwrap_or_else(|e| {
+                eprintln!("❌ Error: {}", e);
+                std::process::exit(1);
+            });
+            let output_file = args.get(5).map(|s| s.as_str());
+
+            compress_command(proof_file, target_kind, output_file);
+        }
+
+        "aggregate" => {
+            // Parse optional --format and --output flags; everything else is a proof file
+            let mut receipt_kind = ReceiptKind::default();
+            let mut output_file = None;
+            let mut proof_files = Vec::new();
+
+            let mut rest = args[2..].iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--format" | "-f" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --format requires a value (composite|succinct|groth16)");
+                            std::process::exit(1);
+                        };
+                        receipt_kind = ReceiptKind::from_str(value).unwrap_or_else(|e| {
+                            eprintln!("❌ Error: {}", e);
+                            std::process::exit(1);
+                        });
+                    }
+                    "--output" | "-o" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --output requires a file name");
+                            std::process::exit(1);
+                        };
+                        output_file = Some(value.as_str());
+                    }
+                    _ => proof_files.push(arg.as_str()),
+                }
+            }
+            if proof_files.is_empty() {
+                eprintln!("Usage: {} aggregate <proof_file>... [--format <type>] [--output <file>]", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            aggregate_command(&proof_files, receipt_kind, output_file);
+        }
+
+        "inspect" => {
+            if args.len() < 3 {
+                eprintln!("Usage: {} inspect <proof_file> [--json]", args[0]);
                 eprintln!("Error: Missing required argument");
                 std::process::exit(1);
             }
 
             let proof_file = &args[2];
 
-            verify_command(proof_file);
+            inspect_command(proof_file, json_output);
+        }
+
+        "export" => {
+            // --evm is the only export target for now
+            let mut evm = false;
+            let mut output_file = None;
+            let mut proof_file = None;
+
+            let mut rest = args[2..].iter();
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--evm" => evm = true,
+                    "--json" => {}
+                    "--output" | "-o" => {
+                        let Some(value) = rest.next() else {
+                            eprintln!("❌ Error: --output requires a file name");
+                            std::process::exit(1);
+                        };
+                        output_file = Some(value.as_str());
+                    }
+                    _ => proof_file = Some(arg.as_str()),
+                }
+            }
+            let (true, Some(proof_file)) = (evm, proof_file) else {
+                eprintln!("Usage: {} export --evm <proof_file> [--output <file>] [--json]", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            };
+
+            export_evm_command(proof_file, output_file, json_output);
+        }
+
+        "convert" => {
+            if args.len() < 4 {
+                eprintln!("Usage: {} convert <log_file> <output_file>", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            convert_command(&args[2], &args[3]);
+        }
+
+        "image-id" => {
+            image_id_command();
         }
 
         "--help" | "-h" => {
@@ -102,17 +309,76 @@ fn print_usage(program: &str) {
     eprintln!("Usage: {} <command> [options]", program);
     eprintln!();
     eprintln!("Commands:");
-    eprintln!("  prove <log_file> [--format <type>] [output_file]");
+    eprintln!("  prove <log_file> [--format <type>] [--rules <ruleset>] [--scoring <format>] [--segment-pairs <N>] [--salt <hex|random>] [--disclose <mode>] [output_file]");
     eprintln!("      Generate a cryptographic proof for a game log");
-    eprintln!("      - log_file: JSON file containing the game log");
+    eprintln!("      - log_file: Game log, JSON or binary, or a session log");
+    eprintln!("                  (JSON with session_seed and the events of each game)");
     eprintln!("      - --format: Optional receipt type (composite|succinct|groth16)");
     eprintln!("                  Default: succinct");
+    eprintln!("      - --rules: Optional ruleset (standard|human-play|spin)");
+    eprintln!("                 human-play also limits paddle acceleration and reaction time");
+    eprintln!("                 spin lets paddle velocity at impact bend the bounce angle");
+    eprintln!("                 Default: standard");
+    eprintln!("      - --scoring: Optional match format, comma separated:");
+    eprintln!("                   first-to-<N>, win-by-<N>, best-of-<N>, alternate-serve,");
+    eprintln!("                   time-limit-<secs> (game clock), allow-draw (ties at the clock),");
+    eprintln!("                   forfeit (prove an unfinished log as abandoned)");
+    eprintln!("                   Default: first-to-3 (one game, serve toward the scorer, untimed)");
+    eprintln!("      - --segment-pairs: Optional; prove the log in segments of N event pairs");
+    eprintln!("                         and join them into one proof (same journal)");
+    eprintln!("      - --salt: Optional private salt mixed into the log hash ({} bytes, hex),", LOG_SALT_BYTES);
+    eprintln!("                or 'random'; keep it to check the log later (not with segments)");
+    eprintln!("      - --disclose: Optional journal contents (full|winner-only|score-only)");
+    eprintln!("                    winner-only commits fairness and the winner, score-only also");
+    eprintln!("                    the final score; both keep the margin and event count private");
+    eprintln!("                    and save to pong-disclosed_game<id>_<timestamp>.json");
+    eprintln!("                    Default: full (not with segments)");
     eprintln!("      - output_file: Optional file to save the proof (JSON)");
     eprintln!("                     Defaults to: pong-proof_game<id>_<timestamp>.json");
+    eprintln!("                     (pong-session_<seed>_<timestamp>.json for a session)");
     eprintln!();
-    eprintln!("  verify <proof_file>");
+    eprintln!("  verify <proof_file> [--log <log_file>] [--salt <hex>]");
     eprintln!("      Cryptographically verify a pong proof");
+    eprintln!("      - proof_file: JSON file containing the proof (full or disclosed)");
+    eprintln!("      - --log: Optional; also check this log is the one the proof commits to");
+    eprintln!("      - --salt: The salt the proof was generated with, for a salted log");
+    eprintln!();
+    eprintln!("  compress <proof_file> --to <type> [output_file]");
+    eprintln!("      Compress an existing proof without re-executing the game log");
+    eprintln!("      - proof_file: JSON file containing the proof");
+    eprintln!("      - --to: Target receipt type (succinct|groth16)");
+    eprintln!("      - output_file: Optional file to save the compressed proof (JSON)");
+    eprintln!("                     Defaults to: pong-proof_game<id>_<timestamp>.json");
+    eprintln!();
+    eprintln!("  aggregate <proof_file>... [--format <type>] [--output <file>]");
+    eprintln!("      Fold many game proofs into one receipt committing a Merkle root over");
+    eprintln!("      each game's (game_id, log_hash, scores, fair), with an inclusion proof per game");
+    eprintln!("      - proof_file: JSON files containing the proofs");
+    eprintln!("      - --format: Optional receipt type (composite|succinct|groth16)");
+    eprintln!("                  Default: succinct");
+    eprintln!("      - --output: Optional file to save the aggregate proof (JSON)");
+    eprintln!("                  Defaults to: pong-aggregate_<timestamp>.json");
+    eprintln!();
+    eprintln!("  inspect <proof_file> [--json]");
+    eprintln!("      Show receipt kind, image ID, claim digest and decoded journal");
+    eprintln!("      and check the saved metadata against the journal (no verification)");
     eprintln!("      - proof_file: JSON file containing the proof");
+    eprintln!("      - --json: Print the inspection as JSON");
+    eprintln!();
+    eprintln!("  export --evm <proof_file> [--output <file>] [--json]");
+    eprintln!("      Export a Groth16 proof as the (seal, imageId, journalDigest) arguments");
+    eprintln!("      and calldata of IRiscZeroVerifier.verify (RISC Zero verifier router)");
+    eprintln!("      - proof_file: JSON file containing a Groth16 proof");
+    eprintln!("      - --output: Optional file to save the export (JSON)");
+    eprintln!("      - --json: Print the export as JSON");
+    eprintln!();
+    eprintln!("  convert <log_file> <output_file>");
+    eprintln!("      Convert a game log between the JSON and binary encodings");
+    eprintln!("      - log_file: JSON log is written as binary, binary log as JSON");
+    eprintln!();
+    eprintln!("  image-id");
+    eprintln!("      Print the guest image IDs as GUEST_REGISTRY fields");
+    eprintln!("      (to record the current release before cutting a new one)");
     eprintln!();
     eprintln!("Receipt Formats:");
     eprintln!("  composite: Fastest proving, largest size (~MB)");
@@ -123,14 +389,11 @@ fn print_usage(program: &str) {
     eprintln!("  1. Generate proof: {} prove pong-log.json --format succinct", program);
     eprintln!("     (saves to pong-proof_game<id>_<timestamp>.json)");
     eprintln!("  2. Verify proof:   {} verify pong-proof_game<id>_<timestamp>.json", program);
+    eprintln!("  3. Shrink proof:   {} compress pong-proof_game<id>_<timestamp>.json --to groth16", program);
 }
 
-fn prove_command(log_file: &str, receipt_kind: ReceiptKind, output_file: Option<&str>) {
-    println!("📋 Generating proof for game log");
-    println!("  Log file: {}", log_file);
-    println!("  Receipt format: {}", receipt_kind);
-    println!();
-
+/// Read the raw contents of a game log file, exiting on error
+fn read_log_bytes(log_file: &str) -> Vec<u8> {
     // Check file size before reading (DoS protection)
     const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024; // 10 MB
     let metadata = fs::metadata(log_file).unwrap_or_else(|e| {
@@ -149,25 +412,163 @@ fn prove_command(log_file: &str, receipt_kind: ReceiptKind, output_file: Option<
     }
 
     // Load log file
-    let raw = fs::read_to_string(log_file).unwrap_or_else(|e| {
+    fs::read(log_file).unwrap_or_else(|e| {
         eprintln!("❌ Error reading file '{}': {}", log_file, e);
         std::process::exit(1);
-    });
+    })
+}
 
-    let log: CompactLog = serde_json::from_str(&raw).unwrap_or_else(|e| {
-        eprintln!("❌ Error parsing JSON: {}", e);
+/// Parse a game log (JSON or binary), exiting on error
+fn parse_log(raw: &[u8]) -> CompactLog {
+    load_log(raw).unwrap_or_else(|e| {
+        eprintln!("❌ Error parsing log: {}", e);
         std::process::exit(1);
-    });
+    })
+}
+
+/// Parse a `--scoring` format such as `first-to-11,win-by-2,best-of-3,alternate-serve`
+///
+/// Unspecified parts keep their classic values.
+fn parse_scoring(spec: &str) -> Result<ScoringRules, String> {
+    let mut scoring = ScoringRules::CLASSIC;
+    for part in spec.split(',').map(str::trim) {
+        let number = |prefix: &str| {
+            part[prefix.len()..]
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid number in scoring format '{}'", part))
+        };
+        if part.starts_with("first-to-") {
+            scoring.points_to_win = number("first-to-")?;
+        } else if part.starts_with("win-by-") {
+            scoring.win_by = number("win-by-")?;
+        } else if part.starts_with("best-of-") {
+            scoring.best_of = number("best-of-")?;
+        } else if part == "alternate-serve" {
+            scoring.serve = Serve::Alternate;
+        } else if part.starts_with("time-limit-") {
+            // Whole seconds, stored as Q16.16
+            let secs = number("time-limit-")?;
+            if secs == 0 || secs > u16::MAX as u32 {
+                return Err(format!("Time limit must be between 1 and {} seconds", u16::MAX));
+            }
+            scoring.time_limit = secs << 16;
+        } else if part == "allow-draw" {
+            scoring.allow_draw = true;
+        } else if part == "forfeit" {
+            scoring.forfeit = true;
+        } else {
+            return Err(format!(
+                "Unknown scoring format '{}' (expected first-to-<N>, win-by-<N>, best-of-<N>, alternate-serve, time-limit-<secs>, allow-draw or forfeit)",
+                part
+            ));
+        }
+    }
+    scoring.validate()?;
+    Ok(scoring)
+}
+
+/// Parse a hex-encoded log salt (with or without 0x)
+fn parse_salt(value: &str) -> Result<[u8; LOG_SALT_BYTES], String> {
+    let bytes = hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| format!("Invalid salt '{}': {}", value, e))?;
+    let salt: [u8; LOG_SALT_BYTES] = bytes
+        .try_into()
+        .map_err(|_| format!("Salt must be {} bytes ({} hex digits)", LOG_SALT_BYTES, 2 * LOG_SALT_BYTES))?;
+    if salt == [0; LOG_SALT_BYTES] {
+        return Err("The all-zero salt is reserved for unsalted logs".into());
+    }
+    Ok(salt)
+}
+
+/// A fresh salt from the operating system's random number generator
+fn random_salt() -> Result<[u8; LOG_SALT_BYTES], String> {
+    let mut salt = [0u8; LOG_SALT_BYTES];
+    getrandom::getrandom(&mut salt).map_err(|e| format!("Failed to generate a salt: {}", e))?;
+    Ok(salt)
+}
+
+fn default_proof_filename(game_id: u32) -> String {
+    format!("pong-proof_game{}_{}.json", game_id, chrono::Utc::now().timestamp())
+}
+
+fn prove_command(
+    log_file: &str,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+    segment_pairs: Option<u32>,
+    salt: Option<[u8; LOG_SALT_BYTES]>,
+    disclosure: Disclosure,
+    output_file: Option<&str>,
+) {
+    println!("📋 Generating proof for game log");
+    println!("  Log file: {}", log_file);
+    println!("  Receipt format: {}", receipt_kind);
+    println!("  Rules: {}", rules);
+    if let Some(pairs) = segment_pairs {
+        println!("  Segments: {} event pairs each", pairs);
+    }
+    if salt.is_some() {
+        println!("  Log hash: salted");
+    }
+    if disclosure != Disclosure::Full {
+        println!("  Disclosure: {}", disclosure);
+    }
+    println!();
+
+    if salt.is_some() && segment_pairs.is_some() {
+        eprintln!("❌ --salt is not supported with --segment-pairs (segment journals hash events unsalted)");
+        std::process::exit(1);
+    }
+    if disclosure != Disclosure::Full && segment_pairs.is_some() {
+        eprintln!("❌ --disclose is not supported with --segment-pairs (segment journals carry the full result)");
+        std::process::exit(1);
+    }
+    if disclosure != Disclosure::Full && salt.is_none() {
+        eprintln!("❌ --disclose {} needs --salt (an unsalted log hash can give the hidden fields away)", disclosure);
+        std::process::exit(1);
+    }
+
+    let raw = read_log_bytes(log_file);
+    if !is_binary_log(&raw) {
+        if let Ok(session) = load_session_log(&raw) {
+            if segment_pairs.is_some() {
+                eprintln!("❌ --segment-pairs is not supported for session logs");
+                std::process::exit(1);
+            }
+            if salt.is_some() {
+                eprintln!("❌ --salt is not supported for session logs");
+                std::process::exit(1);
+            }
+            if disclosure != Disclosure::Full {
+                eprintln!("❌ --disclose is not supported for session logs");
+                std::process::exit(1);
+            }
+            prove_session(&session, receipt_kind, rules, output_file);
+            return;
+        }
+    }
+    let log = parse_log(&raw);
 
     println!("📦 Loaded {} events from log", log.events.len());
     println!("  Game ID: {}", log.game_id);
+    println!("  Log version: {}", log.v);
     println!();
 
+    if disclosure != Disclosure::Full {
+        prove_disclosed(&log, receipt_kind, rules, disclosure, salt, log_file, output_file);
+        return;
+    }
+
     // Generate proof
     println!("🔐 Generating proof (this may take a while)...");
     let start = Instant::now();
 
-    let proof = generate_pong_proof(&log, receipt_kind).unwrap_or_else(|e| {
+    let proof = match (segment_pairs, salt) {
+        (Some(pairs), _) => generate_segmented_pong_proof(&log, receipt_kind, rules, pairs),
+        (None, Some(salt)) => generate_salted_pong_proof(&log, receipt_kind, rules, salt),
+        (None, None) => generate_pong_proof_with_rules(&log, receipt_kind, rules),
+    };
+    let proof = proof.unwrap_or_else(|e| {
         eprintln!();
         eprintln!("❌ Proof generation failed: {}", e);
         std::process::exit(1);
@@ -178,22 +579,13 @@ fn prove_command(log_file: &str, receipt_kind: ReceiptKind, output_file: Option<
     println!();
 
     // Prepare saved proof
-    let receipt_bytes = bincode::serialize(&proof.receipt).unwrap();
-    let saved_proof = SavedProof {
-        proof: proof.clone(),
-        left_score: proof.left_score,
-        right_score: proof.right_score,
-        log_hash: hex::encode(proof.log_hash),
-        events_len: proof.events_len,
-        fair: proof.fair,
-        reason: proof.reason.clone(),
-        game_id: proof.game_id,
-        receipt_kind: proof.receipt_kind.to_string(),
-        receipt_size_bytes: receipt_bytes.len(),
-    };
+    let saved_proof = SavedProof::new(proof.clone()).unwrap_or_else(|e| {
+        eprintln!("❌ Error serializing receipt: {}", e);
+        std::process::exit(1);
+    });
 
     // Determine output filename
-    let default_filename = format!("pong-proof_game{}_{}.json", proof.game_id, chrono::Utc::now().timestamp());
+    let default_filename = default_proof_filename(proof.game_id);
     let file_to_save = output_file.unwrap_or(&default_filename);
 
     // Save proof
@@ -202,15 +594,37 @@ fn prove_command(log_file: &str, receipt_kind: ReceiptKind, output_file: Option<
             println!("✅ Proof generated successfully!");
             println!("  Result: {}", if proof.fair { "FAIR GAME" } else { "UNFAIR GAME" });
             if !proof.fair {
-                println!("  Reason: {}", proof.reason.unwrap_or_else(|| "Unknown".to_string()));
+                println!("  Reason: {}", proof.reason.as_deref().unwrap_or("Unknown"));
             }
             println!("  Score: {}-{}", proof.left_score, proof.right_score);
+            if proof.games.len() > 1 {
+                println!("  Games: {}", format_games(&proof.games));
+            }
+            if proof.end_reason == Some(EndReason::Clock) {
+                println!("  Ended: {}", format_end(proof.end_reason, proof.outcome));
+            }
+            if let Some(pair) = proof.last_valid_pair {
+                match (&proof.reason, proof.violator) {
+                    (Some(reason), Some(violator)) => println!(
+                        "  Ended: abandoned, valid through event pair {} ({}: {})",
+                        pair, reason, violator
+                    ),
+                    (Some(reason), None) => println!("  Ended: abandoned, valid through event pair {} ({})", pair, reason),
+                    (None, _) => println!("  Ended: abandoned, valid through event pair {}", pair),
+                }
+            }
             println!("  Log Hash: 0x{}", hex::encode(proof.log_hash));
             println!("  Events Processed: {}", proof.events_len);
-            println!("  Receipt Size: {} bytes", receipt_bytes.len());
+            println!("  Receipt Size: {} bytes", saved_proof.receipt_size_bytes);
             println!();
             println!("💾 Proof saved to: {}", file_to_save);
             println!("   Use 'verify {}' to cryptographically verify this proof", file_to_save);
+            if let Some(salt) = salt {
+                println!();
+                println!("🔑 Salt: 0x{}", hex::encode(salt));
+                println!("   It is not stored in the proof. Keep it private and share it only with");
+                println!("   parties who check the log: 'verify {} --log {} --salt <salt>'", file_to_save, log_file);
+            }
             println!("{}", "=".repeat(70));
         }
         Err(e) => {
@@ -220,15 +634,160 @@ fn prove_command(log_file: &str, receipt_kind: ReceiptKind, output_file: Option<
     }
 }
 
-fn verify_command(proof_file: &str) {
+/// Prove a log with a winner-only or score-only journal and save the disclosed proof
+fn prove_disclosed(
+    log: &CompactLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+    disclosure: Disclosure,
+    salt: Option<[u8; LOG_SALT_BYTES]>,
+    log_file: &str,
+    output_file: Option<&str>,
+) {
+    println!("🔐 Generating proof (this may take a while)...");
+    let start = Instant::now();
+
+    let proof = generate_disclosed_pong_proof(log, receipt_kind, rules, disclosure, salt).unwrap_or_else(|e| {
+        eprintln!();
+        eprintln!("❌ Proof generation failed: {}", e);
+        std::process::exit(1);
+    });
+
+    let duration = start.elapsed();
+    println!("  Proving time: {:.2}s", duration.as_secs_f64());
+    println!();
+
+    let default_filename = format!(
+        "pong-disclosed_game{}_{}.json",
+        log.game_id,
+        chrono::Utc::now().timestamp()
+    );
+    let file_to_save = output_file.unwrap_or(&default_filename);
+
+    match save_disclosed_proof(&proof, file_to_save) {
+        Ok(_) => {
+            println!("✅ Proof generated successfully!");
+            print_disclosed(&proof, "  ");
+            println!();
+            println!("💾 Proof saved to: {}", file_to_save);
+            println!("   Use 'verify {}' to cryptographically verify this proof", file_to_save);
+            if let Some(salt) = salt {
+                println!();
+                println!("🔑 Salt: 0x{}", hex::encode(salt));
+                println!("   It is not stored in the proof. Keep it private and share it only with");
+                println!("   parties who check the log: 'verify {} --log {} --salt <salt>'", file_to_save, log_file);
+            }
+            println!("{}", "=".repeat(70));
+        }
+        Err(e) => {
+            eprintln!("❌ Error saving proof: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print the fields a disclosed proof reveals
+fn print_disclosed(proof: &DisclosedProof, indent: &str) {
+    let journal = &proof.journal;
+    println!("{}Disclosure: {}", indent, journal.disclosure());
+    println!("{}Result: {}", indent, if journal.fair() { "FAIR GAME" } else { "UNFAIR GAME" });
+    let winner = match journal.outcome() {
+        Some(Outcome::LeftWin) => "left",
+        Some(Outcome::RightWin) => "right",
+        Some(Outcome::Draw) => "draw",
+        None => "none",
+    };
+    println!("{}Winner: {}", indent, winner);
+    if let Some((left, right)) = journal.score() {
+        println!("{}Score: {}-{}", indent, left, right);
+    }
+    println!("{}Log Hash: 0x{}", indent, hex::encode(journal.log_hash()));
+}
+
+/// Prove every game of a session log in one guest run and save the session proof
+fn prove_session(session: &SessionLog, receipt_kind: ReceiptKind, rules: Ruleset, output_file: Option<&str>) {
+    println!("📦 Loaded session of {} games", session.games.len());
+    println!("  Session seed: {}", session.session_seed);
+    println!("  Log version: {}", session.v);
+    println!();
+
+    println!("🔐 Generating session proof (this may take a while)...");
+    let start = Instant::now();
+
+    let proof = generate_session_proof(session, receipt_kind, rules).unwrap_or_else(|e| {
+        eprintln!();
+        eprintln!("❌ Proof generation failed: {}", e);
+        std::process::exit(1);
+    });
+
+    let duration = start.elapsed();
+    println!("  Proving time: {:.2}s", duration.as_secs_f64());
+    println!();
+
+    let default_filename = format!(
+        "pong-session_{}_{}.json",
+        session.session_seed,
+        chrono::Utc::now().timestamp()
+    );
+    let file_to_save = output_file.unwrap_or(&default_filename);
+
+    match save_session_proof(&proof, file_to_save) {
+        Ok(_) => {
+            let output = &proof.output;
+            println!("✅ Session proof generated successfully!");
+            println!("  Result: {}", if output.fair { "FAIR SESSION" } else { "UNFAIR SESSION" });
+            if let Some(reason) = &output.reason {
+                println!("  Reason: {}", reason);
+            }
+            for (index, game) in output.games.iter().enumerate() {
+                println!(
+                    "    #{} game {}: {}-{} {} ({} events)",
+                    index,
+                    game.game_id,
+                    game.left_score,
+                    game.right_score,
+                    if game.fair { "fair" } else { "unfair" },
+                    game.events_len
+                );
+            }
+            let totals = &output.totals;
+            println!(
+                "  Totals: {} games, {} fair, {} left wins, {} right wins, {} draws, points {}-{}",
+                totals.games,
+                totals.fair_games,
+                totals.left_wins,
+                totals.right_wins,
+                totals.draws,
+                totals.left_score,
+                totals.right_score
+            );
+            println!();
+            println!("💾 Session proof saved to: {}", file_to_save);
+            println!("{}", "=".repeat(70));
+        }
+        Err(e) => {
+            eprintln!("❌ Error saving session proof: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn verify_command(proof_file: &str, log_file: Option<&str>, salt: Option<[u8; LOG_SALT_BYTES]>) {
     println!("📋 Verifying proof");
     println!("  Proof file: {}", proof_file);
+    if let Some(log_file) = log_file {
+        println!("  Log file: {}{}", log_file, if salt.is_some() { " (salted)" } else { "" });
+    }
     println!();
 
-    // Load proof
+    // Load proof, falling back to a disclosed proof
     let saved_proof = match load_proof(proof_file) {
         Ok(p) => p,
         Err(e) => {
+            if let Some(proof) = fs::read(proof_file).ok().and_then(|bytes| load_disclosed_proof(&bytes).ok()) {
+                verify_disclosed(&proof, log_file, salt);
+                return;
+            }
             eprintln!("❌ Error loading proof: {}", e);
             std::process::exit(1);
         }
@@ -238,6 +797,9 @@ fn verify_command(proof_file: &str) {
     println!("  Game ID: {}", saved_proof.game_id);
     println!("  Receipt format: {}", saved_proof.receipt_kind);
     println!("  Receipt size: {} bytes", saved_proof.receipt_size_bytes);
+    println!("  Guest version: {}", saved_proof.proof.guest_version);
+    println!("  Log version: {}", saved_proof.proof.log_version);
+    println!("  Rules: {}", saved_proof.proof.rules);
     println!();
 
     // Cryptographically verify the receipt
@@ -255,10 +817,38 @@ fn verify_command(proof_file: &str) {
             println!("  1. The game log was correctly validated");
             println!("  2. The game was {}", if saved_proof.fair { "FAIR" } else { "UNFAIR" });
             if !saved_proof.fair {
-                println!("     Reason: {}", saved_proof.reason.unwrap_or_else(|| "Unknown".to_string()));
+                println!("     Reason: {}", saved_proof.reason.as_deref().unwrap_or("Unknown"));
             }
             println!("  3. Final score: {}-{}", saved_proof.left_score, saved_proof.right_score);
+            if saved_proof.games.len() > 1 {
+                println!("     Games: {}", format_games(&saved_proof.games));
+            }
+            if saved_proof.end_reason == Some(EndReason::Clock) {
+                println!("     Ended: {}", format_end(saved_proof.end_reason, saved_proof.outcome));
+            }
+            if let Some(pair) = saved_proof.last_valid_pair {
+                match (&saved_proof.reason, saved_proof.violator) {
+                    (Some(reason), Some(violator)) => println!(
+                        "     Ended: abandoned, valid through event pair {} ({}: {})",
+                        pair, reason, violator
+                    ),
+                    (Some(reason), None) => {
+                        println!("     Ended: abandoned, valid through event pair {} ({})", pair, reason)
+                    }
+                    (None, _) => println!("     Ended: abandoned, valid through event pair {}", pair),
+                }
+            }
             println!("  4. The computation was executed correctly in the zkVM");
+            if let Some(log_file) = log_file {
+                let log = parse_log(&read_log_bytes(log_file));
+                if let Err(e) = verify_log_commitment(&saved_proof.proof, &log, salt.as_ref()) {
+                    eprintln!();
+                    eprintln!("❌ Log check failed: {}", e);
+                    eprintln!("{}", "=".repeat(70));
+                    std::process::exit(1);
+                }
+                println!("  5. {} is the log the proof commits to", log_file);
+            }
             println!();
             if saved_proof.fair {
                 println!("🎊 This game result is cryptographically verified!");
@@ -271,7 +861,8 @@ fn verify_command(proof_file: &str) {
             eprintln!();
             eprintln!("The receipt is not cryptographically valid. This could mean:");
             eprintln!("  - The proof was tampered with");
-            eprintln!("  - The proof was not generated by the correct program");
+            eprintln!("  - The proof was not generated by a registered guest version");
+            eprintln!("  - The guest version that generated it has been revoked");
             eprintln!("  - The receipt data is corrupted");
             eprintln!("{}", "=".repeat(70));
             std::process::exit(1);
@@ -279,14 +870,297 @@ fn verify_command(proof_file: &str) {
     }
 }
 
-fn save_proof(proof: &SavedProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let json = serde_json::to_string_pretty(proof)?;
-    fs::write(path, json)?;
-    Ok(())
+/// Verify a winner-only or score-only proof, and optionally the log it commits to
+fn verify_disclosed(proof: &DisclosedProof, log_file: Option<&str>, salt: Option<[u8; LOG_SALT_BYTES]>) {
+    println!("📦 Loaded disclosed proof");
+    println!("  Game ID: {}", proof.journal.game_id());
+    println!("  Receipt format: {}", proof.receipt_kind);
+    println!("  Guest version: {}", proof.guest_version);
+    println!("  Rules: {}", proof.journal.rules());
+    println!();
+
+    println!("🔐 Verifying receipt cryptographically...");
+    if let Err(e) = verify_disclosed_proof(proof) {
+        eprintln!();
+        eprintln!("❌ Receipt verification failed: {}", e);
+        eprintln!("{}", "=".repeat(70));
+        std::process::exit(1);
+    }
+    println!("✅ Receipt cryptographically verified!");
+    println!();
+    println!("The proof cryptographically attests that the game log was correctly");
+    println!("validated in the zkVM, and discloses only:");
+    print_disclosed(proof, "  ");
+    if let Some(log_file) = log_file {
+        let log = parse_log(&read_log_bytes(log_file));
+        if let Err(e) = verify_disclosed_log_commitment(proof, &log, salt.as_ref()) {
+            eprintln!();
+            eprintln!("❌ Log check failed: {}", e);
+            eprintln!("{}", "=".repeat(70));
+            std::process::exit(1);
+        }
+        println!("  {} is the log the proof commits to", log_file);
+    }
+    println!("{}", "=".repeat(70));
 }
 
-fn load_proof(path: &str) -> Result<SavedProof, Box<dyn std::error::Error>> {
-    let json = fs::read_to_string(path)?;
-    let proof: SavedProof = serde_json::from_str(&json)?;
-    Ok(proof)
+fn aggregate_command(proof_files: &[&str], receipt_kind: ReceiptKind, output_file: Option<&str>) {
+    println!("📋 Aggregating {} proofs", proof_files.len());
+    println!("  Receipt format: {}", receipt_kind);
+    println!();
+
+    let proofs: Vec<_> = proof_files
+        .iter()
+        .map(|file| {
+            let saved = load_proof(file).unwrap_or_else(|e| {
+                eprintln!("❌ Error loading proof '{}': {}", file, e);
+                std::process::exit(1);
+            });
+            println!("📦 Loaded proof for game {} ({})", saved.game_id, file);
+            saved.proof
+        })
+        .collect();
+    println!();
+
+    println!("🔐 Generating aggregate proof (this may take a while)...");
+    let start = Instant::now();
+
+    let proof = generate_aggregate_proof(&proofs, receipt_kind).unwrap_or_else(|e| {
+        eprintln!();
+        eprintln!("❌ Aggregation failed: {}", e);
+        std::process::exit(1);
+    });
+
+    let duration = start.elapsed();
+    println!("  Proving time: {:.2}s", duration.as_secs_f64());
+    println!();
+
+    let default_filename = format!("pong-aggregate_{}.json", chrono::Utc::now().timestamp());
+    let file_to_save = output_file.unwrap_or(&default_filename);
+
+    match save_aggregate_proof(&proof, file_to_save) {
+        Ok(_) => {
+            println!("✅ Aggregate proof generated successfully!");
+            println!("  Games: {}", proof.output.games);
+            println!("  Merkle Root: 0x{}", hex::encode(proof.output.root));
+            for inclusion in &proof.inclusion_proofs {
+                let leaf = &inclusion.leaf;
+                println!(
+                    "    #{} game {}: {}-{} {} (log 0x{}...)",
+                    inclusion.index,
+                    leaf.game_id,
+                    leaf.left_score,
+                    leaf.right_score,
+                    if leaf.fair { "fair" } else { "unfair" },
+                    hex::encode(&leaf.log_hash[..8])
+                );
+            }
+            println!();
+            println!("💾 Aggregate proof saved to: {}", file_to_save);
+            println!("{}", "=".repeat(70));
+        }
+        Err(e) => {
+            eprintln!("❌ Error saving aggregate proof: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn compress_command(proof_file: &str, target_kind: ReceiptKind, output_file: Option<&str>) {
+    println!("📋 Compressing proof");
+    println!("  Proof file: {}", proof_file);
+    println!("  Target format: {}", target_kind);
+    println!();
+
+    // Load proof
+    let saved_proof = match load_proof(proof_file) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("❌ Error loading proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("📦 Loaded proof");
+    println!("  Game ID: {}", saved_proof.game_id);
+    println!("  Receipt format: {}", saved_proof.receipt_kind);
+    println!("  Receipt size: {} bytes", saved_proof.receipt_size_bytes);
+    println!();
+
+    // Compress the receipt (no guest re-execution)
+    println!("🗜️  Compressing receipt (this may take a while)...");
+    let start = Instant::now();
+
+    let proof = compress_pong_proof(&saved_proof.proof, target_kind).unwrap_or_else(|e| {
+        eprintln!();
+        eprintln!("❌ Compression failed: {}", e);
+        std::process::exit(1);
+    });
+
+    let duration = start.elapsed();
+    println!("  Compression time: {:.2}s", duration.as_secs_f64());
+    println!();
+
+    let compressed = SavedProof::new(proof).unwrap_or_else(|e| {
+        eprintln!("❌ Error serializing receipt: {}", e);
+        std::process::exit(1);
+    });
+
+    let default_filename = default_proof_filename(compressed.game_id);
+    let file_to_save = output_file.unwrap_or(&default_filename);
+
+    match save_proof(&compressed, file_to_save) {
+        Ok(_) => {
+            println!("✅ Proof compressed successfully!");
+            println!("  Receipt format: {} -> {}", saved_proof.receipt_kind, compressed.receipt_kind);
+            println!(
+                "  Receipt size: {} -> {} bytes",
+                saved_proof.receipt_size_bytes, compressed.receipt_size_bytes
+            );
+            println!();
+            println!("💾 Proof saved to: {}", file_to_save);
+            println!("   Use 'verify {}' to cryptographically verify this proof", file_to_save);
+            println!("{}", "=".repeat(70));
+        }
+        Err(e) => {
+            eprintln!("❌ Error saving proof: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn inspect_command(proof_file: &str, json_output: bool) {
+    let saved_proof = match load_proof(proof_file) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("❌ Error loading proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let inspection = inspect_proof(&saved_proof);
+
+    if json_output {
+        match serde_json::to_string_pretty(&inspection) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("❌ Error serializing inspection: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        println!("📋 Inspecting proof");
+        println!("  Proof file: {}", proof_file);
+        println!();
+        print!("{}", inspection);
+        println!("{}", "=".repeat(70));
+    }
+
+    if !inspection.metadata_consistent {
+        std::process::exit(1);
+    }
+}
+
+fn export_evm_command(proof_file: &str, output_file: Option<&str>, json_output: bool) {
+    let saved_proof = match load_proof(proof_file) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("❌ Error loading proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let evm = evm_proof(&saved_proof.proof).unwrap_or_else(|e| {
+        eprintln!("❌ Export failed: {}", e);
+        std::process::exit(1);
+    });
+    let calldata = evm.calldata();
+
+    let export = serde_json::json!({
+        "seal": format!("0x{}", hex::encode(&evm.seal)),
+        "imageId": format!("0x{}", hex::encode(evm.image_id)),
+        "journalDigest": format!("0x{}", hex::encode(evm.journal_digest)),
+        "journal": format!("0x{}", hex::encode(&saved_proof.proof.receipt.journal.bytes)),
+        "calldata": format!("0x{}", hex::encode(&calldata)),
+    });
+    let json = serde_json::to_string_pretty(&export).unwrap_or_else(|e| {
+        eprintln!("❌ Error serializing export: {}", e);
+        std::process::exit(1);
+    });
+
+    if let Some(file) = output_file {
+        if let Err(e) = fs::write(file, &json) {
+            eprintln!("❌ Error writing '{}': {}", file, e);
+            std::process::exit(1);
+        }
+    }
+
+    if json_output {
+        println!("{}", json);
+        return;
+    }
+
+    println!("📋 Exporting proof for EVM verification");
+    println!("  Proof file: {}", proof_file);
+    println!("  Game ID: {}", saved_proof.game_id);
+    println!();
+    println!("✅ IRiscZeroVerifier.verify(seal, imageId, journalDigest)");
+    println!("  Seal: 0x{}", hex::encode(&evm.seal));
+    println!("  Image ID: 0x{}", hex::encode(evm.image_id));
+    println!("  Journal Digest: 0x{}", hex::encode(evm.journal_digest));
+    println!();
+    println!("  Calldata ({} bytes):", calldata.len());
+    println!("  0x{}", hex::encode(&calldata));
+    if let Some(file) = output_file {
+        println!();
+        println!("💾 Export saved to: {}", file);
+    }
+    println!("{}", "=".repeat(70));
+}
+
+fn image_id_command() {
+    let words = |id: [u32; 8]| -> String {
+        id.iter().map(|w| format!("0x{:08x}", w)).collect::<Vec<_>>().join(", ")
+    };
+
+    println!("image_id: [{}],", words(GUEST_CODE_FOR_ZK_PROOF_ID));
+    println!("join_image_id: Some([{}]),", words(JOIN_SEGMENTS_ID));
+}
+
+fn convert_command(log_file: &str, output_file: &str) {
+    println!("📋 Converting game log");
+    println!("  Log file: {}", log_file);
+    println!();
+
+    let raw = read_log_bytes(log_file);
+    let log = parse_log(&raw);
+    let to_binary = !is_binary_log(&raw);
+
+    let output = if to_binary {
+        encode_binary_log(&log).map_err(|e| e.to_string())
+    } else {
+        serde_json::to_vec_pretty(&log).map_err(|e| e.to_string())
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("❌ Error encoding log: {}", e);
+        std::process::exit(1);
+    });
+
+    if let Err(e) = fs::write(output_file, &output) {
+        eprintln!("❌ Error writing '{}': {}", output_file, e);
+        std::process::exit(1);
+    }
+
+    println!("✅ Log converted successfully!");
+    println!(
+        "  Format: {} -> {}",
+        if to_binary { "JSON" } else { "binary" },
+        if to_binary { "binary" } else { "JSON" }
+    );
+    println!("  Events: {}", log.events.len());
+    println!("  Game ID: {}", log.game_id);
+    println!("  Size: {} -> {} bytes", raw.len(), output.len());
+    println!();
+    println!("💾 Log saved to: {}", output_file);
+    println!("{}", "=".repeat(70));
 }
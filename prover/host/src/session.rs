@@ -0,0 +1,113 @@
+use crate::{prover_opts, registry, ReceiptKind, CURRENT_GUEST_VERSION, GUEST_REGISTRY};
+use core::{Ruleset, SessionInput, SessionLog, SessionOutput};
+use methods::{SESSION_ELF, SESSION_ID};
+use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use serde::{Deserialize, Serialize};
+
+/// Proof of every game in a session log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Decoded journal: each game's output and the session totals
+    pub output: SessionOutput,
+}
+
+/// Load a session log from raw file contents (JSON)
+pub fn load_session_log(bytes: &[u8]) -> Result<SessionLog, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_slice(bytes)?)
+}
+
+/// Build the zkVM execution environment for validating a session.
+///
+/// Writes the session header words, the event count of each game, then
+/// every game's events in the guest wire encoding.
+pub fn session_env(input: &SessionInput) -> Result<ExecutorEnv<'static>, Box<dyn std::error::Error>> {
+    let event_bytes = input.event_bytes()?;
+
+    let env = ExecutorEnv::builder()
+        .write_slice(&input.header())
+        .write_slice(&input.events_lens())
+        .write_slice(&event_bytes)
+        .build()?;
+    Ok(env)
+}
+
+/// Generate a proof for every game of a session log in one guest run.
+///
+/// Game IDs are derived from the session seed; each game is validated as a
+/// single-game log with that ID would be, under `rules`.
+///
+/// # Returns
+/// * `Ok(SessionProof)` - The proof with each game's result and the session totals
+/// * `Err` - If the log version is unsupported, an event does not parse, or proving fails
+pub fn generate_session_proof(
+    session: &SessionLog,
+    receipt_kind: ReceiptKind,
+    rules: Ruleset,
+) -> Result<SessionProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Generating session proof for {} games (seed {}) with receipt kind: {}",
+        session.games.len(),
+        session.session_seed,
+        receipt_kind
+    );
+
+    let current = registry::release(GUEST_REGISTRY, CURRENT_GUEST_VERSION)
+        .ok_or("Current guest version is missing from the registry")?;
+    if !current.supports_log_version(session.v) {
+        return Err(format!(
+            "Unsupported log version: {} (guest version {} accepts {:?})",
+            session.v, current.version, current.log_versions
+        )
+        .into());
+    }
+
+    let input = SessionInput {
+        session_seed: session.session_seed,
+        log_version: session.v,
+        rules,
+        games: session.parse_games()?,
+    };
+    let env = session_env(&input)?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, SESSION_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to generate session proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let output: SessionOutput = receipt.journal.decode()?;
+
+    tracing::info!("Session proof generated successfully (receipt kind: {})", receipt_kind);
+
+    Ok(SessionProof {
+        receipt,
+        receipt_kind,
+        output,
+    })
+}
+
+/// Verify a session proof against the session guest, and that its stored output matches the journal
+pub fn verify_session_proof(proof: &SessionProof) -> Result<(), Box<dyn std::error::Error>> {
+    proof
+        .receipt
+        .verify(SESSION_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    let output: SessionOutput = proof.receipt.journal.decode()?;
+    if output != proof.output {
+        return Err("Session output does not match the journal".into());
+    }
+    Ok(())
+}
+
+/// Write a session proof to `path` as pretty-printed JSON
+pub fn save_session_proof(proof: &SessionProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(proof)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::composition::{check_pong_image_ids, pong_image_ids, proven_game};
+use crate::{prover_opts, PongProof, ReceiptKind};
+use core::{Bracket, GameResult, TournamentInput, TournamentOutput};
+use methods::{TOURNAMENT_ELF, TOURNAMENT_ID};
+use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use serde::{Deserialize, Serialize};
+
+/// Proof of a bracket's champion, backed by the proofs of its games
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Decoded journal: bracket hash, champion and the pong image IDs the games were verified against
+    pub output: TournamentOutput,
+}
+
+/// Generate a proof that `bracket` was won by its champion.
+///
+/// `proofs` holds one proof per match, in any order, each played with the
+/// match's [`Bracket::game_id`]; they are verified here and then again inside
+/// the tournament guest by composition, which also checks every game was
+/// fair, played under the bracket's rules, had a winner and was played by the
+/// players in its slot, and that winners advance through the bracket.
+///
+/// # Returns
+/// * `Ok(TournamentProof)` - The proof with its decoded journal
+/// * `Err` - If a game proof does not verify, a match has no proof, or the bracket cannot be played out
+pub fn generate_tournament_proof(
+    bracket: &Bracket,
+    proofs: &[PongProof],
+    receipt_kind: ReceiptKind,
+) -> Result<TournamentProof, Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Generating tournament proof for {} players with receipt kind: {}",
+        bracket.players.len(),
+        receipt_kind
+    );
+    bracket.validate()?;
+
+    // One proof per match, in match order. Later matches depend on who won
+    // the earlier ones, so the bracket is played out here as the proofs are
+    // found, which also gives a clear error instead of a failed guest.
+    let mut games = Vec::with_capacity(bracket.players.len() - 1);
+    let mut receipts = Vec::with_capacity(bracket.players.len() - 1);
+    let mut results = Vec::with_capacity(bracket.players.len() - 1);
+    while let Some(game_id) = bracket.next_game_id(&results)? {
+        let proof = proofs
+            .iter()
+            .find(|p| p.game_id == game_id)
+            .ok_or_else(|| format!("No proof for bracket game {} (match {})", game_id, results.len()))?;
+        let game = proven_game(proof)?;
+        results.push(GameResult::from_output(&game.output, &bracket.rules)?);
+        games.push(game);
+        receipts.push(proof.receipt.clone());
+    }
+
+    let input = TournamentInput {
+        bracket: bracket.clone(),
+        pong_image_ids: pong_image_ids(&games),
+        games,
+    };
+    let mut builder = ExecutorEnv::builder();
+    for receipt in receipts {
+        builder.add_assumption(receipt);
+    }
+    let env = builder.write(&input)?.build()?;
+
+    let prove_info = default_prover()
+        .prove_with_opts(env, TOURNAMENT_ELF, &prover_opts(receipt_kind))
+        .map_err(|e| format!("Failed to generate tournament proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+    let output: TournamentOutput = receipt.journal.decode()?;
+
+    tracing::info!("Tournament proof generated successfully (champion: {})", output.champion);
+
+    Ok(TournamentProof {
+        receipt,
+        receipt_kind,
+        output,
+    })
+}
+
+/// Verify a tournament proof.
+///
+/// Checks the receipt against the tournament guest, that the stored output
+/// matches the journal, and that every pong image ID the games were verified
+/// against belongs to a registered, non-revoked guest release.
+pub fn verify_tournament_proof(proof: &TournamentProof) -> Result<(), Box<dyn std::error::Error>> {
+    proof
+        .receipt
+        .verify(TOURNAMENT_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    let output: TournamentOutput = proof.receipt.journal.decode()?;
+    if output != proof.output {
+        return Err("Tournament output does not match the journal".into());
+    }
+
+    check_pong_image_ids(&output.pong_image_ids)
+}
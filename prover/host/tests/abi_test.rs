@@ -0,0 +1,166 @@
+// Tests for the ABI-stable journal layout in core and the ABI journal guest
+use core::{
+    config_digest, error_code, error_reason, AbiJournal, EndReason, Outcome, Ruleset, ScoringRules, ValidateLogOutput,
+    ABI_JOURNAL_LEN, ABI_JOURNAL_VERSION, ERROR_REASONS, ERROR_UNKNOWN, REASON_BALL_TOO_FAST, REASON_MATCH_NOT_WON,
+    REASON_NO_WINNER, REASON_PADDLE_TOO_FAST, REASON_UNSALTED_DISCLOSURE, Violator,
+};
+use host::{generate_abi_proof, generate_pong_proof, verify_abi_proof, ReceiptKind};
+use risc0_zkvm::sha::Digestible;
+
+mod common;
+use common::{read_log, LOG_19};
+
+fn journal() -> AbiJournal {
+    AbiJournal {
+        version: ABI_JOURNAL_VERSION,
+        fair: true,
+        error_code: 0,
+        left_score: 3,
+        right_score: 2,
+        events_len: 38,
+        log_hash: [0xab; 32],
+        game_id: 0x0102_0304,
+        config_digest: config_digest(1, &Ruleset::STANDARD),
+        outcome: Some(Outcome::LeftWin),
+        end_reason: Some(EndReason::Score),
+        games_won: false,
+        violator: None,
+    }
+}
+
+#[test]
+fn test_abi_layout() {
+    let journal = journal();
+    let bytes = journal.encode();
+    assert_eq!(bytes.len(), ABI_JOURNAL_LEN);
+
+    // Fields concatenated big-endian, as a contract would rebuild them
+    let mut expected = Vec::new();
+    expected.extend(journal.version.to_be_bytes());
+    expected.push(1);
+    expected.extend(journal.error_code.to_be_bytes());
+    expected.extend(3u32.to_be_bytes());
+    expected.extend(2u32.to_be_bytes());
+    expected.extend(38u32.to_be_bytes());
+    expected.extend(journal.log_hash);
+    expected.extend([1, 2, 3, 4]);
+    expected.extend(journal.config_digest);
+    expected.extend([1, 1, 0, 0]);
+    assert_eq!(bytes.to_vec(), expected);
+    assert_eq!(journal.digest().as_slice(), expected.digest().as_bytes());
+
+    assert_eq!(AbiJournal::decode(&bytes), Ok(journal));
+}
+
+#[test]
+fn test_abi_decode_rejects_malformed_journals() {
+    let bytes = journal().encode();
+    assert!(AbiJournal::decode(&bytes[..ABI_JOURNAL_LEN - 1]).is_err());
+    assert!(AbiJournal::decode(&[bytes.as_slice(), &[0]].concat()).is_err());
+
+    let mut version = bytes;
+    version[3] = 2;
+    assert!(AbiJournal::decode(&version).is_err());
+    let mut fair = bytes;
+    fair[4] = 2;
+    assert!(AbiJournal::decode(&fair).is_err());
+    for at in [89, 90, 92] {
+        let mut code = bytes;
+        code[at] = 4;
+        assert!(AbiJournal::decode(&code).is_err());
+    }
+    let mut games_won = bytes;
+    games_won[91] = 2;
+    assert!(AbiJournal::decode(&games_won).is_err());
+}
+
+#[test]
+fn test_abi_end_of_match() {
+    // A draw at the clock in a best-of-3 match is not a points win
+    let mut out = ValidateLogOutput::invalid("");
+    out.fair = true;
+    out.reason = None;
+    out.rules.scoring = ScoringRules { best_of: 3, time_limit: 60, allow_draw: true, ..ScoringRules::CLASSIC };
+    out.end_reason = Some(EndReason::Clock);
+    out.outcome = Some(Outcome::Draw);
+    let journal = AbiJournal::from_output(&out);
+    assert_eq!(journal.encode()[89..], [3, 2, 1, 0]);
+    assert_eq!(AbiJournal::decode(&journal.encode()), Ok(journal));
+
+    // An abandoned prefix has no outcome, and keeps the violation that ended it and whose it was
+    out.rules = Ruleset::STANDARD;
+    out.end_reason = Some(EndReason::Abandoned);
+    out.outcome = None;
+    out.reason = Some(REASON_PADDLE_TOO_FAST.into());
+    out.violator = Some(Violator::Right);
+    let journal = AbiJournal::from_output(&out);
+    assert_eq!(journal.encode()[89..], [0, 3, 0, 2]);
+    assert_eq!(error_reason(journal.error_code), Some(REASON_PADDLE_TOO_FAST));
+    assert_eq!(AbiJournal::decode(&journal.encode()), Ok(journal));
+
+    // A rejected log has neither
+    let journal = AbiJournal::from_output(&ValidateLogOutput::invalid(REASON_MATCH_NOT_WON));
+    assert_eq!(journal.encode()[89..], [0, 0, 0, 0]);
+}
+
+#[test]
+fn test_error_codes() {
+    assert_eq!(error_code(None), 0);
+    assert_eq!(error_reason(0), None);
+    for (i, reason) in ERROR_REASONS.iter().enumerate() {
+        let code = error_code(Some(reason));
+        assert_eq!(code, i as u32 + 1);
+        assert_eq!(error_reason(code), Some(*reason));
+    }
+    assert_eq!(error_code(Some("Something else")), ERROR_UNKNOWN);
+    assert_eq!(error_reason(ERROR_UNKNOWN), None);
+    // The table holds the guest's own reason constants
+    assert_eq!(error_code(Some(REASON_MATCH_NOT_WON)), 17);
+    assert_eq!(error_code(Some(REASON_BALL_TOO_FAST)), 18);
+    assert_eq!(error_code(Some(REASON_UNSALTED_DISCLOSURE)), 20);
+    assert_eq!(error_code(Some(REASON_NO_WINNER)), ERROR_REASONS.len() as u32);
+
+    // Rejections map through from_output
+    let out = ValidateLogOutput::invalid("Events must be pairs");
+    let journal = AbiJournal::from_output(&out);
+    assert!(!journal.fair);
+    assert_eq!(error_reason(journal.error_code), Some("Events must be pairs"));
+}
+
+#[test]
+fn test_config_digest_binds_version_and_rules() {
+    let standard = config_digest(1, &Ruleset::STANDARD);
+    assert_ne!(standard, config_digest(2, &Ruleset::STANDARD));
+    assert_ne!(standard, config_digest(1, &Ruleset::HUMAN_PLAY));
+    assert_ne!(standard, config_digest(1, &Ruleset::SPIN));
+}
+
+#[test]
+fn test_guest_commits_abi_journal() {
+    let log = read_log(LOG_19);
+    let proof = generate_abi_proof(&log, ReceiptKind::Succinct, Ruleset::STANDARD).expect("Failed to prove game");
+    verify_abi_proof(&proof).expect("ABI proof does not verify");
+
+    // Same result as the serde journal of the full-log guest
+    let pong = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to prove game");
+    let out: ValidateLogOutput = pong.receipt.journal.decode().unwrap();
+    assert_eq!(proof.journal, AbiJournal::from_output(&out));
+
+    // The journal is exactly the encoded fields, so its digest can be recomputed from them
+    assert_eq!(proof.receipt.journal.bytes, proof.journal.encode().to_vec());
+    assert_eq!(proof.receipt.journal.bytes.digest().as_bytes(), proof.journal.digest().as_slice());
+
+    let mut tampered = proof.clone();
+    tampered.journal.left_score = 0;
+    assert!(verify_abi_proof(&tampered).is_err());
+}
+
+#[test]
+fn test_guest_commits_error_code() {
+    let mut log = read_log(LOG_19);
+    log.events[6] = "99999999".into();
+    let proof = generate_abi_proof(&log, ReceiptKind::Succinct, Ruleset::STANDARD).expect("Failed to prove game");
+    assert!(!proof.journal.fair);
+    assert_eq!(error_reason(proof.journal.error_code), Some(REASON_PADDLE_TOO_FAST));
+    assert_eq!(proof.journal.end_reason, None);
+}
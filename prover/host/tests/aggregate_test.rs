@@ -0,0 +1,114 @@
+// Tests for batch aggregation: the Merkle tree in core and the aggregation guest
+use core::{merkle_root, EndReason, GameLeaf, InclusionProof, Violator};
+use host::{generate_aggregate_proof, generate_pong_proof, verify_aggregate_proof, ReceiptKind};
+
+mod common;
+use common::{read_log, SAMPLE_LOGS};
+
+fn leaf(game_id: u32) -> GameLeaf {
+    GameLeaf {
+        game_id,
+        log_hash: [game_id as u8; 32],
+        left_score: 3,
+        right_score: game_id % 3,
+        fair: true,
+        end_reason: Some(EndReason::Score),
+        violator: None,
+    }
+}
+
+fn root_of(leaves: &[GameLeaf]) -> [u8; 32] {
+    let hashes: Vec<_> = leaves.iter().map(GameLeaf::hash).collect();
+    merkle_root(&hashes).unwrap()
+}
+
+#[test]
+fn test_inclusion_proofs_for_every_tree_size() {
+    assert_eq!(merkle_root(&[]), None);
+    for n in 1..=9 {
+        let leaves: Vec<_> = (0..n).map(leaf).collect();
+        let root = root_of(&leaves);
+        for i in 0..leaves.len() {
+            let proof = InclusionProof::new(&leaves, i).unwrap();
+            assert!(proof.verify(&root), "Leaf {} of {} does not verify", i, n);
+        }
+        assert!(InclusionProof::new(&leaves, leaves.len()).is_none());
+    }
+
+    // A single game is its own root
+    assert_eq!(root_of(&[leaf(7)]), leaf(7).hash());
+}
+
+#[test]
+fn test_inclusion_proof_binds_leaf_and_position() {
+    let leaves: Vec<_> = (0..5).map(leaf).collect();
+    let root = root_of(&leaves);
+    let proof = InclusionProof::new(&leaves, 2).unwrap();
+
+    let mut unfair = proof.clone();
+    unfair.leaf.fair = false;
+    let mut rescored = proof.clone();
+    rescored.leaf.right_score += 1;
+    let mut moved = proof.clone();
+    moved.index = 3;
+    let mut resized = proof.clone();
+    resized.leaves = 4;
+    let mut padded = proof.clone();
+    padded.siblings.push([0; 32]);
+    // An abandoned game at the same score is a different leaf, as is who broke the rules
+    let mut abandoned = proof.clone();
+    abandoned.leaf.end_reason = Some(EndReason::Abandoned);
+    let mut blamed = abandoned.clone();
+    blamed.leaf.violator = Some(Violator::Left);
+    assert_ne!(abandoned.leaf.hash(), blamed.leaf.hash());
+    for bad in [unfair, rescored, moved, resized, padded, abandoned, blamed] {
+        assert!(!bad.verify(&root));
+    }
+
+    // Carrying the odd node up keeps [a, b, c] and [a, b, c, c] apart
+    let repeated = [leaves[..3].to_vec(), vec![leaves[2]]].concat();
+    assert_ne!(root_of(&leaves[..3]), root_of(&repeated));
+}
+
+#[test]
+fn test_guest_aggregates_games() {
+    let proofs: Vec<_> = SAMPLE_LOGS
+        .iter()
+        .map(|path| generate_pong_proof(&read_log(path), ReceiptKind::Succinct).expect("Failed to prove game"))
+        .collect();
+
+    let aggregate = generate_aggregate_proof(&proofs, ReceiptKind::Succinct).expect("Failed to aggregate");
+    verify_aggregate_proof(&aggregate).expect("Aggregate proof does not verify");
+    assert_eq!(aggregate.output.games, 3);
+
+    // The root commits each game's (game_id, log_hash, scores, fair, end reason, violator) in order
+    let leaves: Vec<_> = proofs
+        .iter()
+        .map(|p| GameLeaf {
+            game_id: p.game_id,
+            log_hash: p.log_hash,
+            left_score: p.left_score,
+            right_score: p.right_score,
+            fair: p.fair,
+            end_reason: p.end_reason,
+            violator: p.violator,
+        })
+        .collect();
+    assert_eq!(aggregate.output.root, root_of(&leaves));
+    let inclusion = aggregate.inclusion_proof(proofs[1].game_id).unwrap();
+    assert_eq!(inclusion.leaf, leaves[1]);
+    assert!(inclusion.verify(&aggregate.output.root));
+
+    // A tampered inclusion proof fails verification of the whole aggregate
+    let mut tampered = aggregate.clone();
+    tampered.inclusion_proofs[0].leaf.left_score = 0;
+    assert!(verify_aggregate_proof(&tampered).is_err());
+}
+
+#[test]
+fn test_aggregate_rejects_empty_and_duplicate_batches() {
+    assert!(generate_aggregate_proof(&[], ReceiptKind::Succinct).is_err());
+
+    let proof = generate_pong_proof(&read_log(SAMPLE_LOGS[0]), ReceiptKind::Succinct).unwrap();
+    assert!(generate_aggregate_proof(&[proof.clone(), proof], ReceiptKind::Succinct).is_err());
+}
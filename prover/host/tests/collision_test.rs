@@ -0,0 +1,59 @@
+// Tests for the swept paddle collision of log v2
+//
+// The logs are in common/mod.rs, all played as COLLISION_GAME_ID.
+use core::{Collision, Physics, Trig, ValidateLogInput, ValidateLogOutput, MAX_BALL_SPEED};
+use host::executor_env;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ELF;
+use risc0_zkvm::default_prover;
+
+mod common;
+use common::{COLLISION_GAME_ID, CORNER_RETURN_LOG, EDGE_GLANCE_LOG, GLANCING_LOG};
+
+fn run(events: &[i64], log_version: u32) -> ValidateLogOutput {
+    let input = ValidateLogInput::new(events.to_vec(), COLLISION_GAME_ID, log_version);
+    let env = executor_env(&input).unwrap();
+
+    let prover = default_prover();
+    let receipt = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap().receipt;
+    receipt.journal.decode().unwrap()
+}
+
+#[test]
+fn test_log_versions_select_physics() {
+    assert_eq!(
+        Physics::for_log_version(1),
+        Some(Physics { trig: Trig::Cordic, collision: Collision::Face, max_ball_speed: None })
+    );
+    assert_eq!(
+        Physics::for_log_version(2),
+        Some(Physics { trig: Trig::Table, collision: Collision::Swept, max_ball_speed: Some(MAX_BALL_SPEED) })
+    );
+    assert_eq!(Physics::for_log_version(0), None);
+    assert_eq!(Physics::for_log_version(3), None);
+}
+
+#[test]
+fn test_corner_glance_is_a_miss() {
+    let output = run(&GLANCING_LOG, 2);
+    assert!(output.fair, "Log should be valid: {:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (3, 0));
+
+    // v1 counts the first ball as a hit, so the rest of the log no longer
+    // matches the game it describes
+    let v1 = run(&GLANCING_LOG, 1);
+    assert_ne!((v1.fair, v1.left_score, v1.right_score), (true, 3, 0));
+}
+
+#[test]
+fn test_corner_strike_returns_ball() {
+    let output = run(&CORNER_RETURN_LOG, 2);
+    assert!(output.fair, "Log should be valid: {:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (2, 3));
+}
+
+#[test]
+fn test_edge_glance_is_a_miss() {
+    let output = run(&EDGE_GLANCE_LOG, 2);
+    assert!(output.fair, "Log should be valid: {:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (2, 3));
+}
@@ -0,0 +1,87 @@
+// Fixtures and helpers shared by the integration tests
+//
+// Every test binary compiles its own copy of this module and uses only part
+// of it, hence the dead_code allowance.
+#![allow(dead_code)]
+
+use core::{CompactLog, I, LOG_SALT_BYTES};
+use host::load_log;
+
+/// Sample log won 3-2 by the left player
+pub const LOG_19: &str = "../../pong-log_events19_1761147203682.json";
+/// Sample log won 2-3 by the right player
+pub const LOG_64: &str = "../../pong-log_events64_1761147732142.json";
+pub const LOG_71: &str = "../../pong-log_events71_1761147635847.json";
+
+/// The three sample logs recorded from the web client
+pub const SAMPLE_LOGS: [&str; 3] = [LOG_19, LOG_64, LOG_71];
+
+/// Log v1 rally (game_id 103) that returns the ball 638 times, until the
+/// next return would take its speed past the fixed-point limit
+pub const LONG_RALLY_V1: &str = "tests/data/long_rally_v1.json";
+
+/// Games of bracket 1 (players 10, 20, 30 and 40 under the standard rules),
+/// played with its game IDs: 10 beats 20 3-0, 40 beats 30 3-0, and 10 (left)
+/// beats 40 3-0 in the final
+pub const BRACKET1_SEMI_0: &str = "tests/data/bracket1_match0.json";
+pub const BRACKET1_SEMI_1: &str = "tests/data/bracket1_match1.json";
+pub const BRACKET1_FINAL: &str = "tests/data/bracket1_match2.json";
+
+/// Games of ladder 1, played with its game IDs: 10 (left) beats 20 in round 0,
+/// and 30 (left) beats 10 in round 1
+pub const LADDER1_ROUND_0: &str = "tests/data/ladder1_round0.json";
+pub const LADDER1_ROUND_1: &str = "tests/data/ladder1_round1.json";
+
+/// Salt for salted and partially disclosed proofs
+pub const SALT: [u8; LOG_SALT_BYTES] = [0x5a; LOG_SALT_BYTES];
+
+/// Game ID of the collision logs below
+///
+/// It serves perfectly flat under the v2 table trig (see
+/// test_longest_rally_does_not_overflow), so the first ball reaches the right
+/// paddle at y = 240 px. The logs were laid out with a floating-point model
+/// of the guest: receivers that should return the ball center on it, and
+/// every other offset leaves several pixels of margin.
+pub const COLLISION_GAME_ID: u32 = 103;
+
+/// Right paddle 45 px below every ball that reaches it: inside v1's square
+/// reach (40 px half height + 6 px ball radius), but the ball passes the
+/// rounded corner and only glances off it. Left centers on every serve.
+pub const GLANCING_LOG: [I; 10] = [
+    15728640, 18677760, // R glances (v2) / hits (v1): 1-0
+    10920374, 18677760, // L returns the serve flat
+    10920374, 13869494, // R glances: 2-0
+    6950680, 13869494,  // L returns the serve flat
+    6950680, 9899800,   // R glances: 3-0
+];
+
+/// First ball strikes the right paddle's top corner 42 px from its center
+/// and is knocked back into play. Left then stays away from every ball and
+/// right keeps glancing, so right wins once the corner return scores.
+pub const CORNER_RETURN_LOG: [I; 12] = [
+    15728640, 18481152, // R returns off the corner
+    2621440, 18481152,  // L misses: 0-1
+    2621440, 25067874,  // R glances: 1-1
+    11860856, 25067874, // L misses: 1-2
+    11860856, 16154067, // R glances: 2-2
+    3113445, 16154067,  // L misses: 2-3
+];
+
+/// Right returns the serve off the end of its face at 60°, and the ball comes
+/// back up under the left paddle, past its front corner and onto its bottom
+/// edge 5 px behind the face. The edge turns the ball back down, but it still
+/// carries on to the goal line. Then each receiver stays clear of the ball.
+pub const EDGE_GLANCE_LOG: [I; 12] = [
+    15728640, 13107200, // R returns the serve steeply
+    19922944, 13107200, // L's bottom edge deflects it: 0-1
+    19922944, 13107200, // R misses: 1-1
+    19922944, 13107200, // L misses: 1-2
+    19922944, 19660800, // R misses: 2-2
+    13369344, 19660800, // L misses: 2-3
+];
+
+/// Load a log file (JSON or binary)
+pub fn read_log(path: &str) -> CompactLog {
+    let bytes = std::fs::read(path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    load_log(&bytes).expect("Failed to load log")
+}
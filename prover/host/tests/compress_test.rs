@@ -0,0 +1,40 @@
+// Tests for compressing existing proofs to smaller receipt kinds
+use host::{compress_pong_proof, generate_pong_proof, verify_pong_proof, ReceiptKind};
+
+mod common;
+use common::{read_log, LOG_19};
+
+#[test]
+fn test_compress_composite_to_succinct() {
+    let log = read_log(LOG_19);
+
+    let composite = generate_pong_proof(&log, ReceiptKind::Composite)
+        .expect("Failed to generate proof");
+
+    let succinct = compress_pong_proof(&composite, ReceiptKind::Succinct)
+        .expect("Failed to compress proof");
+
+    assert_eq!(succinct.receipt_kind, ReceiptKind::Succinct);
+    verify_pong_proof(&succinct).expect("Compressed receipt verification failed");
+
+    // Compression must not change the committed game result
+    assert_eq!(succinct.receipt.journal.bytes, composite.receipt.journal.bytes);
+    assert_eq!(succinct.game_id, composite.game_id);
+    assert_eq!(succinct.log_hash, composite.log_hash);
+    assert_eq!(succinct.left_score, composite.left_score);
+    assert_eq!(succinct.right_score, composite.right_score);
+}
+
+#[test]
+fn test_compress_rejects_same_or_larger_kind() {
+    let log = read_log(LOG_19);
+
+    let succinct = generate_pong_proof(&log, ReceiptKind::Succinct)
+        .expect("Failed to generate proof");
+
+    let same = compress_pong_proof(&succinct, ReceiptKind::Succinct);
+    assert!(same.is_err(), "Compressing to the same kind should fail");
+
+    let larger = compress_pong_proof(&succinct, ReceiptKind::Composite);
+    assert!(larger.is_err(), "Compressing to a larger kind should fail");
+}
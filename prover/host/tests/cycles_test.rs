@@ -0,0 +1,167 @@
+// Cycle measurements for the guest on the sample logs
+// The per-event reports are ignored by default; run them with:
+//   RISC0_DEV_MODE=1 cargo test -p host --test cycles_test -- --ignored --nocapture
+use core::{compute_log_hash, encode_events, ValidateLogInput, ValidateLogOutput, MAX_EVENTS};
+use host::executor_env;
+use methods::{CYCLE_BENCH_ELF, GUEST_CODE_FOR_ZK_PROOF_ELF};
+use risc0_zkvm::sha::{Impl, Sha256};
+use risc0_zkvm::{default_executor, ExecutorEnv};
+
+mod common;
+use common::{read_log, SAMPLE_LOGS};
+
+/// Budget for `compute_log_hash` over a MAX_EVENTS log, about 40 KB or 625
+/// SHA-256 blocks.
+///
+/// With the accelerator each block costs on the order of a hundred cycles; a
+/// software SHA-256 compression on RV32IM costs thousands, which would put the
+/// hash alone well over a million cycles.
+const MAX_LOG_HASH_CYCLE_BUDGET: u64 = 250_000;
+
+fn load_input(path: &str) -> ValidateLogInput {
+    let log = read_log(path);
+    let events = log.parse_events().expect("Failed to parse events");
+    ValidateLogInput::new(events, log.game_id, log.v)
+}
+
+/// Paths of the bench guest (see methods/guest/src/bin/cycle_bench.rs): the
+/// serde `Vec<i64>` the guest used to read, the raw words it reads now, and
+/// the log hash alone, with and without the SHA-256 accelerator
+const PATH_SERDE: u32 = 0;
+const PATH_RAW: u32 = 1;
+const PATH_HASH: u32 = 2;
+const PATH_SOFT_HASH: u32 = 3;
+
+/// Cycles the bench guest spends on `events` along `path`
+fn bench_cycles(path: u32, events: &[i64]) -> u64 {
+    let event_bytes = encode_events(events).unwrap();
+    let mut builder = ExecutorEnv::builder();
+    builder.write_slice(&[path]);
+    let expected = match path {
+        PATH_SERDE => {
+            builder.write(&events.to_vec()).unwrap();
+            let mut buf = b"PONGLOGv1".to_vec();
+            for e in events {
+                buf.extend_from_slice(&e.to_le_bytes());
+            }
+            let digest = Impl::hash_bytes(&buf);
+            <[u8; 32]>::try_from(digest.as_bytes()).unwrap()
+        }
+        _ => {
+            builder.write_slice(&[events.len() as u32]).write_slice(&event_bytes);
+            compute_log_hash(&event_bytes)
+        }
+    };
+
+    let session = default_executor()
+        .execute(builder.build().unwrap(), CYCLE_BENCH_ELF)
+        .expect("Failed to execute bench guest");
+    let (cycles, hash): (u64, [u8; 32]) = session.journal.decode().expect("Failed to decode journal");
+    assert_eq!(hash, expected, "Bench path {} did not see every event", path);
+    cycles
+}
+
+/// A sample log repeated out to MAX_EVENTS
+fn max_events() -> Vec<i64> {
+    let events = load_input(SAMPLE_LOGS[0]).events;
+    events.iter().copied().cycle().take(MAX_EVENTS as usize).collect()
+}
+
+/// Execute the guest (without proving) and return (journal, user cycles, segments)
+fn execute(input: &ValidateLogInput) -> (ValidateLogOutput, u64, u64) {
+    let env = executor_env(input).unwrap();
+
+    let session = default_executor()
+        .execute(env, GUEST_CODE_FOR_ZK_PROOF_ELF)
+        .expect("Failed to execute guest");
+
+    let output: ValidateLogOutput = session.journal.decode().expect("Failed to decode journal");
+    (output, session.cycles(), session.segments.len() as u64)
+}
+
+#[test]
+fn test_max_log_hash_within_cycle_budget() {
+    // Counted in the guest around compute_log_hash alone, so neither reading
+    // the events nor replaying them is in the figure
+    let events = max_events();
+    let hash_cycles = bench_cycles(PATH_HASH, &events);
+    let software_cycles = bench_cycles(PATH_SOFT_HASH, &events);
+    println!(
+        "compute_log_hash over {} events: {} cycles ({} per event), {} cycles in software ({} per event)",
+        MAX_EVENTS,
+        hash_cycles,
+        hash_cycles / MAX_EVENTS as u64,
+        software_cycles,
+        software_cycles / MAX_EVENTS as u64
+    );
+    assert!(
+        hash_cycles < software_cycles,
+        "Accelerated hash took {} cycles, software hash {}; is the sha2 accelerator patch applied?",
+        hash_cycles,
+        software_cycles
+    );
+    assert!(
+        hash_cycles <= MAX_LOG_HASH_CYCLE_BUDGET,
+        "Hashing {} events took {} cycles (budget {}); is the sha2 accelerator patch applied?",
+        MAX_EVENTS,
+        hash_cycles,
+        MAX_LOG_HASH_CYCLE_BUDGET
+    );
+}
+
+#[test]
+#[ignore]
+fn report_cycles_per_event() {
+    println!("{:<48} {:>8} {:>12} {:>12} {:>10}", "log", "events", "cycles", "per event", "segments");
+    for path in SAMPLE_LOGS {
+        let input = load_input(path);
+        let events_len = input.events.len() as u32;
+        let (_, cycles, segments) = execute(&input);
+        println!(
+            "{:<48} {:>8} {:>12} {:>12} {:>10}",
+            path.trim_start_matches("../../"),
+            events_len,
+            cycles,
+            cycles / events_len as u64,
+            segments
+        );
+    }
+}
+
+#[test]
+fn test_raw_input_path_saves_cycles() {
+    // Both paths take in the same maximum-size log; the raw one skips serde
+    // and the copy into a hash buffer, and hashes half the bytes
+    let events = max_events();
+    let serde = bench_cycles(PATH_SERDE, &events);
+    let raw = bench_cycles(PATH_RAW, &events);
+    println!(
+        "{} events: serde input {} cycles ({} per event), raw input {} cycles ({} per event)",
+        MAX_EVENTS,
+        serde,
+        serde / MAX_EVENTS as u64,
+        raw,
+        raw / MAX_EVENTS as u64
+    );
+    assert!(raw < serde, "Raw input took {} cycles, serde input {}", raw, serde);
+}
+
+#[test]
+#[ignore]
+fn report_input_cycles_per_event() {
+    println!("{:<48} {:>8} {:>16} {:>16} {:>10}", "log", "events", "serde per event", "raw per event", "saved");
+    let logs = SAMPLE_LOGS.map(|path| (path.trim_start_matches("../../"), load_input(path).events));
+    for (name, events) in logs.into_iter().chain([("sample log padded to MAX_EVENTS", max_events())]) {
+        let n = events.len() as u64;
+        let serde = bench_cycles(PATH_SERDE, &events);
+        let raw = bench_cycles(PATH_RAW, &events);
+        println!(
+            "{:<48} {:>8} {:>16} {:>16} {:>9.1}%",
+            name,
+            n,
+            serde / n,
+            raw / n,
+            100.0 * (serde as f64 - raw as f64) / serde as f64
+        );
+    }
+}
@@ -0,0 +1,240 @@
+// Tests for the disclosure modes: partial journals in core, the guest
+// committing them, and decoding and verifying each journal shape
+use core::{
+    check_disclosure, compute_salted_log_hash, DisclosedOutput, Disclosure, EndReason, Outcome, Ruleset,
+    ValidateLogInput, ValidateLogOutput, DISCLOSED_OUTPUT_TAG, LOG_SALT_BYTES, REASON_INVALID_DISCLOSURE,
+    REASON_UNSALTED_DISCLOSURE,
+};
+use host::{
+    decode_disclosed_journal, executor_env, generate_disclosed_pong_proof, generate_pong_proof, join_segments,
+    load_disclosed_proof, save_disclosed_proof, verify_disclosed_log_commitment, verify_disclosed_proof,
+    DisclosedJournal, JournalLayout, ReceiptKind,
+};
+use methods::GUEST_CODE_FOR_ZK_PROOF_ELF;
+use risc0_zkvm::{default_prover, ExecutorEnv};
+
+mod common;
+use common::{read_log, LOG_19, SALT};
+
+/// Journal of a fair 3-1 left win over 40 events
+fn left_win() -> ValidateLogOutput {
+    let mut out = ValidateLogOutput::invalid("");
+    out.fair = true;
+    out.reason = None;
+    out.outcome = Some(Outcome::LeftWin);
+    out.end_reason = Some(EndReason::Score);
+    out.left_score = 3;
+    out.right_score = 1;
+    out.events_len = 40;
+    out.game_id = 7;
+    out.log_hash_sha256 = [0x11; 32];
+    out
+}
+
+#[test]
+fn test_disclosure_modes() {
+    for mode in [Disclosure::Full, Disclosure::WinnerOnly, Disclosure::ScoreOnly] {
+        assert_eq!(Disclosure::from_name(&mode.to_string()), Some(mode));
+        assert_eq!(Disclosure::from_word(mode.to_word()), Some(mode));
+    }
+    assert_eq!(Disclosure::default(), Disclosure::Full);
+    assert_eq!(Disclosure::from_name("winner"), None);
+    assert_eq!(Disclosure::from_word(3), None);
+
+    // Partial modes need a salt; unknown words are rejected
+    assert_eq!(check_disclosure(0, false), Ok(Disclosure::Full));
+    assert_eq!(check_disclosure(1, true), Ok(Disclosure::WinnerOnly));
+    assert_eq!(check_disclosure(2, true), Ok(Disclosure::ScoreOnly));
+    assert_eq!(check_disclosure(1, false), Err(REASON_UNSALTED_DISCLOSURE));
+    assert_eq!(check_disclosure(2, false), Err(REASON_UNSALTED_DISCLOSURE));
+    assert_eq!(check_disclosure(3, true), Err(REASON_INVALID_DISCLOSURE));
+}
+
+#[test]
+fn test_disclosed_output() {
+    let out = left_win();
+    assert_eq!(DisclosedOutput::from_output(&out, Disclosure::Full), None);
+
+    let winner = DisclosedOutput::from_output(&out, Disclosure::WinnerOnly).unwrap();
+    assert!(winner.is_well_formed());
+    assert_eq!(winner.tag, DISCLOSED_OUTPUT_TAG);
+    assert!(winner.fair);
+    assert_eq!(winner.outcome, Some(Outcome::LeftWin));
+    assert_eq!(winner.end_reason, Some(EndReason::Score));
+    assert_eq!(winner.score, None);
+    assert_eq!((winner.game_id, winner.log_hash_sha256), (7, [0x11; 32]));
+
+    let score = DisclosedOutput::from_output(&out, Disclosure::ScoreOnly).unwrap();
+    assert!(score.is_well_formed());
+    assert_eq!(score.score, Some((3, 1)));
+
+    // A partial journal opens with the tag where a full one has its fair flag
+    let words = risc0_zkvm::serde::to_vec(&score).unwrap();
+    assert_eq!(words[0], DISCLOSED_OUTPUT_TAG);
+    assert_eq!(risc0_zkvm::serde::to_vec(&out).unwrap()[0], 1);
+
+    // Fields that do not match the mode are malformed
+    let mut bad = winner.clone();
+    bad.score = Some((3, 1));
+    assert!(!bad.is_well_formed());
+    let mut bad = score.clone();
+    bad.score = None;
+    assert!(!bad.is_well_formed());
+    let mut bad = winner.clone();
+    bad.disclosure = Disclosure::Full;
+    assert!(!bad.is_well_formed());
+    let mut bad = winner;
+    bad.tag = 1;
+    assert!(!bad.is_well_formed());
+}
+
+#[test]
+fn test_disclosed_proofs() {
+    let log = read_log(LOG_19);
+    let full = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to prove log");
+
+    let winner = generate_disclosed_pong_proof(
+        &log,
+        ReceiptKind::Succinct,
+        Ruleset::STANDARD,
+        Disclosure::WinnerOnly,
+        Some(SALT),
+    )
+    .expect("Failed to prove log");
+    verify_disclosed_proof(&winner).expect("Winner-only proof does not verify");
+    assert_eq!(winner.journal.disclosure(), Disclosure::WinnerOnly);
+    assert!(winner.journal.fair());
+    assert_eq!(winner.journal.outcome(), Some(Outcome::LeftWin));
+    assert_eq!(winner.journal.score(), None);
+    assert_eq!(winner.journal.game_id(), log.game_id);
+
+    let score = generate_disclosed_pong_proof(
+        &log,
+        ReceiptKind::Succinct,
+        Ruleset::STANDARD,
+        Disclosure::ScoreOnly,
+        Some(SALT),
+    )
+    .expect("Failed to prove log");
+    verify_disclosed_proof(&score).expect("Score-only proof does not verify");
+    assert_eq!(score.journal.score(), Some((full.left_score, full.right_score)));
+    assert_eq!(score.journal.log_hash(), winner.journal.log_hash());
+
+    // Partial journals are smaller than the full one: no breakdown, no event count
+    assert!(winner.receipt.journal.bytes.len() < score.receipt.journal.bytes.len());
+    assert!(score.receipt.journal.bytes.len() < full.receipt.journal.bytes.len());
+
+    // Each shape decodes only as itself
+    assert!(matches!(
+        decode_disclosed_journal(&full.receipt, Disclosure::Full, JournalLayout::CURRENT),
+        Ok(DisclosedJournal::Full(_))
+    ));
+    assert!(decode_disclosed_journal(&full.receipt, Disclosure::WinnerOnly, JournalLayout::CURRENT).is_err());
+    assert!(decode_disclosed_journal(&winner.receipt, Disclosure::Full, JournalLayout::CURRENT).is_err());
+    assert!(decode_disclosed_journal(&winner.receipt, Disclosure::ScoreOnly, JournalLayout::CURRENT).is_err());
+    assert!(decode_disclosed_journal(&score.receipt, Disclosure::WinnerOnly, JournalLayout::CURRENT).is_err());
+
+    // A stored journal that claims more than the receipt is rejected
+    let mut forged = winner.clone();
+    forged.journal = score.journal.clone();
+    assert!(verify_disclosed_proof(&forged).is_err());
+
+    // Saved proofs round trip
+    let path = std::env::temp_dir().join("pong-disclosed_test.json");
+    let path = path.to_str().unwrap();
+    save_disclosed_proof(&winner, path).expect("Failed to save proof");
+    let loaded = load_disclosed_proof(&std::fs::read(path).unwrap()).expect("Failed to load proof");
+    std::fs::remove_file(path).ok();
+    assert_eq!(loaded.journal, winner.journal);
+    verify_disclosed_proof(&loaded).expect("Loaded proof does not verify");
+}
+
+#[test]
+fn test_salted_winner_only() {
+    let log = read_log(LOG_19);
+    let proof = generate_disclosed_pong_proof(
+        &log,
+        ReceiptKind::Succinct,
+        Ruleset::STANDARD,
+        Disclosure::WinnerOnly,
+        Some(SALT),
+    )
+    .expect("Failed to prove log");
+    verify_disclosed_proof(&proof).expect("Proof does not verify");
+
+    let event_bytes = core::encode_events(&log.parse_events().unwrap()).unwrap();
+    assert_eq!(proof.journal.log_hash(), compute_salted_log_hash(&SALT, &event_bytes));
+    assert!(!proof.receipt.journal.bytes.windows(LOG_SALT_BYTES).any(|w| w == SALT));
+
+    verify_disclosed_log_commitment(&proof, &log, Some(&SALT)).expect("Salted log does not match");
+    assert!(verify_disclosed_log_commitment(&proof, &log, None).is_err());
+    let mut edited = log.clone();
+    edited.events[0] = "15728641".into();
+    assert!(verify_disclosed_log_commitment(&proof, &edited, Some(&SALT)).is_err());
+}
+
+#[test]
+fn test_disclosure_restrictions() {
+    let log = read_log(LOG_19);
+    let zero = generate_disclosed_pong_proof(
+        &log,
+        ReceiptKind::Succinct,
+        Ruleset::STANDARD,
+        Disclosure::WinnerOnly,
+        Some([0; LOG_SALT_BYTES]),
+    );
+    assert!(zero.is_err(), "The all-zero salt must be rejected");
+    let unsalted = generate_disclosed_pong_proof(
+        &log,
+        ReceiptKind::Succinct,
+        Ruleset::STANDARD,
+        Disclosure::WinnerOnly,
+        None,
+    );
+    assert!(unsalted.is_err(), "A partial proof needs a salt");
+
+    let input = ValidateLogInput {
+        disclosure: Disclosure::ScoreOnly,
+        ..ValidateLogInput::new(log.parse_events().unwrap(), log.game_id, log.v)
+    };
+    assert!(join_segments(&input, Vec::new(), ReceiptKind::Succinct).is_err());
+}
+
+/// Prove the full-log guest on `env`, decoding a full journal
+fn prove_full(env: ExecutorEnv) -> ValidateLogOutput {
+    let receipt = default_prover()
+        .prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF)
+        .unwrap()
+        .receipt;
+    receipt.journal.decode().expect("Expected a full journal")
+}
+
+#[test]
+fn test_guest_rejects_bad_disclosure() {
+    let log = read_log(LOG_19);
+
+    // A partial mode without a salt is rejected in a full, invalid journal
+    let unsalted = ValidateLogInput {
+        disclosure: Disclosure::WinnerOnly,
+        ..ValidateLogInput::new(log.parse_events().unwrap(), log.game_id, log.v)
+    };
+    let out = prove_full(executor_env(&unsalted).unwrap());
+    assert!(!out.fair);
+    assert_eq!(out.reason.as_deref(), Some(REASON_UNSALTED_DISCLOSURE));
+
+    // So is a word that is not a mode, rather than panicking the guest
+    let input = ValidateLogInput {
+        salt: Some(SALT),
+        ..unsalted
+    };
+    let env = ExecutorEnv::builder()
+        .write_slice(&input.header())
+        .write_slice(&input.salt_bytes())
+        .write_slice(&[3u32])
+        .write_slice(&input.event_bytes().unwrap())
+        .build()
+        .unwrap();
+    let out = prove_full(env);
+    assert!(!out.fair);
+    assert_eq!(out.reason.as_deref(), Some(REASON_INVALID_DISCLOSURE));
+}
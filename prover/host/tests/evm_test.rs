@@ -0,0 +1,92 @@
+// Tests for exporting Groth16 proofs as IRiscZeroVerifier.verify calldata
+use host::{
+    evm_proof, evm_selector, generate_pong_proof, groth16_seal, verify_evm_proof, EvmProof, ReceiptKind,
+    EVM_SELECTOR_LEN,
+};
+use methods::GUEST_CODE_FOR_ZK_PROOF_ID;
+use risc0_zkvm::sha::{Digest, Digestible};
+
+mod common;
+use common::{read_log, LOG_19};
+
+#[test]
+fn test_calldata_layout() {
+    let proof = EvmProof {
+        seal: [evm_selector().as_slice(), &[7u8; 256]].concat(),
+        image_id: [1; 32],
+        journal_digest: [2; 32],
+    };
+    let calldata = proof.calldata();
+
+    // verify(bytes,bytes32,bytes32), then the seal offset, the two words and the seal
+    assert_eq!(calldata[..4], [0xab, 0x75, 0x0e, 0x75]);
+    assert_eq!(calldata[4..36], {
+        let mut offset = [0u8; 32];
+        offset[31] = 0x60;
+        offset
+    });
+    assert_eq!(calldata[36..68], proof.image_id);
+    assert_eq!(calldata[68..100], proof.journal_digest);
+    assert_eq!(calldata.len(), 4 + 4 * 32 + 260_usize.div_ceil(32) * 32);
+    assert_eq!(EvmProof::from_calldata(&calldata).unwrap(), proof);
+
+    // Other calls and truncated calldata do not decode
+    let mut other = calldata.clone();
+    other[0] ^= 1;
+    assert!(EvmProof::from_calldata(&other).is_err());
+    assert!(EvmProof::from_calldata(&calldata[..calldata.len() - 32]).is_err());
+}
+
+#[test]
+fn test_export_round_trip() {
+    let proof = generate_pong_proof(&read_log(LOG_19), ReceiptKind::Groth16).expect("Failed to prove game");
+    let journal = proof.receipt.journal.bytes.clone();
+
+    let evm = evm_proof(&proof).expect("Failed to export proof");
+    assert_eq!(evm.seal[..EVM_SELECTOR_LEN], evm_selector());
+    assert_eq!(evm.seal[EVM_SELECTOR_LEN..], groth16_seal(&proof.receipt).unwrap());
+    assert_eq!(Digest::from(evm.image_id), Digest::from(GUEST_CODE_FOR_ZK_PROOF_ID));
+    assert_eq!(Digest::from(evm.journal_digest), journal.digest());
+
+    // Decode the calldata and verify what a router would receive
+    let decoded = EvmProof::from_calldata(&evm.calldata()).expect("Failed to decode calldata");
+    assert_eq!(decoded, evm);
+    verify_evm_proof(&decoded, &journal).expect("Exported proof failed to verify");
+}
+
+#[test]
+fn test_export_rejects_tampering() {
+    let proof = generate_pong_proof(&read_log(LOG_19), ReceiptKind::Groth16).expect("Failed to prove game");
+    let journal = proof.receipt.journal.bytes.clone();
+    let evm = evm_proof(&proof).expect("Failed to export proof");
+
+    // A different journal does not match the digest
+    let mut other_journal = journal.clone();
+    other_journal[0] ^= 1;
+    assert!(verify_evm_proof(&evm, &other_journal).is_err());
+
+    // A digest and journal that agree, but not with the seal
+    let forged = EvmProof {
+        journal_digest: other_journal.digest().into(),
+        ..evm.clone()
+    };
+    assert!(verify_evm_proof(&forged, &other_journal).is_err());
+
+    let mut bad_seal = evm.clone();
+    bad_seal.seal[EVM_SELECTOR_LEN + 10] ^= 1;
+    assert!(verify_evm_proof(&bad_seal, &journal).is_err());
+
+    let mut bad_selector = evm.clone();
+    bad_selector.seal[0] ^= 1;
+    assert!(verify_evm_proof(&bad_selector, &journal).is_err());
+
+    let mut bad_image = evm.clone();
+    bad_image.image_id[0] ^= 1;
+    assert!(verify_evm_proof(&bad_image, &journal).is_err());
+}
+
+#[test]
+fn test_export_requires_groth16() {
+    let proof = generate_pong_proof(&read_log(LOG_19), ReceiptKind::Succinct).expect("Failed to prove game");
+    assert!(evm_proof(&proof).is_err());
+}
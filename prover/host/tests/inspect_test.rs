@@ -0,0 +1,41 @@
+// Tests for inspecting saved proofs
+use host::{generate_pong_proof, inspect_proof, ReceiptKind, SavedProof};
+
+mod common;
+use common::{read_log, LOG_19};
+
+fn saved_proof(path: &str) -> SavedProof {
+    let proof = generate_pong_proof(&read_log(path), ReceiptKind::Composite).expect("Failed to generate proof");
+    SavedProof::new(proof).expect("Failed to wrap proof")
+}
+
+#[test]
+fn test_inspect_consistent_proof() {
+    let saved = saved_proof(LOG_19);
+
+    let inspection = inspect_proof(&saved);
+
+    assert!(inspection.metadata_consistent, "Issues: {:?}", inspection.issues);
+    assert!(inspection.image_id_matches_guest, "Image ID should match the guest");
+    assert!(inspection.claim_digest.is_some(), "Claim digest should be available");
+
+    let journal = inspection.journal.expect("Journal should decode");
+    assert_eq!(journal.events_len, 38);
+    assert_eq!(journal.game_id, saved.game_id);
+    assert_eq!(inspection.journal_bytes, hex::encode(&saved.proof.receipt.journal.bytes));
+}
+
+#[test]
+fn test_inspect_detects_tampered_metadata() {
+    let mut saved = saved_proof(LOG_19);
+
+    // Claim a different result than the journal commits
+    saved.left_score += 1;
+    saved.proof.game_id ^= 1;
+
+    let inspection = inspect_proof(&saved);
+
+    assert!(!inspection.metadata_consistent, "Tampered metadata should be reported");
+    assert!(inspection.issues.iter().any(|i| i.starts_with("left_score")));
+    assert!(inspection.issues.iter().any(|i| i.starts_with("proof.game_id")));
+}
@@ -1,21 +1,21 @@
 // Unit tests for RISC0 zkVM prover validation logic
 // Tests that rely on specific JSON log files are in log_validation_test.rs
-use core::{ValidateLogInput, ValidateLogOutput};
+use core::{EndReason, Ruleset, ScoringRules, ValidateLogInput, ValidateLogOutput, REASON_BALL_TOO_FAST};
+use host::executor_env;
 use methods::{GUEST_CODE_FOR_ZK_PROOF_ELF, GUEST_CODE_FOR_ZK_PROOF_ID};
-use risc0_zkvm::{default_prover, ExecutorEnv};
+use risc0_zkvm::default_prover;
+
+mod common;
+use common::{read_log, LONG_RALLY_V1};
 
 #[test]
 fn test_invalid_too_many_events() {
     let events = vec![0; 10002]; // Over the 10,000 limit
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -37,13 +37,9 @@ fn test_odd_event_count() {
     let events = vec![0; 11]; // Odd number - invalid!
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -65,13 +61,9 @@ fn test_exactly_10000_events() {
     let events = vec![0; 10000]; // Exactly at the limit - should be OK
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -96,20 +88,13 @@ fn test_hash_determinism() {
     let events = vec![12345, 67890, 11111, 22222];
     let game_id = 5u32; // Use same game_id for both runs
 
-    let input = ValidateLogInput {
-        events: events.clone(),
-        game_id,
-    };
+    let input = ValidateLogInput::new(events.clone(), game_id, 1);
 
     // Run proof twice with same inputs
     let mut hashes = Vec::new();
 
     for _ in 0..2 {
-        let env = ExecutorEnv::builder()
-            .write(&input)
-            .unwrap()
-            .build()
-            .unwrap();
+        let env = executor_env(&input).unwrap();
 
         let prover = default_prover();
         let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -132,13 +117,9 @@ fn test_empty_events() {
     let events: Vec<i64> = vec![];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -160,20 +141,16 @@ fn test_empty_events() {
 fn test_paddle_out_of_bounds() {
     // Create events with extreme paddle position (out of bounds)
     let events = vec![
-        1030792151040,           // leftY - center (valid)
-        1030792151040,           // rightY - center (valid)
-        10000000000000000,       // leftY - extreme position (invalid)
-        1030792151040,           // rightY - center
+        15728640,                // leftY - center (valid)
+        15728640,                // rightY - center (valid)
+        i32::MAX as i64,         // leftY - extreme position (invalid)
+        15728640,                // rightY - center
     ];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -195,20 +172,16 @@ fn test_paddle_out_of_bounds() {
 fn test_paddle_too_fast() {
     // Create events where paddle moves too fast between events
     let events = vec![
-        1030792151040,  // leftY - center
-        1030792151040,  // rightY - center
-        1030792151040,  // leftY - still at center
-        2000000000000,  // rightY - huge jump (too fast)
+        15728640,         // leftY - center
+        15728640,         // rightY - center
+        15728640,         // leftY - still at center
+        i32::MAX as i64,  // rightY - huge jump (too fast)
     ];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -238,13 +211,9 @@ fn test_final_score_validation() {
     ];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -263,22 +232,23 @@ fn test_final_score_validation() {
 
 #[test]
 fn test_extreme_overflow_i64_max() {
-    // Test with I64::MAX values to ensure overflow protection
+    // I64::MAX cannot be represented in the 32-bit guest wire encoding,
+    // so the host must refuse to build an environment for it
+    let input = ValidateLogInput::new(vec![i64::MAX; 4], 0, 1);
+    assert!(executor_env(&input).is_err(), "I64::MAX should be rejected by the host");
+
+    // The largest value that does reach the guest must still be rejected there
     let events = vec![
-        i64::MAX,  // leftY - extreme value
-        i64::MAX,  // rightY - extreme value
-        i64::MAX,  // leftY
-        i64::MAX,  // rightY
+        i32::MAX as i64,  // leftY - extreme value
+        i32::MAX as i64,  // rightY - extreme value
+        i32::MAX as i64,  // leftY
+        i32::MAX as i64,  // rightY
     ];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let result = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF);
@@ -302,7 +272,10 @@ fn test_extreme_overflow_i64_max() {
 fn test_extreme_overflow_velocity_time_product() {
     // Test overflow protection in reflection calculation (vy * dt)
     // Use values that would overflow when multiplied but are individually valid
-    let large_value = 1i64 << 40; // Large but not MAX
+    let input = ValidateLogInput::new(vec![15728640, 15728640, 1i64 << 40, 15728640], 0, 1);
+    assert!(executor_env(&input).is_err(), "Values beyond 32 bits should be rejected by the host");
+
+    let large_value = 1i64 << 30; // Large but still fits the wire encoding
 
     let events = vec![
         15728640,    // leftY - center (valid)
@@ -312,13 +285,9 @@ fn test_extreme_overflow_velocity_time_product() {
     ];
     let game_id = 0u32;
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let result = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF);
@@ -340,34 +309,35 @@ fn test_extreme_overflow_velocity_time_product() {
 #[test]
 fn test_i64_min_edge_case() {
     // Test I64::MIN edge case (cannot be negated without overflow)
+    // It cannot be sent to the guest at all...
+    let input = ValidateLogInput::new(vec![i64::MIN, 15728640, 15728640, i64::MIN], 0, 1);
+    assert!(executor_env(&input).is_err(), "I64::MIN should be rejected by the host");
+
+    // ...and the most negative value that can must be rejected by the guest
     let events = vec![
-        i64::MIN,  // leftY - most negative value
-        15728640,  // rightY - center
-        15728640,  // leftY - center
-        i64::MIN,  // rightY - most negative value
+        i32::MIN as i64,  // leftY - most negative value
+        15728640,         // rightY - center
+        15728640,         // leftY - center
+        i32::MIN as i64,  // rightY - most negative value
     ];
 
     let game_id = 0u32; // Zero game_id for test
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let result = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF);
 
-    // I64::MIN should be handled gracefully (converted to I64::MAX in abs)
+    // I32::MIN should be handled gracefully
     match result {
         Ok(prove_info) => {
             let receipt = prove_info.receipt;
             receipt.verify(GUEST_CODE_FOR_ZK_PROOF_ID).unwrap();
             let output: ValidateLogOutput = receipt.journal.decode().unwrap();
             // Should be rejected due to out of bounds or too fast movement
-            assert!(!output.fair, "I64::MIN should be rejected as invalid paddle position");
+            assert!(!output.fair, "I32::MIN should be rejected as invalid paddle position");
         }
         Err(_) => {
             // Panic is also acceptable
@@ -389,13 +359,9 @@ fn test_malformed_receipt_rejection() {
 
     let game_id = 1u32; // Test game_id
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap();
@@ -415,3 +381,95 @@ fn test_malformed_receipt_rejection() {
     // Additional test: Verify that journal can't be decoded from tampered receipt
     // (This is implicit - if verification fails, journal should not be trusted)
 }
+
+#[test]
+fn test_unsupported_log_version() {
+    // The log version selects the physics; unknown versions must not be replayed
+    let events = vec![15728640, 15728640, 15728640, 15728640];
+    let input = ValidateLogInput::new(events, 1, 99);
+
+    let env = executor_env(&input).unwrap();
+
+    let prover = default_prover();
+    let receipt = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap().receipt;
+    let output: ValidateLogOutput = receipt.journal.decode().unwrap();
+
+    assert!(!output.fair, "Unknown log version should be rejected");
+    assert_eq!(output.reason.as_deref(), Some("Unsupported log version"));
+    assert_eq!(output.log_version, 0);
+}
+
+#[test]
+fn test_longest_rally_does_not_overflow() {
+    // With log v2 (exact table trig) and game_id 103 the first serve is
+    // perfectly flat (103 * 37 mod 121 = 60, i.e. 0°), so paddles parked at
+    // the center return every ball straight back and the rally never ends.
+    // Without the ball speed cap this overflows i_mul after ~645 bounces;
+    // with it, a MAX_EVENTS-long rally must replay to the end without panicking.
+    let events = vec![15728640; 10000];
+    let input = ValidateLogInput::new(events, 103, 2);
+
+    let env = executor_env(&input).unwrap();
+
+    let prover = default_prover();
+    let receipt = prover
+        .prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF)
+        .expect("A maximal rally must not panic the guest")
+        .receipt;
+    let output: ValidateLogOutput = receipt.journal.decode().unwrap();
+
+    // Nobody ever misses, so the log is rejected on its score, not on arithmetic
+    assert!(!output.fair);
+    assert_eq!(
+        output.reason.as_deref(),
+        Some("Invalid final score - neither player reached POINTS_TO_WIN")
+    );
+}
+
+#[test]
+fn test_uncapped_v1_rally_stops_before_overflow() {
+    // Log v1 has no ball speed cap, so its returns keep speeding the ball up.
+    // This generated log (game_id 103) has each receiver meet the ball a few
+    // pixels off center, steering it back to the middle, for 638 returns: the
+    // ball reaches 32400 px/s, and the 639th return would take it to about
+    // 32450 px/s, past UNCAPPED_BALL_SPEED_LIMIT. That is still below the
+    // 32768 px/s i_mul bound (the limit is a safety margin for trig rounding),
+    // so the guest without the limit replays this log; with it the replay
+    // rejects the log at that return.
+    let log = read_log(LONG_RALLY_V1);
+    let events = log.parse_events().unwrap();
+    assert_eq!(events.len(), 2 * 639);
+
+    let replay = |events: &[i64], scoring: ScoringRules| -> ValidateLogOutput {
+        let input = ValidateLogInput {
+            rules: Ruleset { scoring, ..Ruleset::STANDARD },
+            ..ValidateLogInput::new(events.to_vec(), log.game_id, 1)
+        };
+        let env = executor_env(&input).unwrap();
+        let receipt = default_prover()
+            .prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF)
+            .expect("An uncapped rally must not panic the guest")
+            .receipt;
+        receipt.journal.decode().unwrap()
+    };
+
+    let output = replay(&events, ScoringRules::CLASSIC);
+    assert!(!output.fair);
+    assert_eq!(output.reason.as_deref(), Some(REASON_BALL_TOO_FAST));
+
+    // Every return before it replays under the uncapped physics (with v2's
+    // 4000 px/s cap the paddles soon miss and the same log is a 3-1 game)
+    let forfeit = ScoringRules { forfeit: true, ..ScoringRules::CLASSIC };
+    let output = replay(&events[..2 * 638], forfeit);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.end_reason, Some(EndReason::Abandoned));
+    assert_eq!((output.left_score, output.right_score), (0, 0));
+    assert_eq!(output.last_valid_pair, Some(637));
+
+    // In forfeit mode the rally up to the limit is the valid prefix, with nobody to blame
+    let output = replay(&events, forfeit);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.reason.as_deref(), Some(REASON_BALL_TOO_FAST));
+    assert_eq!(output.last_valid_pair, Some(637));
+    assert_eq!(output.violator, None);
+}
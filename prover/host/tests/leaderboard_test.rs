@@ -0,0 +1,144 @@
+// Tests for the Elo ladder in core and its proof by the rating guest
+use core::{expected_score, Leaderboard, Outcome, Pairing, Ruleset, ValidateLogOutput, INITIAL_RATING, I};
+use host::{generate_leaderboard_proof, generate_pong_proof, verify_leaderboard_proof, ReceiptKind};
+
+mod common;
+use common::{read_log, LADDER1_ROUND_0, LADDER1_ROUND_1};
+
+/// Journal of a fair standard game played for `pairing` on `ladder`, with the given outcome
+fn game(ladder: &Leaderboard, pairing: Pairing, outcome: Option<Outcome>) -> ValidateLogOutput {
+    let mut out = ValidateLogOutput::invalid("");
+    out.fair = true;
+    out.reason = None;
+    out.game_id = ladder.game_id(&pairing);
+    out.outcome = outcome;
+    out
+}
+
+fn pairing(round: u32, left: u32, right: u32) -> Pairing {
+    Pairing { round, left, right }
+}
+
+fn points(rating: I) -> f64 {
+    rating as f64 / 65536.0
+}
+
+#[test]
+fn test_expected_score() {
+    let half = expected_score(INITIAL_RATING, INITIAL_RATING);
+    assert!((half - (1 << 15)).abs() <= 1, "Equal ratings expect {}", half);
+
+    // 400 points ahead expects 10 / 11
+    let favourite = expected_score(1900 << 16, 1500 << 16);
+    let underdog = expected_score(1500 << 16, 1900 << 16);
+    assert!((points(favourite) - 10.0 / 11.0).abs() < 0.001);
+    assert!((points(favourite + underdog) - 1.0).abs() < 0.001);
+
+    // Differences beyond 800 points are capped
+    assert_eq!(expected_score(3000 << 16, 1000 << 16), expected_score(2300 << 16, 1500 << 16));
+}
+
+#[test]
+fn test_elo_update() {
+    let mut ladder = Leaderboard::new(1, Ruleset::STANDARD);
+    let first = pairing(1, 10, 20);
+    ladder.apply(&first, &game(&ladder, first, Some(Outcome::LeftWin))).unwrap();
+
+    // Equal ratings and K = 32: the winner gains 16, the loser drops 16
+    assert!((points(ladder.rating(10)) - 1516.0).abs() < 0.01);
+    assert!((points(ladder.rating(20)) - 1484.0).abs() < 0.01);
+    assert_eq!(ladder.rating(10) + ladder.rating(20), 2 * INITIAL_RATING);
+    assert_eq!(ladder.rating(30), INITIAL_RATING);
+
+    // Beating a weaker player earns less; a draw moves the favourite down
+    let before = ladder.rating(10);
+    let second = pairing(2, 20, 10);
+    ladder.apply(&second, &game(&ladder, second, Some(Outcome::RightWin))).unwrap();
+    let gain = ladder.rating(10) - before;
+    assert!(gain > 0 && points(gain) < 16.0);
+
+    let before = ladder.rating(10);
+    let third = pairing(3, 10, 20);
+    ladder.apply(&third, &game(&ladder, third, Some(Outcome::Draw))).unwrap();
+    assert!(ladder.rating(10) < before);
+
+    let mut rated: Vec<_> = [first, second, third].iter().map(|p| ladder.game_id(p)).collect();
+    rated.sort();
+    assert_eq!(ladder.rated_games, rated);
+}
+
+#[test]
+fn test_game_ids_bind_pairings() {
+    let ladder = Leaderboard::new(1, Ruleset::STANDARD);
+    assert_eq!(ladder.game_id(&pairing(0, 10, 20)), 3092725534);
+    assert_eq!(ladder.game_id(&pairing(1, 30, 10)), 1337322817);
+
+    // Sides, round and ladder all change the ID
+    let id = ladder.game_id(&pairing(0, 10, 20));
+    assert_ne!(ladder.game_id(&pairing(0, 20, 10)), id);
+    assert_ne!(ladder.game_id(&pairing(1, 10, 20)), id);
+    assert_ne!(ladder.game_id(&pairing(0, 10, 30)), id);
+    assert_ne!(Leaderboard::new(2, Ruleset::STANDARD).game_id(&pairing(0, 10, 20)), id);
+}
+
+#[test]
+fn test_elo_rejects_bad_games() {
+    let mut ladder = Leaderboard::new(1, Ruleset::STANDARD);
+    let first = pairing(1, 10, 20);
+    ladder.apply(&first, &game(&ladder, first, Some(Outcome::LeftWin))).unwrap();
+    let root = ladder.root();
+
+    // Rated already
+    assert!(ladder.apply(&first, &game(&ladder, first, Some(Outcome::LeftWin))).is_err());
+    // A game played for another pairing: other players, sides, round or ladder
+    let next = pairing(2, 10, 20);
+    for other in [pairing(2, 10, 30), pairing(2, 20, 10), pairing(3, 10, 20)] {
+        assert!(ladder.apply(&next, &game(&ladder, other, Some(Outcome::LeftWin))).is_err());
+    }
+    let elsewhere = Leaderboard::new(2, Ruleset::STANDARD);
+    assert!(ladder.apply(&next, &game(&elsewhere, next, Some(Outcome::LeftWin))).is_err());
+    // A player against themselves
+    let alone = pairing(2, 10, 10);
+    assert!(ladder.apply(&alone, &game(&ladder, alone, Some(Outcome::LeftWin))).is_err());
+    // Unfair, abandoned or under other rules
+    let mut unfair = game(&ladder, next, Some(Outcome::LeftWin));
+    unfair.fair = false;
+    assert!(ladder.apply(&next, &unfair).is_err());
+    assert!(ladder.apply(&next, &game(&ladder, next, None)).is_err());
+    let mut spin = game(&ladder, next, Some(Outcome::LeftWin));
+    spin.rules = Ruleset::preset("spin").unwrap();
+    assert!(ladder.apply(&next, &spin).is_err());
+
+    // Rejected games leave the ladder untouched
+    assert_eq!(ladder.root(), root);
+}
+
+#[test]
+fn test_guest_proves_rating_updates() {
+    let first = generate_pong_proof(&read_log(LADDER1_ROUND_0), ReceiptKind::Succinct).expect("Failed to prove game");
+    let second = generate_pong_proof(&read_log(LADDER1_ROUND_1), ReceiptKind::Succinct).expect("Failed to prove game");
+
+    let ladder = Leaderboard::new(1, Ruleset::STANDARD);
+    let batch = [(pairing(0, 10, 20), first.clone()), (pairing(1, 30, 10), second.clone())];
+    let proof = generate_leaderboard_proof(&ladder, &batch, ReceiptKind::Succinct).expect("Failed to prove ratings");
+    verify_leaderboard_proof(&proof).expect("Leaderboard proof does not verify");
+
+    assert_eq!(proof.output.prev_root, ladder.root());
+    assert_eq!(proof.output.root, proof.leaderboard.root());
+    assert_eq!(proof.output.games, vec![first.game_id, second.game_id]);
+    // 10 beat 20, then lost to 30
+    assert!(proof.leaderboard.rating(30) > INITIAL_RATING);
+    assert!(proof.leaderboard.rating(20) < INITIAL_RATING);
+
+    // A game cannot be rated twice, in a later batch or within one
+    let again = [(pairing(0, 10, 20), first.clone())];
+    assert!(generate_leaderboard_proof(&proof.leaderboard, &again, ReceiptKind::Succinct).is_err());
+    let twice = [(pairing(0, 10, 20), first.clone()), (pairing(0, 10, 20), first.clone())];
+    assert!(generate_leaderboard_proof(&ladder, &twice, ReceiptKind::Succinct).is_err());
+
+    // Nor for players other than the ones it was played for
+    let swapped = [(pairing(0, 20, 10), first.clone())];
+    assert!(generate_leaderboard_proof(&ladder, &swapped, ReceiptKind::Succinct).is_err());
+    let impostor = [(pairing(0, 40, 20), first)];
+    assert!(generate_leaderboard_proof(&ladder, &impostor, ReceiptKind::Succinct).is_err());
+}
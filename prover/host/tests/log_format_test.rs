@@ -0,0 +1,86 @@
+// Tests for converting game logs between the JSON and binary encodings
+use core::{decode_binary_log, encode_binary_log, is_binary_log, CompactLog, LogError, BINARY_LOG_MAGIC};
+use host::load_log;
+
+mod common;
+use common::SAMPLE_LOGS;
+
+fn load_json_log(path: &str) -> (CompactLog, usize) {
+    let raw = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    let log = serde_json::from_str(&raw).unwrap_or_else(|e| panic!("Failed to parse {}: {}", path, e));
+    (log, raw.len())
+}
+
+#[test]
+fn test_binary_round_trip_is_lossless() {
+    for path in SAMPLE_LOGS {
+        let (log, json_size) = load_json_log(path);
+
+        let bytes = encode_binary_log(&log).expect("Failed to encode log");
+        assert!(is_binary_log(&bytes));
+        assert!(
+            bytes.len() * 3 < json_size,
+            "{}: binary log ({} bytes) should be much smaller than JSON ({} bytes)",
+            path,
+            bytes.len(),
+            json_size
+        );
+
+        let decoded = decode_binary_log(&bytes).expect("Failed to decode log");
+        assert_eq!(decoded, log, "{}: round trip changed the log", path);
+    }
+}
+
+#[test]
+fn test_binary_preserves_extreme_values() {
+    let log = CompactLog {
+        v: 1,
+        events: [i64::MIN, i64::MAX, 0, -1, i64::MAX, i64::MIN, 15728640]
+            .iter()
+            .map(|e| e.to_string())
+            .collect(),
+        game_id: u32::MAX,
+    };
+
+    let decoded = decode_binary_log(&encode_binary_log(&log).unwrap()).unwrap();
+    assert_eq!(decoded, log);
+}
+
+#[test]
+fn test_load_log_detects_encoding() {
+    let (log, _) = load_json_log(SAMPLE_LOGS[0]);
+
+    let json = serde_json::to_vec(&log).unwrap();
+    let binary = encode_binary_log(&log).unwrap();
+
+    assert_eq!(load_log(&json).unwrap(), log);
+    assert_eq!(load_log(&binary).unwrap(), log);
+    assert_eq!(log.parse_events().unwrap().len(), 38);
+}
+
+#[test]
+fn test_binary_rejects_malformed_input() {
+    let (log, _) = load_json_log(SAMPLE_LOGS[0]);
+    let bytes = encode_binary_log(&log).unwrap();
+
+    assert_eq!(decode_binary_log(b"{\"v\":1}"), Err(LogError::BadMagic));
+    assert_eq!(decode_binary_log(&BINARY_LOG_MAGIC), Err(LogError::Truncated));
+    assert_eq!(decode_binary_log(&bytes[..bytes.len() - 1]), Err(LogError::Truncated));
+
+    let mut wrong_format = bytes.clone();
+    wrong_format[4] = 9;
+    assert_eq!(decode_binary_log(&wrong_format), Err(LogError::UnsupportedFormat(9)));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(decode_binary_log(&trailing), Err(LogError::TrailingBytes(1)));
+
+    // Header claiming 2^62 events must be rejected without allocating
+    let mut huge = bytes[..10].to_vec();
+    huge.extend_from_slice(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x40]);
+    assert!(matches!(decode_binary_log(&huge), Err(LogError::EventCountTooLarge(_))));
+
+    let bad_event = CompactLog { v: 1, events: vec!["12x".to_string()], game_id: 0 };
+    assert_eq!(encode_binary_log(&bad_event), Err(LogError::InvalidEvent { index: 0 }));
+}
@@ -1,15 +1,15 @@
 // Tests for validating real game logs from JSON files
 // These tests depend on specific JSON log files in the project root
-use core::{CompactLog, ValidateLogInput, ValidateLogOutput};
+use core::{ValidateLogInput, ValidateLogOutput};
+use host::executor_env;
 use methods::{GUEST_CODE_FOR_ZK_PROOF_ELF, GUEST_CODE_FOR_ZK_PROOF_ID};
-use risc0_zkvm::{default_prover, ExecutorEnv};
+use risc0_zkvm::default_prover;
 
-fn load_and_parse_log(path: &str) -> (Vec<i64>, u32) {
-    let raw = std::fs::read_to_string(path)
-        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+mod common;
+use common::{read_log, LOG_19, LOG_64, LOG_71};
 
-    let log: CompactLog = serde_json::from_str(&raw)
-        .unwrap_or_else(|e| panic!("Failed to parse {}: {}", path, e));
+fn load_and_parse_log(path: &str) -> (Vec<i64>, u32) {
+    let log = read_log(path);
 
     assert_eq!(log.v, 1, "Unsupported log version: {}", log.v);
 
@@ -27,15 +27,11 @@ fn load_and_parse_log(path: &str) -> (Vec<i64>, u32) {
 
 #[test]
 fn test_valid_game_19_events() {
-    let (events, game_id) = load_and_parse_log("../../pong-log_events19_1761147203682.json");
+    let (events, game_id) = load_and_parse_log(LOG_19);
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover
@@ -57,15 +53,11 @@ fn test_valid_game_19_events() {
 
 #[test]
 fn test_valid_game_64_events() {
-    let (events, game_id) = load_and_parse_log("../../pong-log_events64_1761147732142.json");
+    let (events, game_id) = load_and_parse_log(LOG_64);
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover
@@ -87,15 +79,11 @@ fn test_valid_game_64_events() {
 
 #[test]
 fn test_valid_game_71_events() {
-    let (events, game_id) = load_and_parse_log("../../pong-log_events71_1761147635847.json");
+    let (events, game_id) = load_and_parse_log(LOG_71);
 
-    let input = ValidateLogInput { events, game_id };
+    let input = ValidateLogInput::new(events, game_id, 1);
 
-    let env = ExecutorEnv::builder()
-        .write(&input)
-        .unwrap()
-        .build()
-        .unwrap();
+    let env = executor_env(&input).unwrap();
 
     let prover = default_prover();
     let prove_info = prover
@@ -0,0 +1,107 @@
+// Tests for the optional rulesets (human-play limits and spin)
+use core::{Ruleset, ValidateLogInput, ValidateLogOutput};
+use host::executor_env;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ELF;
+use risc0_zkvm::default_prover;
+
+/// Paddle center in Q16.16 (240 px)
+const CENTER: i64 = 15728640;
+/// One pixel in Q16.16
+const PX: i64 = 65536;
+
+fn run(events: Vec<i64>, rules: Ruleset) -> ValidateLogOutput {
+    run_game(events, 1, 1, rules)
+}
+
+fn run_game(events: Vec<i64>, game_id: u32, log_version: u32, rules: Ruleset) -> ValidateLogOutput {
+    let input = ValidateLogInput {
+        rules,
+        ..ValidateLogInput::new(events, game_id, log_version)
+    };
+    let env = executor_env(&input).unwrap();
+
+    let prover = default_prover();
+    let receipt = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap().receipt;
+    receipt.journal.decode().unwrap()
+}
+
+#[test]
+fn test_presets() {
+    assert_eq!(Ruleset::default(), Ruleset::STANDARD);
+    assert_eq!(Ruleset::preset("standard"), Some(Ruleset::STANDARD));
+    assert_eq!(Ruleset::preset("human-play"), Some(Ruleset::HUMAN_PLAY));
+    assert_eq!(Ruleset::preset("spin"), Some(Ruleset::SPIN));
+    assert_eq!(Ruleset::preset("bot"), None);
+    assert_eq!(Ruleset::from_words(Ruleset::HUMAN_PLAY.to_words()), Some(Ruleset::HUMAN_PLAY));
+}
+
+#[test]
+fn test_receiver_moving_before_reaction_delay() {
+    // The first serve goes right, so the right paddle is receiving. A 1 px
+    // move is well within the base speed limit but not before a 10 s delay.
+    let rules = Ruleset { reaction_ms: 10_000, ..Ruleset::STANDARD };
+    let output = run(vec![CENTER, CENTER + PX], rules);
+
+    assert!(!output.fair);
+    assert_eq!(output.reason.as_deref(), Some("Paddle moved before reaction delay"));
+}
+
+#[test]
+fn test_reaction_delay_only_applies_to_receiver() {
+    // Same move by the left paddle, which is not receiving: the log fails
+    // later (nobody wins a one-volley game), not on the reaction rule
+    let rules = Ruleset { reaction_ms: 10_000, ..Ruleset::STANDARD };
+    let output = run(vec![CENTER + PX, CENTER], rules);
+
+    assert!(!output.fair);
+    assert_ne!(output.reason.as_deref(), Some("Paddle moved before reaction delay"));
+}
+
+#[test]
+fn test_paddle_accelerating_too_fast() {
+    // Moving 100 px from rest within one volley (< 3 s) needs well over 1 px/s²
+    let rules = Ruleset { max_paddle_accel: 1, ..Ruleset::STANDARD };
+    let output = run(vec![CENTER + 100 * PX, CENTER], rules);
+
+    assert!(!output.fair);
+    assert_eq!(output.reason.as_deref(), Some("Paddle accelerated too fast"));
+}
+
+#[test]
+fn test_standard_rules_ignore_acceleration_and_reaction() {
+    let output = run(vec![CENTER + 100 * PX, CENTER + PX], Ruleset::STANDARD);
+
+    assert!(!output.fair);
+    assert_ne!(output.reason.as_deref(), Some("Paddle accelerated too fast"));
+    assert_ne!(output.reason.as_deref(), Some("Paddle moved before reaction delay"));
+}
+
+#[test]
+fn test_spin_bends_bounce() {
+    // game_id 103 serves flat under log v2 (see COLLISION_GAME_ID). Both players
+    // return the ball flat at 240 px until the right paddle, having stepped
+    // 100 px down, comes back up as it returns the third ball: its velocity
+    // puts ~8° of spin on the ball, which the left paddle meets at ~135 px.
+    // The right player then stays away from every ball and loses 0-3.
+    let events = vec![
+        15728640, 15728640, // R returns flat
+        15728640, 22282240, // L returns flat, R steps down 100 px
+        15728640, 15728640, // R returns moving up: spin
+        8819037, 15728640,  // L returns the spinning ball
+        8819037, 28835840,  // R misses: 1-0
+        18052415, 28835840, // L returns the serve
+        18052415, 28835840, // R misses: 2-0
+        28835840, 28835840, // L returns the serve
+        28835840, 3833899,  // R misses: 3-0
+    ];
+
+    let output = run_game(events.clone(), 103, 2, Ruleset::SPIN);
+    assert!(output.fair, "Log should be valid with spin: {:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (3, 0));
+    assert_eq!(output.rules, Ruleset::SPIN);
+
+    // Without spin the third ball comes back flat and the log stops
+    // describing a game that can be played
+    let output = run_game(events, 103, 2, Ruleset::STANDARD);
+    assert!(!output.fair);
+}
@@ -0,0 +1,80 @@
+// Tests for salted log commitments: the salted hash, salted proofs and
+// checking a log against a proof with and without its salt
+use core::{
+    compute_log_hash, compute_salted_log_hash, log_commitment, salt_from_bytes, Ruleset, ValidateLogInput,
+    LOG_SALT_BYTES,
+};
+use host::{
+    generate_pong_proof, generate_salted_pong_proof, join_segments, verify_log_commitment, verify_pong_proof,
+    ReceiptKind,
+};
+
+mod common;
+use common::{read_log, LOG_19, SALT};
+
+#[test]
+fn test_salted_commitment() {
+    // Two centered paddles: the kind of log an observer could guess
+    let events = [15728640i32.to_le_bytes(), 15728640i32.to_le_bytes()].concat();
+
+    assert_eq!(log_commitment(None, &events), compute_log_hash(&events));
+    let salted = log_commitment(Some(&SALT), &events);
+    assert_eq!(salted, compute_salted_log_hash(&SALT, &events));
+    assert_ne!(salted, compute_log_hash(&events));
+
+    let mut other = SALT;
+    other[31] ^= 1;
+    assert_ne!(compute_salted_log_hash(&other, &events), salted);
+
+    // The all-zero salt slot means no salt
+    assert_eq!(salt_from_bytes([0; LOG_SALT_BYTES]), None);
+    assert_eq!(salt_from_bytes(SALT), Some(SALT));
+    let input = ValidateLogInput::new(vec![], 1, 1);
+    assert_eq!(input.salt_bytes(), [0; LOG_SALT_BYTES]);
+}
+
+#[test]
+fn test_salted_proof() {
+    let log = read_log(LOG_19);
+    let plain = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to prove log");
+    let salted =
+        generate_salted_pong_proof(&log, ReceiptKind::Succinct, Ruleset::STANDARD, SALT).expect("Failed to prove log");
+    verify_pong_proof(&salted).expect("Salted proof does not verify");
+
+    // Same result, different commitment
+    assert!(salted.fair);
+    assert_eq!((salted.left_score, salted.right_score), (plain.left_score, plain.right_score));
+    assert_eq!(salted.events_len, plain.events_len);
+    let event_bytes = core::encode_events(&log.parse_events().unwrap()).unwrap();
+    assert_eq!(salted.log_hash, compute_salted_log_hash(&SALT, &event_bytes));
+    assert_ne!(salted.log_hash, plain.log_hash);
+
+    // The salt is private input, not part of the journal
+    assert!(!salted.receipt.journal.bytes.windows(LOG_SALT_BYTES).any(|w| w == SALT));
+
+    verify_log_commitment(&plain, &log, None).expect("Unsalted log does not match");
+    verify_log_commitment(&salted, &log, Some(&SALT)).expect("Salted log does not match");
+    assert!(verify_log_commitment(&salted, &log, None).is_err());
+    let mut wrong = SALT;
+    wrong[0] ^= 1;
+    assert!(verify_log_commitment(&salted, &log, Some(&wrong)).is_err());
+    assert!(verify_log_commitment(&plain, &log, Some(&SALT)).is_err());
+
+    // A different log with the right salt does not match
+    let mut edited = log.clone();
+    edited.events[0] = "15728641".into();
+    assert!(verify_log_commitment(&salted, &edited, Some(&SALT)).is_err());
+}
+
+#[test]
+fn test_salt_restrictions() {
+    let log = read_log(LOG_19);
+    let zero = generate_salted_pong_proof(&log, ReceiptKind::Succinct, Ruleset::STANDARD, [0; LOG_SALT_BYTES]);
+    assert!(zero.is_err(), "The all-zero salt must be rejected");
+
+    let input = ValidateLogInput {
+        salt: Some(SALT),
+        ..ValidateLogInput::new(log.parse_events().unwrap(), log.game_id, log.v)
+    };
+    assert!(join_segments(&input, Vec::new(), ReceiptKind::Succinct).is_err());
+}
@@ -0,0 +1,374 @@
+// Tests for the scoring rules: match formats in core and their replay in the guest
+use core::{
+    EndReason, GameScore, MatchScore, Outcome, Ruleset, ScoringRules, Serve, ValidateLogInput, ValidateLogOutput,
+    Violator,
+};
+use host::executor_env;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ELF;
+use risc0_zkvm::default_prover;
+
+mod common;
+use common::{read_log, LOG_19};
+
+fn scoring(points_to_win: u32, win_by: u32, best_of: u32, serve: Serve) -> ScoringRules {
+    ScoringRules { points_to_win, win_by, best_of, serve, ..ScoringRules::CLASSIC }
+}
+
+/// Classic scoring, proving unfinished logs as abandoned
+const FORFEIT: ScoringRules = ScoringRules { forfeit: true, ..ScoringRules::CLASSIC };
+
+/// Classic scoring with a game clock of `quarters` quarter seconds
+fn timed(quarters: u32, allow_draw: bool) -> ScoringRules {
+    ScoringRules { time_limit: quarters << 14, allow_draw, ..ScoringRules::CLASSIC }
+}
+
+/// Play points in order ('L' or 'R'), returning whether the match ended on the last one
+fn play(score: &mut MatchScore, points: &str) -> bool {
+    let mut over = false;
+    for p in points.chars() {
+        assert!(!over, "Point played after the match ended");
+        over = score.point(p == 'L');
+    }
+    over
+}
+
+fn run_sample(scoring: ScoringRules) -> ValidateLogOutput {
+    run_sample_with(|_| {}, scoring)
+}
+
+/// Run the sample log after editing its events, e.g. cutting it short as if recording had stopped
+fn run_sample_with(edit: impl FnOnce(&mut Vec<i64>), scoring: ScoringRules) -> ValidateLogOutput {
+    let log = read_log(LOG_19);
+    let mut events = log.parse_events().expect("Failed to parse events");
+    edit(&mut events);
+    let input = ValidateLogInput {
+        rules: Ruleset { scoring, ..Ruleset::STANDARD },
+        ..ValidateLogInput::new(events, log.game_id, log.v)
+    };
+    let env = executor_env(&input).unwrap();
+
+    let prover = default_prover();
+    let receipt = prover.prove(env, GUEST_CODE_FOR_ZK_PROOF_ELF).unwrap().receipt;
+    receipt.journal.decode().unwrap()
+}
+
+#[test]
+fn test_first_to_n() {
+    let mut score = MatchScore::new(ScoringRules::CLASSIC);
+    assert!(!play(&mut score, "LRLR"));
+    assert!(play(&mut score, "L"));
+    assert_eq!(score.result(), (3, 2));
+    assert_eq!(score.games, vec![GameScore { left: 3, right: 2 }]);
+}
+
+#[test]
+fn test_win_by_two() {
+    let mut score = MatchScore::new(scoring(3, 2, 1, Serve::ToScorer));
+    // 3-2 is not enough; deuce continues until a two point lead
+    assert!(!play(&mut score, "LRLRL"));
+    assert!(!play(&mut score, "RL"));
+    assert!(play(&mut score, "L"));
+    assert_eq!(score.result(), (5, 3));
+}
+
+#[test]
+fn test_best_of_n() {
+    let mut score = MatchScore::new(scoring(2, 1, 3, Serve::ToScorer));
+    assert!(!play(&mut score, "LL"));
+    assert!(!play(&mut score, "RLR"));
+    assert_eq!((score.left, score.right), (0, 0), "Points reset between games");
+    assert!(play(&mut score, "RLL"));
+    // The match result counts games; the breakdown keeps every game's points
+    assert_eq!(score.result(), (2, 1));
+    assert_eq!(
+        score.games,
+        vec![
+            GameScore { left: 2, right: 0 },
+            GameScore { left: 1, right: 2 },
+            GameScore { left: 2, right: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_serve_rules() {
+    let mut to_scorer = MatchScore::new(ScoringRules::CLASSIC);
+    to_scorer.point(false);
+    assert_eq!(to_scorer.serve_dir(1, 1), 1);
+    assert_eq!(to_scorer.serve_dir(-1, 1), -1);
+
+    // Alternating: the opening serve went right, so after one point the next goes left
+    let mut alternate = MatchScore::new(scoring(3, 1, 1, Serve::Alternate));
+    alternate.point(true);
+    assert_eq!(alternate.serve_dir(-1, 1), -1);
+    assert_eq!(alternate.serve_dir(1, 1), -1);
+    alternate.point(true);
+    assert_eq!(alternate.serve_dir(-1, 1), 1);
+}
+
+#[test]
+fn test_clock_ends_match() {
+    // Leader at the deadline wins; the game in progress is recorded as it stood
+    let mut score = MatchScore::new(timed(360, false));
+    assert!(!play(&mut score, "LRL"));
+    assert!(score.expire_clock());
+    assert_eq!(score.result(), (2, 1));
+    assert_eq!(score.games, vec![GameScore { left: 2, right: 1 }]);
+    assert_eq!(score.end_reason(), Some(EndReason::Clock));
+    assert_eq!(score.outcome(), Some(Outcome::LeftWin));
+
+    // A tie is a draw when the rules allow it
+    let mut score = MatchScore::new(timed(360, true));
+    assert!(!play(&mut score, "LR"));
+    assert!(score.expire_clock());
+    assert_eq!(score.outcome(), Some(Outcome::Draw));
+
+    // Otherwise the next point decides it
+    let mut score = MatchScore::new(timed(360, false));
+    assert!(!play(&mut score, "LR"));
+    assert!(!score.expire_clock());
+    assert!(score.clock_expired());
+    assert!(play(&mut score, "R"));
+    assert_eq!(score.result(), (1, 2));
+    assert_eq!(score.end_reason(), Some(EndReason::Clock));
+    assert_eq!(score.outcome(), Some(Outcome::RightWin));
+}
+
+#[test]
+fn test_clock_in_best_of_n() {
+    // Games 1-1, with the third game in progress counting for its leader
+    let rules = ScoringRules { best_of: 3, ..timed(360, true) };
+    let mut score = MatchScore::new(rules);
+    assert!(!play(&mut score, "LLLRRRR"));
+    assert!(score.expire_clock());
+    assert_eq!(score.result(), (1, 2));
+    assert_eq!(score.games.last(), Some(&GameScore { left: 0, right: 1 }));
+    assert_eq!(score.outcome(), Some(Outcome::RightWin));
+
+    // A tied game in progress counts for neither player
+    let mut score = MatchScore::new(rules);
+    assert!(!play(&mut score, "LLLRRRLR"));
+    assert!(score.expire_clock());
+    assert_eq!(score.result(), (1, 1));
+    assert_eq!(score.games.len(), 3);
+    assert_eq!(score.outcome(), Some(Outcome::Draw));
+
+    // Without draws, a tie that counts the game in progress goes to a golden
+    // point, which decides the match even if the game's leader scores it
+    let rules = ScoringRules { best_of: 3, ..timed(360, false) };
+    for (golden, outcome, result) in [("R", Outcome::RightWin, (1, 2)), ("L", Outcome::LeftWin, (2, 1))] {
+        let mut score = MatchScore::new(rules);
+        assert!(!play(&mut score, "LLLR"));
+        assert!(!score.expire_clock(), "Games 1-1 counting the game in progress");
+        assert_eq!(score.games.last(), Some(&GameScore { left: 0, right: 1 }));
+        assert!(play(&mut score, golden));
+        assert_eq!(score.end_reason(), Some(EndReason::Clock));
+        assert_eq!(score.outcome(), Some(outcome));
+        assert_eq!(score.result(), result);
+        assert_eq!(score.games.len(), 3);
+    }
+
+    // A tied game in progress stands as a tie, and the golden point decides
+    let mut score = MatchScore::new(rules);
+    assert!(!play(&mut score, "LLLRRRLR"));
+    assert!(!score.expire_clock());
+    assert!(play(&mut score, "L"));
+    assert_eq!(score.games[2..], [GameScore { left: 1, right: 1 }, GameScore { left: 1, right: 0 }]);
+    assert_eq!(score.outcome(), Some(Outcome::LeftWin));
+}
+
+#[test]
+fn test_abandoned_match() {
+    let mut score = MatchScore::new(ScoringRules { best_of: 3, ..FORFEIT });
+    assert!(!play(&mut score, "LLLRL"));
+    score.abandon();
+    assert!(score.is_over());
+    assert_eq!(score.end_reason(), Some(EndReason::Abandoned));
+    assert_eq!(score.outcome(), None, "An abandoned match has no winner");
+    assert_eq!(score.result(), (1, 0));
+    assert_eq!(score.game_in_progress(), Some(GameScore { left: 1, right: 1 }));
+
+    // A single game's result is the points as they stood
+    let mut score = MatchScore::new(FORFEIT);
+    assert!(!play(&mut score, "RRL"));
+    score.abandon();
+    assert_eq!(score.result(), (1, 2));
+}
+
+#[test]
+fn test_scoring_rules_validation_and_words() {
+    assert!(ScoringRules::CLASSIC.validate().is_ok());
+    assert!(scoring(0, 1, 1, Serve::ToScorer).validate().is_err());
+    assert!(scoring(3, 0, 1, Serve::ToScorer).validate().is_err());
+    assert!(scoring(1, 2, 1, Serve::ToScorer).validate().is_err());
+    assert!(scoring(3, 1, 2, Serve::ToScorer).validate().is_err());
+
+    assert!(ScoringRules { allow_draw: true, ..ScoringRules::CLASSIC }.validate().is_err());
+    assert!(timed(360, true).validate().is_ok());
+
+    let rules = scoring(11, 2, 5, Serve::Alternate);
+    assert_eq!(ScoringRules::from_words(rules.to_words()), Some(rules));
+    assert_eq!(ScoringRules::from_words([3, 1, 1, 2, 0, 0, 0]), None);
+    assert_eq!(ScoringRules::from_words([3, 1, 1, 0, 0, 2, 0]), None);
+    assert_eq!(ScoringRules::from_words([3, 1, 1, 0, 0, 0, 2]), None);
+    assert_eq!(rules.to_string(), "best of 5 games, first to 11, win by 2, alternating serve");
+
+    let ruleset = Ruleset { scoring: rules, ..Ruleset::HUMAN_PLAY };
+    assert_eq!(Ruleset::from_words(ruleset.to_words()), Some(ruleset));
+    assert_eq!(Ruleset::STANDARD.to_string(), "standard");
+    assert!(ruleset.to_string().starts_with("human-play ("));
+    assert!(ruleset.to_string().ends_with("; best of 5 games, first to 11, win by 2, alternating serve"));
+
+    let rules = timed(362, true);
+    assert_eq!(ScoringRules::from_words(rules.to_words()), Some(rules));
+    assert_eq!(rules.to_string(), "first to 3, 90.500 s clock, draws allowed");
+    assert_eq!(timed(360, false).to_string(), "first to 3, 90 s clock");
+    assert_eq!(FORFEIT.to_string(), "first to 3, forfeits accepted");
+}
+
+#[test]
+fn test_guest_commits_game_breakdown() {
+    let output = run_sample(ScoringRules::CLASSIC);
+    assert!(output.fair);
+    assert_eq!((output.left_score, output.right_score), (3, 2));
+    assert_eq!(output.games, vec![GameScore { left: 3, right: 2 }]);
+    assert_eq!(output.rules.scoring, ScoringRules::CLASSIC);
+
+    // Same points, one per game
+    let best_of_3 = scoring(1, 1, 3, Serve::ToScorer);
+    let output = run_sample(best_of_3);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (2, 1));
+    assert_eq!(output.games.len(), 3);
+    assert_eq!(output.rules.scoring, best_of_3);
+}
+
+#[test]
+fn test_guest_win_by_two() {
+    // 3-2 does not end a win-by-two game, so the replay carries on into the
+    // point recorded after the classic finish
+    let output = run_sample(scoring(3, 2, 1, Serve::ToScorer));
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (4, 2));
+}
+
+#[test]
+fn test_guest_rejects_invalid_scoring_rules() {
+    let output = run_sample(scoring(3, 1, 2, Serve::ToScorer));
+    assert!(!output.fair);
+    assert_eq!(output.reason.as_deref(), Some("Invalid scoring rules: best_of must be odd"));
+}
+
+#[test]
+fn test_guest_untimed_match_ends_on_score() {
+    let output = run_sample(ScoringRules::CLASSIC);
+    assert_eq!(output.end_reason, Some(EndReason::Score));
+    assert_eq!(output.outcome, Some(Outcome::LeftWin));
+}
+
+#[test]
+fn test_guest_timed_match() {
+    // 1.5 s in, the left player leads 1-0
+    let output = run_sample(timed(6, false));
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (1, 0));
+    assert_eq!(output.end_reason, Some(EndReason::Clock));
+    assert_eq!(output.outcome, Some(Outcome::LeftWin));
+
+    // 2 s in it is 1-1: a draw if allowed...
+    let output = run_sample(timed(8, true));
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (1, 1));
+    assert_eq!(output.games, vec![GameScore { left: 1, right: 1 }]);
+    assert_eq!(output.outcome, Some(Outcome::Draw));
+
+    // ...otherwise the golden point, won by the left player
+    let output = run_sample(timed(8, false));
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (2, 1));
+    assert_eq!(output.end_reason, Some(EndReason::Clock));
+    assert_eq!(output.outcome, Some(Outcome::LeftWin));
+
+    // A clock longer than the match changes nothing
+    let output = run_sample(timed(400, true));
+    assert_eq!((output.left_score, output.right_score), (3, 2));
+    assert_eq!(output.end_reason, Some(EndReason::Score));
+}
+
+#[test]
+fn test_guest_timed_log_may_stop_at_deadline() {
+    // Recording stopped after 2 pairs, with the ball in play past the 2 s deadline
+    let output = run_sample_with(|e| e.truncate(4), timed(8, true));
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!((output.left_score, output.right_score), (1, 1));
+    assert_eq!(output.outcome, Some(Outcome::Draw));
+
+    // The same log stops well before a 10 s deadline, so it is incomplete
+    let output = run_sample_with(|e| e.truncate(4), timed(40, true));
+    assert!(!output.fair);
+
+    // A tie without draws needs the golden point to have been recorded
+    let output = run_sample_with(|e| e.truncate(4), timed(8, false));
+    assert!(!output.fair);
+    assert_eq!(
+        output.reason.as_deref(),
+        Some("Timed match tied at the deadline - golden point not played")
+    );
+}
+
+#[test]
+fn test_guest_proves_abandoned_prefix() {
+    // Recording stopped after 4 pairs at 2-2
+    let output = run_sample_with(|e| e.truncate(8), FORFEIT);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.reason, None);
+    assert_eq!((output.left_score, output.right_score), (2, 2));
+    assert_eq!(output.games, vec![GameScore { left: 2, right: 2 }]);
+    assert_eq!(output.end_reason, Some(EndReason::Abandoned));
+    assert_eq!(output.outcome, None);
+    assert_eq!(output.last_valid_pair, Some(3));
+    assert_eq!(output.events_len, 8);
+    // Nobody broke a rule: the log just stopped
+    assert_eq!(output.violator, None);
+
+    // A torn final pair is left out
+    let output = run_sample_with(|e| e.truncate(9), FORFEIT);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.last_valid_pair, Some(3));
+    assert_eq!(output.events_len, 9);
+
+    // Without forfeit mode the same log is rejected
+    let output = run_sample_with(|e| e.truncate(8), ScoringRules::CLASSIC);
+    assert!(!output.fair);
+    assert_eq!(output.last_valid_pair, None);
+
+    // A finished match is unaffected
+    let output = run_sample(FORFEIT);
+    assert_eq!(output.end_reason, Some(EndReason::Score));
+    assert_eq!(output.last_valid_pair, None);
+}
+
+#[test]
+fn test_guest_abandoned_prefix_ends_at_violation() {
+    // The left paddle jumps off the board in pair 3: pairs 0-2 (2-1) are the valid prefix
+    let output = run_sample_with(|e| e[6] = 99999999, FORFEIT);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.reason.as_deref(), Some("Paddle moved too fast"));
+    assert_eq!((output.left_score, output.right_score), (2, 1));
+    assert_eq!(output.end_reason, Some(EndReason::Abandoned));
+    assert_eq!(output.last_valid_pair, Some(2));
+    assert_eq!(output.violator, Some(Violator::Left));
+
+    // The same jump by the right paddle, and by both
+    let output = run_sample_with(|e| e[7] = 99999999, FORFEIT);
+    assert!(output.fair, "{:?}", output.reason);
+    assert_eq!(output.violator, Some(Violator::Right));
+    let output = run_sample_with(|e| (e[6], e[7]) = (99999999, 99999999), FORFEIT);
+    assert_eq!(output.violator, Some(Violator::Both));
+
+    // A violation in the first pair leaves nothing to prove
+    let output = run_sample_with(|e| e[0] = 99999999, FORFEIT);
+    assert!(!output.fair);
+    assert_eq!(output.reason.as_deref(), Some("Paddle moved too fast"));
+    assert_eq!(output.violator, None);
+}
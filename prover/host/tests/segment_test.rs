@@ -0,0 +1,139 @@
+// Tests for segmented proving: checkpoints, the segment guest and the join guest
+use core::{
+    Checkpoint, CompactLog, FixState, MatchScore, Ruleset, ScoringRules, SegmentStart, ValidateLogInput,
+    ValidateLogOutput, Violator,
+};
+use host::{
+    generate_pong_proof_with_rules, generate_segmented_pong_proof, join_segments, prove_segment, verify_pong_proof,
+    ReceiptKind,
+};
+
+mod common;
+use common::{read_log, LOG_19, LOG_64};
+
+fn rules(scoring: ScoringRules) -> Ruleset {
+    Ruleset { scoring, ..Ruleset::STANDARD }
+}
+
+/// Prove `log` in one piece and in segments of each size, checking the journals are identical
+fn assert_segments_match(log: &CompactLog, rules: Ruleset, sizes: &[u32]) -> ValidateLogOutput {
+    let single = generate_pong_proof_with_rules(log, ReceiptKind::Succinct, rules).expect("Failed to prove log");
+    for &pairs in sizes {
+        let joined = generate_segmented_pong_proof(log, ReceiptKind::Succinct, rules, pairs)
+            .unwrap_or_else(|e| panic!("Failed to prove log in segments of {} pairs: {}", pairs, e));
+        assert_eq!(
+            joined.receipt.journal.bytes, single.receipt.journal.bytes,
+            "Segments of {} pairs committed a different journal",
+            pairs
+        );
+        verify_pong_proof(&joined).expect("Joined proof does not verify against the registry");
+    }
+    single.receipt.journal.decode().unwrap()
+}
+
+#[test]
+fn test_checkpoint_digest_binds_state() {
+    let state = FixState { t0: 0, x: 0, y: 0, vx: 0, vy: 0, speed: 0, left_y: 0, right_y: 0, dir: 1 };
+    let checkpoint = Checkpoint {
+        game_id: 7,
+        log_version: 1,
+        rules: Ruleset::STANDARD,
+        pairs: 4,
+        state,
+        prev_v_l: 0,
+        prev_v_r: 0,
+        prev_dt: 0,
+        score: MatchScore::new(ScoringRules::CLASSIC),
+        valid_pairs: 4,
+        stopped: None,
+        violator: None,
+    };
+    let digest = checkpoint.digest();
+    assert_eq!(digest, checkpoint.clone().digest());
+
+    let mut moved = checkpoint.clone();
+    moved.state.y = 1;
+    let mut scored = checkpoint.clone();
+    scored.score.point(true);
+    let mut stopped = checkpoint.clone();
+    stopped.stopped = Some("Paddle moved too fast".into());
+    let mut blamed = stopped.clone();
+    blamed.violator = Some(Violator::Left);
+    let mut later = checkpoint.clone();
+    later.pairs = 5;
+    assert_ne!(blamed.digest(), stopped.digest());
+    for other in [moved, scored, stopped, blamed, later] {
+        assert_ne!(other.digest(), digest);
+    }
+}
+
+#[test]
+fn test_segments_chain_by_checkpoint_digest() {
+    let log = read_log(LOG_19);
+    let input = ValidateLogInput::new(log.parse_events().unwrap(), log.game_id, log.v);
+    let bytes = input.event_bytes().unwrap();
+
+    let start = SegmentStart::New { game_id: log.game_id, log_version: log.v, rules: Ruleset::STANDARD };
+    let (_, first) = prove_segment(&start, &bytes[..4 * 8]).expect("Failed to prove first segment");
+    assert_eq!(first.resumed_from, None);
+    assert_eq!((first.end.pairs, first.events_len), (4, 8));
+
+    let (_, second) = prove_segment(&SegmentStart::Resume(first.end.clone()), &bytes[4 * 8..])
+        .expect("Failed to prove second segment");
+    assert_eq!(second.resumed_from, Some(first.end.digest()));
+    assert_eq!(second.end.pairs, 19);
+    assert_eq!(second.end.score.result(), (3, 2));
+}
+
+#[test]
+fn test_segmented_proof_matches_single_proof() {
+    let out = assert_segments_match(&read_log(LOG_19), Ruleset::STANDARD, &[1, 2, 4, 100]);
+    assert!(out.fair);
+    assert_eq!((out.left_score, out.right_score), (3, 2));
+
+    assert_segments_match(&read_log(LOG_64), rules(ScoringRules { best_of: 3, ..ScoringRules::CLASSIC }), &[3]);
+}
+
+#[test]
+fn test_segmented_proof_of_abandoned_log() {
+    // Cut mid-match with a torn final pair, which no segment replays
+    let mut log = read_log(LOG_19);
+    log.events.truncate(9);
+    let forfeit = rules(ScoringRules { forfeit: true, ..ScoringRules::CLASSIC });
+
+    let out = assert_segments_match(&log, forfeit, &[1, 3]);
+    assert!(out.fair);
+    assert_eq!(out.last_valid_pair, Some(3));
+}
+
+#[test]
+fn test_segmented_proof_of_invalid_logs() {
+    // A violation part way through, and a header the guest rejects before any segment
+    let mut log = read_log(LOG_19);
+    log.events[6] = "99999999".into();
+    let out = assert_segments_match(&log, Ruleset::STANDARD, &[2]);
+    assert!(!out.fair);
+
+    log.events.truncate(7);
+    let out = assert_segments_match(&log, Ruleset::STANDARD, &[2]);
+    assert_eq!(out.reason.as_deref(), Some("Events must be pairs"));
+}
+
+#[test]
+fn test_join_rejects_incomplete_chain() {
+    let log = read_log(LOG_19);
+    let input = ValidateLogInput::new(log.parse_events().unwrap(), log.game_id, log.v);
+    let bytes = input.event_bytes().unwrap();
+
+    let start = SegmentStart::New { game_id: log.game_id, log_version: log.v, rules: Ruleset::STANDARD };
+    let first = prove_segment(&start, &bytes[..4 * 8]).unwrap();
+    let second = prove_segment(&SegmentStart::Resume(first.1.end.clone()), &bytes[4 * 8..]).unwrap();
+
+    // Missing the tail of the log
+    assert!(join_segments(&input, vec![first.clone()], ReceiptKind::Succinct).is_err());
+    // Segments out of order
+    assert!(join_segments(&input, vec![second.clone(), first.clone()], ReceiptKind::Succinct).is_err());
+    // A segment started fresh instead of resuming
+    let fresh = prove_segment(&start, &bytes[4 * 8..]).unwrap();
+    assert!(join_segments(&input, vec![first, fresh], ReceiptKind::Succinct).is_err());
+}
@@ -0,0 +1,136 @@
+// Tests for multi-game session logs: derived game IDs, totals and the session guest
+use core::{
+    check_session_header, session_game_id, CompactLog, EndReason, Outcome, Ruleset, SessionInput, SessionLog,
+    SessionTotals, ValidateLogOutput, Violator,
+};
+use host::{generate_pong_proof, generate_session_proof, verify_session_proof, ReceiptKind};
+
+mod common;
+use common::{read_log, LOG_19, LOG_64};
+
+const SEED: u32 = 0x5e55_1017;
+
+fn session(logs: &[CompactLog]) -> SessionLog {
+    SessionLog {
+        v: 1,
+        session_seed: SEED,
+        games: logs.iter().map(|log| log.events.clone()).collect(),
+    }
+}
+
+/// Journal of a game with the given fairness, outcome and score
+fn game(fair: bool, outcome: Option<Outcome>, left_score: u32, right_score: u32) -> ValidateLogOutput {
+    let mut out = ValidateLogOutput::invalid("");
+    out.fair = fair;
+    out.reason = (!fair).then(|| "Paddle moved too fast".into());
+    out.outcome = outcome;
+    out.left_score = left_score;
+    out.right_score = right_score;
+    out
+}
+
+/// Journal of a fair game abandoned at the given score, after a violation by `violator`
+fn abandoned(violator: Option<Violator>, left_score: u32, right_score: u32) -> ValidateLogOutput {
+    let mut out = game(true, None, left_score, right_score);
+    out.end_reason = Some(EndReason::Abandoned);
+    out.violator = violator;
+    out
+}
+
+#[test]
+fn test_session_game_ids() {
+    let log = session(&[read_log(LOG_19), read_log(LOG_64), read_log(LOG_19)]);
+    let ids = log.game_ids();
+    assert_eq!(ids, (0..3).map(|i| session_game_id(SEED, i)).collect::<Vec<_>>());
+
+    // Distinct within a session and across seeds
+    assert_ne!(ids[0], ids[1]);
+    assert_ne!(ids[0], ids[2]);
+    assert_ne!(session_game_id(SEED + 1, 0), ids[0]);
+}
+
+#[test]
+fn test_session_totals() {
+    let games = [
+        game(true, Some(Outcome::LeftWin), 3, 2),
+        game(true, Some(Outcome::RightWin), 2, 3),
+        game(true, Some(Outcome::Draw), 1, 1),
+        abandoned(None, 1, 0),
+        abandoned(Some(Violator::Right), 2, 1),
+        abandoned(Some(Violator::Both), 0, 0),
+        game(false, Some(Outcome::LeftWin), 3, 0),
+    ];
+    let totals = SessionTotals::of(&games);
+    assert_eq!(
+        totals,
+        SessionTotals {
+            games: 7,
+            fair_games: 6,
+            left_wins: 1,
+            right_wins: 1,
+            draws: 1,
+            abandoned: 3,
+            left_violations: 1,
+            right_violations: 2,
+            left_score: 9,
+            right_score: 7,
+        }
+    );
+}
+
+#[test]
+fn test_session_header_checks() {
+    let input = |games: usize, log_version: u32| SessionInput {
+        session_seed: SEED,
+        log_version,
+        rules: Ruleset::STANDARD,
+        games: vec![Vec::new(); games],
+    };
+    assert_eq!(check_session_header(&input(2, 1).header()), Ok(Ruleset::STANDARD));
+    assert!(check_session_header(&input(0, 1).header()).is_err());
+    assert!(check_session_header(&input(65, 1).header()).is_err());
+    assert!(check_session_header(&input(2, 99).header()).is_err());
+}
+
+#[test]
+fn test_guest_proves_session() {
+    let logs = [read_log(LOG_19), read_log(LOG_64)];
+    let session = session(&logs);
+    let proof = generate_session_proof(&session, ReceiptKind::Succinct, Ruleset::STANDARD)
+        .expect("Failed to prove session");
+    verify_session_proof(&proof).expect("Session proof does not verify");
+
+    // Each game's output is what its own proof under the derived ID commits
+    for ((log, game_id), out) in logs.iter().zip(session.game_ids()).zip(&proof.output.games) {
+        let standalone = CompactLog {
+            game_id,
+            ..log.clone()
+        };
+        let single = generate_pong_proof(&standalone, ReceiptKind::Succinct).expect("Failed to prove game");
+        let expected: ValidateLogOutput = single.receipt.journal.decode().unwrap();
+        assert_eq!(*out, expected);
+    }
+
+    assert!(proof.output.fair);
+    assert_eq!(proof.output.session_seed, SEED);
+    assert_eq!(proof.output.totals, SessionTotals::of(&proof.output.games));
+    assert_eq!(proof.output.totals.games, 2);
+
+    // A tampered output no longer matches the journal
+    let mut tampered = proof.clone();
+    tampered.output.totals.left_wins += 1;
+    assert!(verify_session_proof(&tampered).is_err());
+}
+
+#[test]
+fn test_guest_reports_first_unfair_game() {
+    let mut session = session(&[read_log(LOG_19), read_log(LOG_64)]);
+    session.games[1][6] = "99999999".into();
+
+    let proof = generate_session_proof(&session, ReceiptKind::Succinct, Ruleset::STANDARD)
+        .expect("Failed to prove session");
+    assert!(!proof.output.fair);
+    assert_eq!(proof.output.reason.as_deref(), Some("Game 1: Paddle moved too fast"));
+    assert!(proof.output.games[0].fair);
+    assert_eq!(proof.output.totals.fair_games, 1);
+}
@@ -0,0 +1,230 @@
+// Tests for the Soroban contract: pong journal decoding and claim digests
+// against core and risc0, and settling Groth16 proofs generated by the host
+use core::{
+    config_digest, EndReason, GameScore, Outcome as PongOutcome, Ruleset, ValidateLogOutput, Violator,
+    REASON_PADDLE_TOO_FAST,
+};
+use host::{generate_pong_proof_with_rules, groth16_seal, PongProof, ReceiptKind};
+use methods::GUEST_CODE_FOR_ZK_PROOF_ID;
+use pong_soroban::{
+    claim_digest, decode_journal, Config, Error, GameRecord, Outcome, PongContract, PongContractClient,
+};
+use risc0_zkvm::sha::Digestible;
+use risc0_zkvm::{Groth16ReceiptVerifierParameters, ReceiptClaim};
+use soroban_sdk::{Bytes, BytesN, Env};
+
+mod common;
+use common::{read_log, LOG_19, LOG_64};
+
+fn image_id_bytes(image_id: [u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_mut(4).zip(image_id) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+/// Journal bytes as the pong guest commits `out`
+fn journal_bytes(out: &ValidateLogOutput) -> Vec<u8> {
+    let words = risc0_zkvm::serde::to_vec(out).unwrap();
+    words.iter().flat_map(|w| w.to_le_bytes()).collect()
+}
+
+/// Journal of a fair best-of-3 game won by the right player
+fn sample_output() -> ValidateLogOutput {
+    let mut out = ValidateLogOutput::invalid("");
+    out.fair = true;
+    out.reason = None;
+    out.left_score = 1;
+    out.right_score = 2;
+    out.game_id = 475565618;
+    out.log_version = 1;
+    out.rules = Ruleset::preset("spin").unwrap();
+    out.rules.scoring.best_of = 3;
+    out.games = vec![
+        GameScore { left: 3, right: 1 },
+        GameScore { left: 2, right: 3 },
+        GameScore { left: 1, right: 3 },
+    ];
+    out.end_reason = Some(EndReason::Score);
+    out.outcome = Some(PongOutcome::RightWin);
+    out
+}
+
+/// Contract settling standard-rules v1 games
+fn register(env: &Env) -> PongContractClient<'_> {
+    let params = Groth16ReceiptVerifierParameters::default();
+    let config = Config {
+        image_id: BytesN::from_array(env, &image_id_bytes(GUEST_CODE_FOR_ZK_PROOF_ID)),
+        config_digest: BytesN::from_array(env, &config_digest(1, &Ruleset::STANDARD)),
+        control_root: BytesN::from_array(env, params.control_root.as_bytes().try_into().unwrap()),
+        bn254_control_id: BytesN::from_array(env, params.bn254_control_id.as_bytes().try_into().unwrap()),
+    };
+    let contract_id = env.register(PongContract, (config,));
+    PongContractClient::new(env, &contract_id)
+}
+
+fn prove(path: &str, rules: Ruleset) -> PongProof {
+    generate_pong_proof_with_rules(&read_log(path), ReceiptKind::Groth16, rules).expect("Failed to prove game")
+}
+
+fn submit_args(env: &Env, proof: &PongProof) -> (Bytes, Bytes) {
+    let seal = groth16_seal(&proof.receipt).expect("Not a Groth16 proof");
+    (
+        Bytes::from_slice(env, &seal),
+        Bytes::from_slice(env, &proof.receipt.journal.bytes),
+    )
+}
+
+#[test]
+fn test_decode_journal() {
+    // The contract reads the journal the guest commits
+    let env = Env::default();
+    let out = sample_output();
+    let bytes = journal_bytes(&out);
+    let journal = decode_journal(&Bytes::from_slice(&env, &bytes)).unwrap();
+    assert!(journal.fair);
+    assert_eq!((journal.left_score, journal.right_score), (1, 2));
+    assert_eq!(journal.game_id, 475565618);
+    assert_eq!(journal.config_digest, config_digest(1, &out.rules));
+    assert_eq!(journal.outcome, Some(Outcome::RightWin));
+
+    // Rejected: a reason string (not a whole number of words) and no outcome
+    let rejected = journal_bytes(&ValidateLogOutput::invalid("Events must be pairs"));
+    let journal = decode_journal(&Bytes::from_slice(&env, &rejected)).unwrap();
+    assert!(!journal.fair);
+    assert_eq!(journal.outcome, None);
+
+    // Abandoned: every optional field present
+    let abandoned = ValidateLogOutput {
+        fair: true,
+        reason: Some(REASON_PADDLE_TOO_FAST.into()),
+        end_reason: Some(EndReason::Abandoned),
+        last_valid_pair: Some(12),
+        outcome: None,
+        violator: Some(Violator::Right),
+        ..sample_output()
+    };
+    let journal = decode_journal(&Bytes::from_slice(&env, &journal_bytes(&abandoned))).unwrap();
+    assert!(journal.fair);
+    assert_eq!(journal.outcome, None);
+    assert_eq!(journal.game_id, 475565618);
+
+    // Truncated or padded journals and out-of-range words do not parse
+    let words = bytes.len() / 4;
+    let mut fair = bytes.clone();
+    fair[0] = 2;
+    // The outcome is the third word from the end, after its tag
+    let mut outcome = bytes.clone();
+    outcome[(words - 3) * 4] = 3;
+    let padded = [bytes.as_slice(), &[0, 0, 0, 0]].concat();
+    for bad in [
+        &bytes[..bytes.len() - 4],
+        &bytes[..bytes.len() - 1],
+        &padded,
+        &fair,
+        &outcome,
+    ] {
+        assert_eq!(
+            decode_journal(&Bytes::from_slice(&env, bad)),
+            Err(Error::MalformedJournal)
+        );
+    }
+}
+
+#[test]
+fn test_claim_digest_matches_risc0() {
+    let env = Env::default();
+    let journal = journal_bytes(&sample_output());
+    let expected = ReceiptClaim::ok(GUEST_CODE_FOR_ZK_PROOF_ID, journal.clone()).digest();
+
+    let digest = claim_digest(
+        &env,
+        &BytesN::from_array(&env, &image_id_bytes(GUEST_CODE_FOR_ZK_PROOF_ID)),
+        &Bytes::from_slice(&env, &journal),
+    );
+    assert_eq!(digest.to_array().as_slice(), expected.as_bytes());
+}
+
+#[test]
+fn test_contract_records_groth16_results() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = register(&env);
+
+    let left = prove(LOG_19, Ruleset::STANDARD);
+    let right = prove(LOG_64, Ruleset::STANDARD);
+
+    let (seal, journal) = submit_args(&env, &left);
+    let record = client.submit(&seal, &journal);
+    assert_eq!(
+        record,
+        GameRecord {
+            outcome: Outcome::LeftWin,
+            left_score: 3,
+            right_score: 2,
+        }
+    );
+    assert_eq!(client.result(&left.game_id), Some(record));
+
+    let (seal, journal) = submit_args(&env, &right);
+    assert_eq!(client.submit(&seal, &journal).outcome, Outcome::RightWin);
+    assert_eq!(client.result(&1), None);
+
+    // Each game ID is recorded once
+    let (seal, journal) = submit_args(&env, &left);
+    assert_eq!(client.try_submit(&seal, &journal), Err(Ok(Error::GameAlreadyRecorded)));
+}
+
+#[test]
+fn test_contract_rejects_forged_proofs() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = register(&env);
+
+    let proof = prove(LOG_19, Ruleset::STANDARD);
+    let (seal, journal) = submit_args(&env, &proof);
+
+    // A journal claiming the other player won, with the genuine seal
+    let mut forged: ValidateLogOutput = proof.receipt.journal.decode().unwrap();
+    forged.outcome = Some(PongOutcome::RightWin);
+    let forged = Bytes::from_slice(&env, &journal_bytes(&forged));
+    assert_eq!(client.try_submit(&seal, &forged), Err(Ok(Error::InvalidProof)));
+
+    // A seal of the wrong length
+    let short = seal.slice(..255);
+    assert_eq!(client.try_submit(&short, &journal), Err(Ok(Error::InvalidProof)));
+
+    // Nothing was recorded, so the genuine proof still goes through
+    assert_eq!(client.result(&proof.game_id), None);
+    client.submit(&seal, &journal);
+}
+
+#[test]
+fn test_contract_rejects_unfair_games() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = register(&env);
+
+    let mut log = read_log(LOG_19);
+    log.events[6] = "99999999".into();
+    let proof =
+        generate_pong_proof_with_rules(&log, ReceiptKind::Groth16, Ruleset::STANDARD).expect("Failed to prove game");
+    let (seal, journal) = submit_args(&env, &proof);
+    assert_eq!(client.try_submit(&seal, &journal), Err(Ok(Error::UnfairGame)));
+}
+
+#[test]
+fn test_contract_rejects_other_configs() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let client = register(&env);
+
+    // A genuine proof of a game played under other rules
+    let proof = prove(LOG_19, Ruleset::HUMAN_PLAY);
+    let out: ValidateLogOutput = proof.receipt.journal.decode().unwrap();
+    assert_eq!(out.rules, Ruleset::HUMAN_PLAY);
+    let (seal, journal) = submit_args(&env, &proof);
+    assert_eq!(client.try_submit(&seal, &journal), Err(Ok(Error::WrongConfig)));
+    assert_eq!(client.result(&proof.game_id), None);
+}
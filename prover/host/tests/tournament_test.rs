@@ -0,0 +1,127 @@
+// Tests for tournament brackets in core and their proof by the tournament guest
+use core::{Bracket, GameResult, Ruleset};
+use host::{generate_pong_proof, generate_tournament_proof, verify_tournament_proof, ReceiptKind};
+
+mod common;
+use common::{read_log, BRACKET1_FINAL, BRACKET1_SEMI_0, BRACKET1_SEMI_1};
+
+/// Four players in two semifinals and a final
+fn bracket() -> Bracket {
+    Bracket {
+        id: 1,
+        players: vec![10, 20, 30, 40],
+        rules: Ruleset::STANDARD,
+    }
+}
+
+fn result(game_id: u32, left_won: bool) -> GameResult {
+    GameResult { game_id, left_won }
+}
+
+#[test]
+fn test_bracket_validation() {
+    assert!(bracket().validate().is_ok());
+
+    let mut odd = bracket();
+    odd.players.push(50);
+    assert!(odd.validate().is_err());
+
+    let mut twice = bracket();
+    twice.players[3] = 10;
+    assert!(twice.validate().is_err());
+}
+
+#[test]
+fn test_game_ids_bind_players() {
+    let b = bracket();
+    assert_eq!(b.game_id(0, 10, 20), 4019116110);
+    assert_eq!(b.game_id(1, 30, 40), 211735587);
+    assert_eq!(b.game_id(2, 10, 40), 2948663432);
+
+    // Sides, match and bracket all change the ID
+    assert_ne!(b.game_id(0, 20, 10), b.game_id(0, 10, 20));
+    assert_ne!(b.game_id(1, 10, 20), b.game_id(0, 10, 20));
+    let other = Bracket { id: 2, ..bracket() };
+    assert_ne!(other.game_id(0, 10, 20), b.game_id(0, 10, 20));
+
+    // The final is known once both semifinals are
+    let semis = [result(b.game_id(0, 10, 20), true), result(b.game_id(1, 30, 40), false)];
+    assert_eq!(b.next_game_id(&[]), Ok(Some(b.game_id(0, 10, 20))));
+    assert_eq!(b.next_game_id(&semis[..1]), Ok(Some(b.game_id(1, 30, 40))));
+    assert_eq!(b.next_game_id(&semis), Ok(Some(b.game_id(2, 10, 40))));
+    assert_eq!(b.next_game_id(&[semis[0], semis[1], result(b.game_id(2, 10, 40), true)]), Ok(None));
+}
+
+#[test]
+fn test_winners_advance() {
+    let b = bracket();
+    // 10 beats 20, 40 beats 30, then the final is 10 (left) vs 40 (right)
+    let semis = [result(b.game_id(0, 10, 20), true), result(b.game_id(1, 30, 40), false)];
+    let final_id = b.game_id(2, 10, 40);
+    assert_eq!(b.champion(&[semis[0], semis[1], result(final_id, true)]), Ok(10));
+    assert_eq!(b.champion(&[semis[0], semis[1], result(final_id, false)]), Ok(40));
+
+    // Results must be the bracket's games, in match order, one per match
+    assert!(b.champion(&[semis[1], semis[0], result(final_id, true)]).is_err());
+    assert!(b.champion(&semis).is_err());
+    let extra = result(b.game_id(3, 10, 40), true);
+    assert!(b.champion(&[semis[0], semis[1], result(final_id, true), extra]).is_err());
+
+    // A final between players who did not both get through does not fit the slot
+    assert!(b.champion(&[semis[0], semis[1], result(b.game_id(2, 10, 30), true)]).is_err());
+}
+
+#[test]
+fn test_bracket_digest_binds_seeding() {
+    let b = bracket();
+    let mut swapped = b.clone();
+    swapped.players.swap(0, 1);
+    let mut timed = b.clone();
+    timed.rules.scoring.time_limit = 60 << 16;
+    let other = Bracket { id: 2, ..bracket() };
+
+    assert_eq!(b.digest(), bracket().digest());
+    assert_ne!(swapped.digest(), b.digest());
+    assert_ne!(timed.digest(), b.digest());
+    assert_ne!(other.digest(), b.digest());
+}
+
+#[test]
+fn test_guest_proves_champion() {
+    let proofs: Vec<_> = [BRACKET1_FINAL, BRACKET1_SEMI_0, BRACKET1_SEMI_1]
+        .iter()
+        .map(|path| generate_pong_proof(&read_log(path), ReceiptKind::Succinct).expect("Failed to prove game"))
+        .collect();
+
+    let proof = generate_tournament_proof(&bracket(), &proofs, ReceiptKind::Succinct)
+        .expect("Failed to prove tournament");
+    assert_eq!(proof.output.champion, 10);
+    assert_eq!(proof.output.bracket_hash, bracket().digest());
+    assert_eq!(proof.output.pong_image_ids.len(), 1);
+    verify_tournament_proof(&proof).expect("Tournament proof does not verify");
+
+    // The games were played for these slots: swapping the seeds or moving
+    // them to another bracket leaves every match without its game
+    let mut reseeded = bracket();
+    reseeded.players.swap(0, 1);
+    assert!(generate_tournament_proof(&reseeded, &proofs, ReceiptKind::Succinct).is_err());
+    let other = Bracket { id: 2, ..bracket() };
+    assert!(generate_tournament_proof(&other, &proofs, ReceiptKind::Succinct).is_err());
+}
+
+#[test]
+fn test_tournament_rejects_missing_or_unfair_games() {
+    let semis: Vec<_> = [BRACKET1_SEMI_0, BRACKET1_SEMI_1]
+        .iter()
+        .map(|path| generate_pong_proof(&read_log(path), ReceiptKind::Succinct).unwrap())
+        .collect();
+    assert!(generate_tournament_proof(&bracket(), &semis, ReceiptKind::Succinct).is_err());
+
+    // A final that was proven unfair sends nobody through
+    let mut final_log = read_log(BRACKET1_FINAL);
+    final_log.events[6] = "99999999".into();
+    let unfair = generate_pong_proof(&final_log, ReceiptKind::Succinct).unwrap();
+    assert!(!unfair.fair);
+    let proofs = [semis, vec![unfair]].concat();
+    assert!(generate_tournament_proof(&bracket(), &proofs, ReceiptKind::Succinct).is_err());
+}
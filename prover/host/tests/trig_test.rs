@@ -0,0 +1,68 @@
+// Tests for the versioned trig implementations in core
+use core::{cordic_sin_cos, table_sin_cos, TRIG_TABLE_MAX_DEG};
+
+const ONE: f64 = 65536.0;
+
+/// Distance from the exact value, in Q16.16 units
+fn error(actual: i64, angle_rad: f64, f: fn(f64) -> f64) -> f64 {
+    (actual as f64 - f(angle_rad) * ONE).abs()
+}
+
+#[test]
+fn test_table_exact_at_whole_degrees() {
+    // Pinned entries guard against the generated table changing silently
+    assert_eq!(table_sin_cos(0), (0, 65536));
+    assert_eq!(table_sin_cos(30 << 16), (32768, 56756));
+    assert_eq!(table_sin_cos(45 << 16), (46341, 46341));
+    assert_eq!(table_sin_cos(60 << 16), (56756, 32768));
+    assert_eq!(table_sin_cos(90 << 16), (65536, 0));
+
+    for deg in -TRIG_TABLE_MAX_DEG..=TRIG_TABLE_MAX_DEG {
+        let (sin, cos) = table_sin_cos((deg as i64) << 16);
+        let rad = (deg as f64).to_radians();
+        assert_eq!(sin, (rad.sin() * ONE).round() as i64, "sin({})", deg);
+        assert_eq!(cos, (rad.cos() * ONE).round() as i64, "cos({})", deg);
+    }
+}
+
+#[test]
+fn test_table_symmetry() {
+    for angle in (0..=(60i64 << 16)).step_by(997) {
+        let (sin_pos, cos_pos) = table_sin_cos(angle);
+        let (sin_neg, cos_neg) = table_sin_cos(-angle);
+        assert_eq!(sin_neg, -sin_pos);
+        assert_eq!(cos_neg, cos_pos);
+    }
+}
+
+#[test]
+fn test_table_more_accurate_than_cordic() {
+    // Sweep the bounce range (±60°) including fractional degrees
+    let mut table_max: f64 = 0.0;
+    let mut cordic_max: f64 = 0.0;
+    for angle in (-(60i64 << 16)..=(60i64 << 16)).step_by(811) {
+        let rad = (angle as f64 / ONE).to_radians();
+        let (sin, cos) = table_sin_cos(angle);
+        table_max = table_max.max(error(sin, rad, f64::sin)).max(error(cos, rad, f64::cos));
+
+        // Same angle in Q16.16 radians, compared against the angle CORDIC actually sees
+        let rad_q16 = (rad * ONE).round() as i64;
+        let rad = rad_q16 as f64 / ONE;
+        let (sin, cos) = cordic_sin_cos(rad_q16);
+        cordic_max = cordic_max.max(error(sin, rad, f64::sin)).max(error(cos, rad, f64::cos));
+    }
+
+    assert!(table_max <= 4.0, "Interpolated table error {} exceeds 4 Q16.16 units", table_max);
+    assert!(
+        table_max < cordic_max,
+        "Table error {} should be below CORDIC error {}",
+        table_max,
+        cordic_max
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of valid range")]
+fn test_table_rejects_out_of_range_angle() {
+    table_sin_cos((91i64) << 16);
+}
@@ -0,0 +1,160 @@
+// Tests for the standalone pong-verifier crate against proofs generated by the host
+use core::{EndReason, Ruleset, ValidateLogOutput, Violator, REASON_PADDLE_TOO_FAST};
+use host::registry::UNRECORDED_IMAGE_ID;
+use host::{generate_pong_proof, GuestRelease, JournalLayout, ReceiptKind, SavedProof, CURRENT_GUEST_VERSION};
+use join_methods::JOIN_SEGMENTS_ID;
+use methods::GUEST_CODE_FOR_ZK_PROOF_ID;
+use pong_verifier::journal::JournalV1;
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::Journal;
+
+mod common;
+use common::{read_log, LOG_19};
+
+#[test]
+fn test_current_release_matches_guest() {
+    let current = host::registry::release(host::GUEST_REGISTRY, CURRENT_GUEST_VERSION).expect("No current release");
+    assert_eq!(current.image_id, GUEST_CODE_FOR_ZK_PROOF_ID);
+    assert_eq!(current.join_image_id, Some(JOIN_SEGMENTS_ID));
+}
+
+#[test]
+fn test_verifier_accepts_host_proof() {
+    let log = read_log(LOG_19);
+    let proof = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to generate proof");
+    let saved = SavedProof::new(proof).expect("Failed to wrap proof");
+
+    // Round-trip through the proof file format, as a game server would receive it
+    let json = serde_json::to_string(&saved).unwrap();
+    let loaded: SavedProof = serde_json::from_str(&json).unwrap();
+
+    let out = pong_verifier::verify_saved_proof(&loaded).expect("Verification failed");
+    assert!(out.fair, "Game should be fair");
+    assert_eq!(out.events_len, 38);
+    assert_eq!(out.game_id, log.game_id);
+}
+
+#[test]
+fn test_verifier_rejects_mismatched_metadata() {
+    let log = read_log(LOG_19);
+    let mut proof = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to generate proof");
+
+    // Claim a different score than the journal commits
+    proof.left_score += 1;
+
+    assert!(
+        pong_verifier::verify(&proof).is_err(),
+        "Proof with tampered metadata should be rejected"
+    );
+}
+
+#[test]
+fn test_registry_rejects_revoked_and_unknown_guests() {
+    let log = read_log(LOG_19);
+    let proof = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to generate proof");
+
+    let registry = host::GUEST_REGISTRY;
+    assert!(host::registry::verify_registered(registry, &proof.receipt).is_ok());
+
+    // Revoking the release that produced the proof must reject it
+    let revoked: Vec<host::GuestRelease> = registry
+        .iter()
+        .map(|r| host::GuestRelease { revoked: true, ..*r })
+        .collect();
+    assert!(host::registry::verify_registered(&revoked, &proof.receipt).is_err());
+
+    // So must a registry that does not know the image ID at all
+    assert!(host::registry::verify_registered(&[], &proof.receipt).is_err());
+}
+
+#[test]
+fn test_legacy_proof_defaults_to_guest_version_1() {
+    let log = read_log(LOG_19);
+    let proof = generate_pong_proof(&log, ReceiptKind::Succinct).expect("Failed to generate proof");
+
+    // Proof files written before guest versions were recorded have no such field
+    let mut json = serde_json::to_value(&proof).unwrap();
+    json.as_object_mut().unwrap().remove("guest_version");
+
+    let legacy: host::PongProof = serde_json::from_value(json).unwrap();
+    assert_eq!(legacy.guest_version, 1);
+}
+
+/// Journal bytes as a guest commits `value`
+fn journal_of<T: serde::Serialize>(value: &T) -> Journal {
+    let words = risc0_zkvm::serde::to_vec(value).unwrap();
+    Journal::new(words.iter().flat_map(|w| w.to_le_bytes()).collect())
+}
+
+#[test]
+fn test_registry_has_a_release_per_version() {
+    let registry = pong_verifier::GUEST_REGISTRY;
+    for (i, release) in registry.iter().enumerate() {
+        assert_eq!(
+            release.version,
+            i as u32 + 1,
+            "Releases must be numbered from 1, oldest first"
+        );
+    }
+    let current = registry.last().unwrap();
+    assert_eq!(current.version, CURRENT_GUEST_VERSION);
+    assert_eq!(current.journal, JournalLayout::CURRENT);
+    assert!(
+        registry.windows(2).all(|w| w[0].journal <= w[1].journal),
+        "Journal layouts only ever grow"
+    );
+}
+
+#[test]
+fn test_unrecorded_image_ids_match_no_receipt() {
+    let release = GuestRelease {
+        image_id: UNRECORDED_IMAGE_ID,
+        join_image_id: Some(UNRECORDED_IMAGE_ID),
+        ..host::GUEST_REGISTRY[0]
+    };
+    assert!(!release.has_image_id(Digest::from(UNRECORDED_IMAGE_ID)));
+    assert_eq!(host::registry::unrecorded_versions(&[release]), vec![1]);
+}
+
+#[test]
+fn test_v1_journal_decodes_with_legacy_defaults() {
+    let v1 = JournalV1 {
+        fair: true,
+        reason: None,
+        left_score: 3,
+        right_score: 2,
+        events_len: 38,
+        log_hash_sha256: [7; 32],
+        game_id: 42,
+    };
+    let journal = journal_of(&v1);
+
+    let out = JournalLayout::V1.decode(&journal).expect("V1 journal should decode");
+    assert_eq!(
+        (out.left_score, out.right_score, out.events_len, out.game_id),
+        (3, 2, 38, 42)
+    );
+    assert_eq!(out.log_hash_sha256, [7; 32]);
+    assert_eq!(out.log_version, 1);
+    assert_eq!(out.rules, Ruleset::STANDARD);
+    assert!(out.games.is_empty());
+    assert_eq!(
+        (out.end_reason, out.outcome, out.last_valid_pair, out.violator),
+        (None, None, None, None)
+    );
+
+    // The current layout cannot read it
+    assert!(JournalLayout::CURRENT.decode(&journal).is_err());
+}
+
+#[test]
+fn test_current_layout_round_trips() {
+    let out = ValidateLogOutput {
+        violator: Some(Violator::Right),
+        end_reason: Some(EndReason::Abandoned),
+        last_valid_pair: Some(12),
+        game_id: 9,
+        ..ValidateLogOutput::invalid(REASON_PADDLE_TOO_FAST)
+    };
+    assert_eq!(JournalLayout::CURRENT.decode(&journal_of(&out)).unwrap(), out);
+}
@@ -0,0 +1,12 @@
+// Builds the join guest with the segment guest's image ID
+//
+// The join guest verifies segment receipts against `methods`'
+// `REPLAY_SEGMENT_ID`. The guest build inherits this script's environment, so
+// the ID is handed to it as `PONG_REPLAY_SEGMENT_ID` (eight comma-separated
+// words) and read with `env!`, instead of the guest depending on `methods`.
+
+fn main() {
+    let words: Vec<String> = methods::REPLAY_SEGMENT_ID.iter().map(u32::to_string).collect();
+    std::env::set_var("PONG_REPLAY_SEGMENT_ID", words.join(","));
+    risc0_build::embed_methods();
+}
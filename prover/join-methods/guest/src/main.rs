@@ -0,0 +1,102 @@
+// Join guest: chains segment proofs into the output of a single full-log proof
+//
+// Reads the same input as the full-log guest (header words, the salt slot,
+// the disclosure word, then the event bytes), followed by the outputs of the
+// segment proofs. Each segment is verified against the segment guest's image
+// ID by composition; together they must chain from the opening serve and
+// cover every whole event pair of the log. The committed journal is identical
+// to the full-log guest's.
+
+use guest_code_for_zk_proof::replay;
+use guest_code_for_zk_proof::types::*;
+use risc0_zkvm::{guest::env, serde};
+
+fn main() {
+    let mut header = [0u32; INPUT_HEADER_WORDS];
+    env::read_slice(&mut header);
+    let [game_id, events_len, log_version, ..] = header;
+    // The host never salts segmented proofs or limits their disclosure: segment
+    // journals hash the events unsalted and carry the full checkpoints
+    let mut salt = [0u8; LOG_SALT_BYTES];
+    env::read_slice(&mut salt);
+    let salt = salt_from_bytes(salt);
+    let mut disclosure = [0u32; 1];
+    env::read_slice(&mut disclosure);
+
+    // A disclosure word or header the full-log guest rejects is rejected here
+    // too, without segments
+    let (out, disclosure) = match check_disclosure(disclosure[0], salt.is_some()) {
+        Err(msg) => (ValidateLogOutput::invalid(msg), Disclosure::Full),
+        Ok(disclosure) => match check_header(&header) {
+            Err(msg) => (ValidateLogOutput::invalid(msg), disclosure),
+            Ok((_, rules)) => {
+                let mut event_bytes = vec![0u8; events_len as usize * EVENT_BYTES];
+                env::read_slice(&mut event_bytes);
+                let segments: Vec<SegmentOutput> = env::read();
+                let end = join(segments, &event_bytes, game_id, log_version, rules);
+                let out = replay::finish(end, events_len, log_commitment(salt.as_ref(), &event_bytes));
+                (out, disclosure)
+            }
+        },
+    };
+
+    match DisclosedOutput::from_output(&out, disclosure) {
+        None => env::commit(&out),
+        Some(disclosed) => env::commit(&disclosed),
+    }
+}
+
+/// Verify the segments and check they chain over the whole log, returning the final checkpoint
+fn join(
+    segments: Vec<SegmentOutput>,
+    event_bytes: &[u8],
+    game_id: u32,
+    log_version: u32,
+    rules: Ruleset,
+) -> Checkpoint {
+    let segment_id = replay_segment_id();
+    let mut resumes = None;
+    let mut offset = 0;
+    let mut end = None;
+
+    for segment in segments {
+        env::verify(segment_id, &serde::to_vec(&segment).unwrap()).unwrap();
+        assert_eq!(segment.resumed_from, resumes, "Segment does not resume from the previous one");
+
+        let checkpoint = segment.end;
+        assert!(
+            checkpoint.game_id == game_id && checkpoint.log_version == log_version && checkpoint.rules == rules,
+            "Segment replays a different game"
+        );
+        let len = segment.events_len as usize * EVENT_BYTES;
+        let bytes = event_bytes
+            .get(offset..offset + len)
+            .expect("Segments cover more events than the log");
+        assert_eq!(compute_segment_hash(bytes), segment.events_hash, "Segment replayed different events");
+        offset += len;
+        assert_eq!(
+            checkpoint.pairs as usize * 2 * EVENT_BYTES,
+            offset,
+            "Segment pair count does not match its events"
+        );
+
+        resumes = Some(checkpoint.digest());
+        end = Some(checkpoint);
+    }
+
+    // Every whole pair is replayed; only a torn final event (forfeit mode) is left over
+    let whole_pairs = event_bytes.len() / (2 * EVENT_BYTES);
+    assert_eq!(offset, whole_pairs * 2 * EVENT_BYTES, "Segments do not cover the whole log");
+    end.expect("No segments")
+}
+
+/// Image ID of the segment guest, passed to this build by join-methods/build.rs
+fn replay_segment_id() -> [u32; 8] {
+    let mut words = env!("PONG_REPLAY_SEGMENT_ID").split(',');
+    std::array::from_fn(|_| {
+        words
+            .next()
+            .and_then(|w| w.parse().ok())
+            .expect("Malformed segment image ID")
+    })
+}
@@ -0,0 +1,40 @@
+// ABI journal guest: the full-log guest with a contract-friendly journal
+//
+// Reads the same input as the full-log guest and validates the log the same
+// way, but commits the fixed-width big-endian `AbiJournal` bytes (see
+// core/src/abi.rs) instead of the serde-encoded `ValidateLogOutput`.
+
+use risc0_zkvm::guest::env;
+
+use guest_code_for_zk_proof::replay::validate_log;
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let mut header = [0u32; INPUT_HEADER_WORDS];
+    env::read_slice(&mut header);
+    let [game_id, events_len, log_version, ..] = header;
+    // Private salt for the log commitment (all zeros: none), never committed
+    let mut salt = [0u8; LOG_SALT_BYTES];
+    env::read_slice(&mut salt);
+    let salt = salt_from_bytes(salt);
+    let mut disclosure = [0u32; 1];
+    env::read_slice(&mut disclosure);
+
+    // The ABI journal always discloses the full result, so any other mode is
+    // rejected like an invalid header
+    let checked = match Disclosure::from_word(disclosure[0]) {
+        Some(Disclosure::Full) => check_header(&header),
+        _ => Err(REASON_INVALID_DISCLOSURE),
+    };
+    let out = match checked {
+        Err(msg) => ValidateLogOutput::invalid(msg),
+        Ok((physics, rules)) => {
+            let mut event_bytes = vec![0u8; events_len as usize * EVENT_BYTES];
+            env::read_slice(&mut event_bytes);
+            validate_log(&event_bytes, salt.as_ref(), game_id, log_version, physics, rules)
+        }
+    };
+
+    // Raw bytes, so the journal digest is SHA-256 of the encoded fields
+    env::commit_slice(&AbiJournal::from_output(&out).encode());
+}
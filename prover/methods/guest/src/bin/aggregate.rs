@@ -0,0 +1,34 @@
+// Aggregation guest: folds many game proofs into one receipt
+//
+// Commits the Merkle root over each game's result (see aggregate.rs in core).
+// Each game's journal is verified by composition against the pong image ID it
+// claims, which must be one of the IDs this proof commits (see tournament.rs).
+
+use risc0_zkvm::{guest::env, serde};
+
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let input: AggregateInput = env::read();
+
+    let mut game_ids = Vec::with_capacity(input.games.len());
+    let mut leaves = Vec::with_capacity(input.games.len());
+    for game in &input.games {
+        assert!(
+            input.pong_image_ids.contains(&game.image_id),
+            "Game was proven by a guest outside the committed image IDs"
+        );
+        env::verify(game.image_id, &serde::to_vec(&game.output).unwrap()).unwrap();
+
+        // One leaf per game, so a game's inclusion proof is unambiguous
+        assert!(!game_ids.contains(&game.output.game_id), "Game aggregated twice");
+        game_ids.push(game.output.game_id);
+        leaves.push(GameLeaf::from_output(&game.output).hash());
+    }
+
+    env::commit(&AggregateOutput {
+        root: merkle_root(&leaves).expect("No games to aggregate"),
+        games: leaves.len() as u32,
+        pong_image_ids: input.pong_image_ids,
+    });
+}
@@ -0,0 +1,93 @@
+// Cycle bench: what taking a log's events in, and hashing them, costs the guest
+//
+// Not a release guest and not in the registry: host/tests/cycles_test.rs runs
+// it in the executor to compare the serde input path the full-log guest used
+// to take with the raw one it takes now, on the same events, and to time the
+// log hash on its own, with the accelerator and without it. The first input
+// word selects the path. The journal is
+// the cycles the path took, counted with `env::cycle_count`, then the hash it
+// computed, so the host can check the path saw every event.
+
+use std::hint::black_box;
+
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+use guest_code_for_zk_proof::types::*;
+
+/// Events as a serde `Vec<i64>`, copied 8 bytes each into the "PONGLOGv1" hash buffer (the old path)
+const PATH_SERDE: u32 = 0;
+/// Events as raw little-endian words, decoded and hashed where they lie (the current path)
+const PATH_RAW: u32 = 1;
+/// Raw events read first, then only `compute_log_hash` counted
+const PATH_HASH: u32 = 2;
+/// As `PATH_HASH`, with the unaccelerated sha2 crate
+const PATH_SOFT_HASH: u32 = 3;
+
+fn main() {
+    let mut path = [0u32; 1];
+    env::read_slice(&mut path);
+
+    let (cycles, hash) = match path[0] {
+        PATH_SERDE => measure(serde_path),
+        PATH_RAW => measure(raw_path),
+        PATH_HASH => {
+            let event_bytes = read_events();
+            measure(|| compute_log_hash(&event_bytes))
+        }
+        PATH_SOFT_HASH => {
+            let event_bytes = read_events();
+            measure(|| software_log_hash(&event_bytes))
+        }
+        _ => panic!("Unknown bench path"),
+    };
+
+    env::commit(&(cycles, hash));
+}
+
+/// Run `f`, returning the cycles it took and its hash
+fn measure(f: impl FnOnce() -> [u8; 32]) -> (u64, [u8; 32]) {
+    let start = env::cycle_count();
+    let hash = f();
+    (env::cycle_count() - start, hash)
+}
+
+fn serde_path() -> [u8; 32] {
+    let events: Vec<i64> = env::read();
+    let mut buf = Vec::with_capacity(9 + events.len() * 8);
+    buf.extend_from_slice(b"PONGLOGv1");
+    for e in &events {
+        buf.extend_from_slice(&e.to_le_bytes());
+    }
+    Sha256::digest(&buf).into()
+}
+
+fn read_events() -> Vec<u8> {
+    let mut events_len = [0u32; 1];
+    env::read_slice(&mut events_len);
+    let mut event_bytes = vec![0u8; events_len[0] as usize * EVENT_BYTES];
+    env::read_slice(&mut event_bytes);
+    event_bytes
+}
+
+fn raw_path() -> [u8; 32] {
+    let event_bytes = read_events();
+    // The replay decodes each event in the buffer as it reaches it
+    for bytes in event_bytes.chunks_exact(EVENT_BYTES) {
+        black_box(decode_event(bytes));
+    }
+    compute_log_hash(&event_bytes)
+}
+
+/// `compute_log_hash` in plain software SHA-256
+fn software_log_hash(event_bytes: &[u8]) -> [u8; 32] {
+    use sha2_soft::Digest as _;
+
+    let mut h = sha2_soft::Sha256::new();
+    h.update(core::LOG_HASH_PREFIX);
+    h.update(event_bytes);
+    let out = h.finalize();
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&out);
+    arr
+}
@@ -0,0 +1,36 @@
+// Rating guest: proves a batch of Elo updates to a ladder
+//
+// Commits the roots of the ladder state before and after the batch. Each
+// game's journal is verified by composition against the pong image ID it
+// claims, which must be one of the IDs this proof commits (see tournament.rs).
+
+use risc0_zkvm::{guest::env, serde};
+
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let input: LeaderboardInput = env::read();
+    let mut leaderboard = input.leaderboard;
+    let prev_root = leaderboard.root();
+
+    let mut games = Vec::with_capacity(input.games.len());
+    for rated in &input.games {
+        let game = &rated.game;
+        assert!(
+            input.pong_image_ids.contains(&game.image_id),
+            "Game was proven by a guest outside the committed image IDs"
+        );
+        env::verify(game.image_id, &serde::to_vec(&game.output).unwrap()).unwrap();
+        // Rejects games not played for their pairing, and games already
+        // rated (in an earlier batch or earlier in this one)
+        leaderboard.apply(&rated.pairing, &game.output).unwrap();
+        games.push(game.output.game_id);
+    }
+
+    env::commit(&LeaderboardOutput {
+        prev_root,
+        root: leaderboard.root(),
+        games,
+        pong_image_ids: input.pong_image_ids,
+    });
+}
@@ -0,0 +1,43 @@
+// Segment guest: replays a range of event pairs from a checkpoint
+//
+// Commits a SegmentOutput for the join guest to chain (see checkpoint.rs in
+// core). Inputs that could never be part of a valid chain abort the proof.
+
+use risc0_zkvm::guest::env;
+
+use guest_code_for_zk_proof::replay;
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let start: SegmentStart = env::read();
+    let mut events_len = [0u32; 1];
+    env::read_slice(&mut events_len);
+    let [events_len] = events_len;
+    assert!(events_len <= MAX_EVENTS, "Too many events (exceeds MAX_EVENTS limit)");
+    assert!(events_len.is_multiple_of(2), "Segments must cover whole event pairs");
+
+    let (resumed_from, mut checkpoint) = match start {
+        SegmentStart::New {
+            game_id,
+            log_version,
+            rules,
+        } => {
+            let physics = Physics::for_log_version(log_version).expect("Unsupported log version");
+            rules.scoring.validate().expect("Invalid scoring rules");
+            (None, replay::start(game_id, log_version, physics, rules))
+        }
+        SegmentStart::Resume(checkpoint) => (Some(checkpoint.digest()), checkpoint),
+    };
+    let physics = Physics::for_log_version(checkpoint.log_version).expect("Unsupported log version");
+
+    let mut event_bytes = vec![0u8; events_len as usize * EVENT_BYTES];
+    env::read_slice(&mut event_bytes);
+    replay::replay(&mut checkpoint, physics, &event_bytes);
+
+    env::commit(&SegmentOutput {
+        resumed_from,
+        end: checkpoint,
+        events_hash: compute_segment_hash(&event_bytes),
+        events_len,
+    });
+}
@@ -0,0 +1,54 @@
+// Session guest: validates every game of a multi-game session log in one run
+//
+// Reads the session header words, the event count of each game, then all
+// events back to back (see SessionInput in core). Each game is checked and
+// replayed exactly as the full-log guest would with its derived game ID.
+
+use risc0_zkvm::guest::env;
+
+use guest_code_for_zk_proof::replay::validate_log;
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let mut header = [0u32; SESSION_HEADER_WORDS];
+    env::read_slice(&mut header);
+    env::commit(&session(header));
+}
+
+fn session(header: [u32; SESSION_HEADER_WORDS]) -> SessionOutput {
+    let [session_seed, games, log_version, ..] = header;
+
+    // The game count is bounded before the event counts are read, and the
+    // event total before any events are read
+    let rules = match check_session_header(&header) {
+        Ok(rules) => rules,
+        Err(msg) => return SessionOutput::invalid(session_seed, msg),
+    };
+    let mut events_lens = vec![0u32; games as usize];
+    env::read_slice(&mut events_lens);
+    if let Err(msg) = check_session_events(&events_lens) {
+        return SessionOutput::invalid(session_seed, msg);
+    }
+
+    let total: u32 = events_lens.iter().sum();
+    let mut event_bytes = vec![0u8; total as usize * EVENT_BYTES];
+    env::read_slice(&mut event_bytes);
+
+    let mut outputs = Vec::with_capacity(games as usize);
+    let mut offset = 0;
+    for (index, &events_len) in events_lens.iter().enumerate() {
+        let bytes = &event_bytes[offset..offset + events_len as usize * EVENT_BYTES];
+        offset += bytes.len();
+
+        let mut game_header = [0u32; INPUT_HEADER_WORDS];
+        game_header[..3].copy_from_slice(&[session_game_id(session_seed, index as u32), events_len, log_version]);
+        game_header[3..].copy_from_slice(&header[3..]);
+        let out = match check_header(&game_header) {
+            Err(msg) => ValidateLogOutput::invalid(msg),
+            Ok((physics, rules)) => validate_log(bytes, None, game_header[0], log_version, physics, rules),
+        };
+        outputs.push(out);
+    }
+
+    SessionOutput::from_games(session_seed, log_version, rules, outputs)
+}
@@ -0,0 +1,32 @@
+// Tournament guest: proves the champion of a single-elimination bracket
+//
+// Each game's journal is verified by composition against the pong image ID it
+// claims, which must be one of the IDs this proof commits. The guest cannot
+// know the pong guest's image ID (it is built in the same package), so the
+// verifier of a tournament proof checks the committed IDs instead.
+
+use risc0_zkvm::{guest::env, serde};
+
+use guest_code_for_zk_proof::types::*;
+
+fn main() {
+    let input: TournamentInput = env::read();
+    let bracket = input.bracket;
+
+    let mut results = Vec::with_capacity(input.games.len());
+    for game in &input.games {
+        assert!(
+            input.pong_image_ids.contains(&game.image_id),
+            "Game was proven by a guest outside the committed image IDs"
+        );
+        env::verify(game.image_id, &serde::to_vec(&game.output).unwrap()).unwrap();
+        results.push(GameResult::from_output(&game.output, &bracket.rules).unwrap());
+    }
+    let champion = bracket.champion(&results).unwrap();
+
+    env::commit(&TournamentOutput {
+        bracket_hash: bracket.digest(),
+        champion,
+        pong_image_ids: input.pong_image_ids,
+    });
+}
@@ -2,6 +2,8 @@
 // These are hardcoded in both frontend and prover - must match exactly!
 // Physics is now fully deterministic based on event count - no seed needed
 
+use crate::fixed::{FRAC_BITS, MAX_SAFE};
+
 /// Game board width (pixels)
 pub const WIDTH: i32 = 800;
 
@@ -29,11 +31,21 @@ pub const SERVE_SPEED: i32 = 500;
 /// Speed increment per bounce (pixels/second)
 pub const SPEED_INCREMENT: i32 = 50;
 
+// Maximum ball speed: MAX_BALL_SPEED in core, applied by the physics of log v2
+
+/// Fastest ball an uncapped (log v1) replay follows (pixels/second)
+///
+/// Past it a velocity is no longer a safe i_mul operand (with 1% headroom for
+/// trig rounding, see the overflow bound in `fixed.rs`), so a v1 return that
+/// would speed the ball up beyond it rejects the log instead. That takes over
+/// 600 returns in one rally; v1 logs that stay below it replay exactly as
+/// they always have.
+pub const UNCAPPED_BALL_SPEED_LIMIT: i32 = ((MAX_SAFE * 100 / 101) >> FRAC_BITS) as i32;
+
 /// Maximum bounce angle off paddle (degrees)
 pub const MAX_BOUNCE_ANGLE_DEG: i32 = 60;
 
-/// Points needed to win the game
-pub const POINTS_TO_WIN: u32 = 3;
+// Points needed to win: see ScoringRules in core (classic: first to 3)
 
 /// Serve angle calculation - range of possible angles
 pub const ANGLE_RANGE: i32 = MAX_BOUNCE_ANGLE_DEG * 2 + 1; // 121 values (-60 to +60)
@@ -44,5 +56,29 @@ pub const SERVE_ANGLE_MULTIPLIER: i32 = 37; // Coprime with 121 for good distrib
 /// Initial serve direction: 1 = right, -1 = left
 pub const INITIAL_SERVE_DIRECTION: i32 = 1;
 
-/// Maximum number of events allowed in a game log (~5000 volleys max)
-pub const MAX_EVENTS: u32 = 10000;
+// Maximum number of events: MAX_EVENTS in core (10,000, ~5000 volleys max)
+
+// Overflow bound (see fixed.rs): checked at compile time so changing a
+// constant cannot silently make long rallies overflow.
+const _: () = {
+    use crate::types::{MAX_BALL_SPEED, MAX_EVENTS};
+
+    // Fastest ball, with 1% headroom for trig rounding, is a safe i_mul operand
+    // (a physics cap beyond the uncapped limit is clamped to it in replay.rs)
+    assert!(((UNCAPPED_BALL_SPEED_LIMIT as i64) << FRAC_BITS) * 101 / 100 < MAX_SAFE);
+    assert!(MAX_BALL_SPEED <= UNCAPPED_BALL_SPEED_LIMIT as u32);
+    assert!(SERVE_SPEED <= MAX_BALL_SPEED as i32);
+
+    // Slowest crossing: the full distance between contact planes, plus the
+    // ball radius a corner return (log v2) may start behind its contact plane,
+    // at the smallest horizontal speed (serve speed at the steepest angle, cos 60° = 1/2)
+    let contact_span = WIDTH - 2 * (PADDLE_MARGIN + PADDLE_WIDTH + BALL_RADIUS) + BALL_RADIUS;
+    let min_vx = SERVE_SPEED / 2;
+    assert!(MAX_BOUNCE_ANGLE_DEG <= 60);
+    let max_dt_secs = (contact_span / min_vx + 1) as i64;
+    assert!(max_dt_secs << FRAC_BITS < MAX_SAFE);
+
+    // Total game time over MAX_EVENTS events stays far inside i64 (and i_div's shift)
+    let max_t = (MAX_EVENTS as i64 / 2) * (max_dt_secs << FRAC_BITS);
+    assert!(max_t < MAX_SAFE);
+};
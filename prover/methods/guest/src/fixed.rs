@@ -17,6 +17,30 @@
 // - Division: checked_shl prevents shift overflow
 // - Addition/subtraction/shifts: Automatic panic on overflow via overflow-checks
 //
+// ## Overflow Bound
+// No sequence of up to MAX_EVENTS events can overflow any operation in
+// validate_log. Every i_mul operand is one of:
+// - a speed, velocity or velocity component: |v| <= UNCAPPED_BALL_SPEED_LIMIT
+//   (bounce caps the speed at the physics' MAX_BALL_SPEED, and an uncapped
+//   v1 replay stops before passing the limit; |sin|, |cos| <= 1 plus trig rounding)
+// - a time step: 0 < dt <= span / (SERVE_SPEED * cos 60°) ~ 3 s, since the
+//   ball is never slower than the serve and never steeper than 60° (corner
+//   returns in log v2 are clamped to 60° too, and start at most BALL_RADIUS
+//   further from the far paddle)
+// - a normalized offset or paddle speed fraction (<= 1), an angle (<= 60°,
+//   or <= 120° of spin) or a ±1 direction
+// so all operands stay below MAX_SAFE. Time accumulates at most
+// MAX_EVENTS / 2 steps (~15,000 s), and i_div only divides positions
+// (<= WIDTH) by velocities, or paddle velocities (<= 2 * PADDLE_MAX_SPEED,
+// enforced by the reachability check) by time, so its shift cannot overflow either.
+// Ruleset limits are u32 values shifted into Q16.16 (< 2^48) and only compared.
+// The swept corner test multiplies raw Q16.16 values within a ball radius of
+// the corner directly; those products stay below 2^56 (see swept_contact).
+// The edge test only multiplies a path length after checking it against the
+// path length to the back of the paddle, which is below BALL_RADIUS +
+// PADDLE_WIDTH over cos 60°.
+// constants.rs checks these inequalities at compile time.
+//
 // ## Determinism Guarantee
 // All operations are pure integer arithmetic with no floating-point.
 // This ensures bit-for-bit identical results across all platforms,
@@ -54,6 +78,9 @@ pub fn i_min(a: I, b: I) -> I { if a < b { a } else { b } }
 #[inline(always)]
 pub fn i_max(a: I, b: I) -> I { if a > b { a } else { b } }
 
+/// Largest operand magnitude `i_mul` accepts (see `i_mul`)
+pub const MAX_SAFE: i64 = 1i64 << 31;
+
 #[inline(always)]
 pub fn i_mul(a: I, b: I) -> I {
     // Q16.16 fixed-point multiplication with overflow protection
@@ -63,8 +90,6 @@ pub fn i_mul(a: I, b: I) -> I {
     // With validated game configs (speeds <= 10000, dimensions <= 10000):
     // - Max game value: ~10000 << 16 = ~655M (well below 2^31)
     // - This check catches malicious inputs while allowing all valid game states
-    const MAX_SAFE: i64 = 1i64 << 31;
-
     let a_abs = if a == I::MIN { I::MAX } else { i_abs(a) };
     let b_abs = if b == I::MIN { I::MAX } else { i_abs(b) };
 
@@ -83,6 +108,33 @@ pub fn i_div(a: I, b: I) -> I {
     shifted / b
 }
 
+/// Integer square root (floor) of a non-negative raw value
+///
+/// The square root of a Q32.32 value in raw units is its square root in
+/// Q16.16, so the swept collision test takes the root of a product of two
+/// Q16.16 values without rescaling.
+#[inline(always)]
+pub fn i_sqrt(n: I) -> I {
+    assert!(n >= 0, "Square root of a negative value");
+    // Bit-by-bit method: one iteration per result bit
+    let mut rem = n;
+    let mut root: I = 0;
+    let mut bit: I = 1 << 62;
+    while bit > rem {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
 // Reflection on [min_y, max_y]
 // Simulates ball bouncing between boundaries using modular arithmetic
 // Uses double-modulo approach for mathematically clean negative handling
@@ -106,12 +158,26 @@ pub fn reflect1d(y0: I, vy: I, dt: I, min_y: I, max_y: I) -> I {
     min_y + y
 }
 
+/// Vertical velocity at the end of the motion [`reflect1d`] replays:
+/// `vy`, negated when the ball is on a reflected leg of its path
+#[inline(always)]
+pub fn reflect1d_vy(y0: I, vy: I, dt: I, min_y: I, max_y: I) -> I {
+    let span = max_y - min_y;
+    if span <= 0 { return vy; }
+
+    let period = span << 1;
+    let mut y = y0 + i_mul(vy, dt) - min_y;
+    y = ((y % period) + period) % period;
+
+    if y > span { -vy } else { vy }
+}
+
 pub fn clamp_paddle_y(y: I, half: I, height: I) -> I {
     i_max(half, i_min(height - half, y))
 }
 
 // PI constant in Q16.16 (π ≈ 3.14159265359 × 65536 ≈ 205887)
-pub const PI_Q16: I = 205887i64;
+pub use core::PI_Q16;
 
 // Angles in radians in Q16.16 using integer-only math
 #[inline(always)]
@@ -0,0 +1,8 @@
+// Pong log validation, shared by the guest programs built from this package:
+// the full-log guest (main.rs) and the segment guest (bin/replay_segment.rs)
+
+pub mod constants;
+pub mod fixed;
+pub mod physics;
+pub mod replay;
+pub mod types;
@@ -1,69 +1,27 @@
 use crate::fixed::*;
+use crate::types::{cordic_sin_cos, table_sin_cos, Collision, FixState, Trig};
 
-// CORDIC sin/cos with ITER=8 (optimized for performance)
-// 8 iterations provides ~0.23° accuracy, sufficient for game physics
-// Constants in Q16.16 format for i64 fixed-point
-const ITER: usize = 8;
-const ATAN_Q16: [I; ITER] = [
-    51472,   // atan(2^0)  = 45°     in Q16.16
-    30386,   // atan(2^-1) = 26.565° in Q16.16
-    16055,   // atan(2^-2) = 14.036° in Q16.16
-    8150,    // atan(2^-3) = 7.125°  in Q16.16
-    4091,    // atan(2^-4) = 3.576°  in Q16.16
-    2047,    // atan(2^-5) = 1.790°  in Q16.16
-    1024,    // atan(2^-6) = 0.895°  in Q16.16
-    512,     // atan(2^-7) = 0.448°  in Q16.16
-];
-const K_Q16: I = 39797;  // CORDIC gain ~0.6073 in Q16.16
-
+/// Angle in the unit `trig` works in: Q16.16 radians for CORDIC, Q16.16 degrees for the table
 #[inline(always)]
-pub fn cordic_sin_cos(angle: I) -> (I, I) {
-    // Validate input angle is reasonable (±4π is more than sufficient for game physics)
-    const MAX_ANGLE: I = PI_Q16 * 8;
-    assert!(
-        angle.abs() < MAX_ANGLE,
-        "CORDIC: angle out of valid range (|angle| must be < 8π)"
-    );
-
-    let atan = ATAN_Q16;
-    let mut x = K_Q16;
-    let mut y: I = 0;
-    let mut z = angle;
-    for i in 0..ITER {
-        let di: I = if z >= 0 { 1 } else { -1 };
-        let shift = i as i32;
-        let x_shift = x >> shift;
-        let y_shift = y >> shift;
-
-        // CORDIC rotation step
-        // These multiplications cannot overflow: di is ±1, values are small after shifts
-        // Overflow protection provided by Cargo.toml: overflow-checks = true
-        let x_term = di * y_shift;
-        let y_term = di * x_shift;
-        let z_term = di * atan[i];
-
-        x = x - x_term;
-        y = y + y_term;
-        z = z - z_term;
+pub fn angle_from_deg(trig: Trig, d: i32) -> I {
+    match trig {
+        Trig::Cordic => deg_to_rad_fixed(d),
+        Trig::Table => to_fixed_int(d as i64),
     }
-    (y, x)
 }
 
-#[derive(Clone, Copy)]
-pub struct FixState {
-    pub t0: I,
-    pub x: I,
-    pub y: I,
-    pub vx: I,
-    pub vy: I,
-    pub speed: I,
-    pub left_y: I,
-    pub right_y: I,
-    pub dir: i32, // -1 or +1
+/// (sin, cos) of an angle given in the unit of `trig` (see [`angle_from_deg`])
+#[inline(always)]
+pub fn sin_cos(trig: Trig, angle: I) -> (I, I) {
+    match trig {
+        Trig::Cordic => cordic_sin_cos(angle),
+        Trig::Table => table_sin_cos(angle),
+    }
 }
 
 #[inline(always)]
 pub fn serve(
+    trig: Trig,
     receiver_dir: i32,
     t0: I,
     width: I,
@@ -85,8 +43,8 @@ pub fn serve(
     // SECURITY: Prevent overflow in angle calculation with wrapping arithmetic
     let volley_i32 = (entropy_mix.wrapping_mul(serve_angle_multiplier)).rem_euclid(angle_range);
     let angle_raw = volley_i32 - max_bounce_angle_deg;
-    let angle = deg_to_rad_fixed(angle_raw);
-    let (sinv, cosv) = cordic_sin_cos(angle);
+    let angle = angle_from_deg(trig, angle_raw);
+    let (sinv, cosv) = sin_cos(trig, angle);
     let vx = i_mul(serve_speed, i_mul(cosv, to_fixed_int(receiver_dir as i64)));
     let vy = i_mul(serve_speed, sinv);
     FixState {
@@ -104,14 +62,23 @@ pub fn serve(
 
 #[inline(always)]
 pub fn bounce(
+    trig: Trig,
+    collision: Collision,
     s: &FixState,
     paddle_y: I,
     half: I,
     ball_radius: I,
     max_bounce_angle: I,
+    english: I,
     speed_increment: I,
+    max_ball_speed: I,
 ) -> (I, I, I, i32) {
-    let limit = half + ball_radius;
+    // Swept collision sends corner strikes through `bounce_corner`, so the
+    // full bounce angle is reached at the ends of the face itself
+    let limit = match collision {
+        Collision::Face => half + ball_radius,
+        Collision::Swept => half,
+    };
 
     // Guard against division by zero (should be prevented by config validation)
     if limit <= 0 {
@@ -123,14 +90,218 @@ pub fn bounce(
     if offset > limit { offset = limit; }
 
     let norm = i_div(offset, limit);
-    let angle = i_max(-max_bounce_angle, i_min(max_bounce_angle, i_mul(norm, max_bounce_angle)));
+    // Spin (english) adds to the offset angle; the sum stays within the maximum
+    let angle = i_max(-max_bounce_angle, i_min(max_bounce_angle, i_mul(norm, max_bounce_angle) + english));
 
-    let new_speed = s.speed + speed_increment;
+    // Capped so arbitrarily long rallies stay within the overflow bound (see fixed.rs)
+    let new_speed = i_min(s.speed + speed_increment, max_ball_speed);
     let new_dir = if s.dir < 0 { 1 } else { -1 };
 
-    let (sinv, cosv) = cordic_sin_cos(angle);
+    let (sinv, cosv) = sin_cos(trig, angle);
     let vx = i_mul(new_speed, i_mul(cosv, to_fixed_int(new_dir as i64)));
     let vy = i_mul(new_speed, sinv);
 
     (vx, vy, new_speed, new_dir)
 }
+
+/// Outcome of the swept hit test (log v2) when the ball reaches the contact plane
+pub enum PaddleContact {
+    /// Ball passes the paddle without touching it
+    Miss,
+    /// Ball hits the face; deflected by `bounce`
+    Face,
+    /// Ball strikes a front corner and is knocked back into play
+    Corner(PaddleHit),
+    /// Ball glances off a corner or a top/bottom edge and is deflected on
+    /// toward the goal line
+    Glance(PaddleHit),
+}
+
+/// Where the ball strikes a paddle corner or edge and the direction it leaves in
+pub struct PaddleHit {
+    /// Ball centre at contact
+    pub x: I,
+    pub y: I,
+    /// Time from the contact plane to the contact
+    pub dt: I,
+    /// Unit direction after the deflection
+    pub ux: I,
+    pub uy: I,
+}
+
+/// Swept hit test of the ball circle against the paddle rectangle
+///
+/// `s` is the ball at the contact plane and `vy` its vertical velocity there
+/// (after any wall reflections). The paddle is held at its logged position
+/// while the ball sweeps past it.
+///
+/// A ball centre within `half` of the paddle centre hits the face. Otherwise
+/// the ball's path is tested against the nearer top/bottom edge (from the
+/// front corner back `paddle_width`) and its two corners, and the first
+/// contact along the path wins. A corner reflects the ball about the contact
+/// normal and an edge reverses its vertical direction. Only a reflection back
+/// toward the field returns the ball; anything else is a glancing blow that
+/// still goes past the paddle.
+#[inline(always)]
+pub fn swept_contact(
+    s: &FixState,
+    vy: I,
+    paddle_y: I,
+    half: I,
+    ball_radius: I,
+    face_x: I,
+    paddle_width: I,
+) -> PaddleContact {
+    let offset = s.y - paddle_y;
+    if i_abs(offset) <= half {
+        return PaddleContact::Face;
+    }
+
+    // Nearer edge; the back of the paddle is further along the direction of travel
+    let above = offset < 0;
+    let edge_y = if above { paddle_y - half } else { paddle_y + half };
+    let back_x = if s.dir < 0 { face_x - paddle_width } else { face_x + paddle_width };
+
+    // Unit direction of travel
+    let ux = i_div(s.vx, s.speed);
+    let uy = i_div(vy, s.speed);
+
+    // Path length to each contact, with the direction the ball leaves in
+    let front = corner_contact(s, ux, uy, face_x, edge_y, ball_radius);
+    let rear = corner_contact(s, ux, uy, back_x, edge_y, ball_radius);
+    let edge = edge_contact(s, ux, uy, face_x, back_x, edge_y, above, ball_radius)
+        .map(|dist| (dist, ux, -uy));
+
+    let mut first: Option<(I, I, I)> = None;
+    let mut returned = false;
+    for (contact, is_front) in [(front, true), (edge, false), (rear, false)] {
+        if let Some((dist, rx, ry)) = contact {
+            if first.map_or(true, |(best, _, _)| dist < best) {
+                first = Some((dist, rx, ry));
+                returned = is_front;
+            }
+        }
+    }
+    let Some((dist, rx, ry)) = first else {
+        return PaddleContact::Miss;
+    };
+
+    // A reflection that still heads for the goal line is a glancing blow
+    let new_dir = if s.dir < 0 { 1 } else { -1 };
+    let hit = PaddleHit {
+        x: s.x + i_mul(dist, ux),
+        y: s.y + i_mul(dist, uy),
+        dt: i_div(dist, s.speed),
+        ux: rx,
+        uy: ry,
+    };
+    if returned && rx * new_dir as I > 0 {
+        PaddleContact::Corner(hit)
+    } else {
+        PaddleContact::Glance(hit)
+    }
+}
+
+/// Path length to where the ball first touches the corner at (`cx`, `cy`),
+/// and its unit direction after reflecting about the contact normal
+#[inline(always)]
+fn corner_contact(s: &FixState, ux: I, uy: I, cx: I, cy: I, ball_radius: I) -> Option<(I, I, I)> {
+    // Corner relative to the ball centre
+    let wx = cx - s.x;
+    let wy = cy - s.y;
+
+    // Distance along the path to the corner's closest approach, and how far
+    // the path passes from the corner there
+    let along = i_mul(wx, ux) + i_mul(wy, uy);
+    let across = i_mul(ux, wy) - i_mul(uy, wx);
+    if along <= 0 || i_abs(across) > ball_radius {
+        return None;
+    }
+
+    // First point on the path a ball radius from the corner. Squares of
+    // Q16.16 values are Q32.32, and i_sqrt brings them back to Q16.16.
+    let r2 = ball_radius * ball_radius;
+    let dist = along - i_sqrt(r2 - across * across);
+    if dist < 0 {
+        return None;
+    }
+
+    // Contact normal scaled by ball_radius (corner to ball centre)
+    let mx = i_mul(dist, ux) - wx;
+    let my = i_mul(dist, uy) - wy;
+
+    // Reflect: u' = u - 2 (u·m) m / r². Every operand is below 2^19 and the
+    // products below 2^56, so this is exact in i64.
+    let u_dot_m = ux * mx + uy * my;
+    let rx = (ux * r2 - 2 * u_dot_m * mx) / r2;
+    let ry = (uy * r2 - 2 * u_dot_m * my) / r2;
+    Some((dist, rx, ry))
+}
+
+/// Path length to where the ball lands on the edge at `edge_y` between its
+/// front and back corners, if it does
+///
+/// The ball centre must come down (or up) to a ball radius from the edge's
+/// line while it is over the edge. The path length to the back corner is
+/// found from the horizontal direction first (at least cos 60°), so a
+/// nearly flat path never divides out to a length beyond the i_mul bound.
+#[inline(always)]
+fn edge_contact(
+    s: &FixState,
+    ux: I,
+    uy: I,
+    face_x: I,
+    back_x: I,
+    edge_y: I,
+    above: bool,
+    ball_radius: I,
+) -> Option<I> {
+    // Gap between the ball and the edge's line, and how fast the path closes it
+    let (gap, closing) = if above {
+        (edge_y - ball_radius - s.y, uy)
+    } else {
+        (s.y - edge_y - ball_radius, -uy)
+    };
+    if gap < 0 || closing <= 0 {
+        return None;
+    }
+
+    let to_front = i_div(face_x - s.x, ux);
+    let to_back = i_div(back_x - s.x, ux);
+    let dist = i_div(gap, closing);
+    if dist < to_front || dist > to_back {
+        return None;
+    }
+    Some(dist)
+}
+
+/// Bounce off a paddle corner (log v2)
+///
+/// The ball leaves along the reflected direction, limited to the face's
+/// maximum bounce angle so the horizontal speed stays within the overflow
+/// bound in fixed.rs. Speed increases as for a face hit.
+#[inline(always)]
+pub fn bounce_corner(
+    trig: Trig,
+    s: &FixState,
+    hit: &PaddleHit,
+    max_bounce_angle: I,
+    speed_increment: I,
+    max_ball_speed: I,
+) -> (I, I, I, i32) {
+    let new_speed = i_min(s.speed + speed_increment, max_ball_speed);
+    let new_dir = if s.dir < 0 { 1 } else { -1 };
+
+    let (sin_max, cos_max) = sin_cos(trig, max_bounce_angle);
+    let (ux, uy) = if i_abs(hit.ux) < cos_max {
+        let uy = if hit.uy < 0 { -sin_max } else { sin_max };
+        (i_mul(cos_max, to_fixed_int(new_dir as i64)), uy)
+    } else {
+        (hit.ux, hit.uy)
+    };
+
+    let vx = i_mul(new_speed, ux);
+    let vy = i_mul(new_speed, uy);
+
+    (vx, vy, new_speed, new_dir)
+}
@@ -0,0 +1,383 @@
+// Log replay, shared by the full-log and segment guests
+//
+// A replay starts from a Checkpoint (the opening serve, or where an earlier
+// segment stopped), runs through some event pairs, and finish() turns the
+// checkpoint after the last pair into the public output. Validating a whole
+// log in one proof is the same three steps over all of its pairs.
+
+use crate::constants::*;
+use crate::fixed::*;
+use crate::physics::*;
+use crate::types::*;
+
+/// Validate a whole log (header already accepted by `check_header`)
+///
+/// The log commitment is salted with `salt`, if given.
+pub fn validate_log(
+    event_bytes: &[u8],
+    salt: Option<&[u8; LOG_SALT_BYTES]>,
+    game_id: u32,
+    log_version: u32,
+    physics: Physics,
+    rules: Ruleset,
+) -> ValidateLogOutput {
+    let mut checkpoint = start(game_id, log_version, physics, rules);
+    replay(&mut checkpoint, physics, event_bytes);
+
+    // Build commitment / hash of events for binding (the exact bytes read from the host)
+    let events_len = (event_bytes.len() / EVENT_BYTES) as u32;
+    finish(checkpoint, events_len, log_commitment(salt, event_bytes))
+}
+
+/// Replay state at the opening serve
+pub fn start(game_id: u32, log_version: u32, physics: Physics, rules: Ruleset) -> Checkpoint {
+    let state = serve(
+        physics.trig,
+        INITIAL_SERVE_DIRECTION,
+        to_fixed_int(0),
+        to_fixed_int(WIDTH as i64),
+        to_fixed_int(HEIGHT as i64),
+        to_fixed_int(SERVE_SPEED as i64),
+        MAX_BOUNCE_ANGLE_DEG,
+        ANGLE_RANGE,
+        SERVE_ANGLE_MULTIPLIER,
+        0,
+        game_id,
+    );
+
+    Checkpoint {
+        game_id,
+        log_version,
+        rules,
+        pairs: 0,
+        state,
+        // Paddles start at rest, with a zero-length "previous interval"
+        prev_v_l: 0,
+        prev_v_r: 0,
+        prev_dt: 0,
+        score: MatchScore::new(rules.scoring),
+        valid_pairs: 0,
+        stopped: None,
+        violator: None,
+    }
+}
+
+/// Replay the whole event pairs in `event_bytes` on from `checkpoint`
+///
+/// Pairs after the match is decided, or after a violation, are consumed
+/// without being replayed (they are still part of the log hash).
+pub fn replay(checkpoint: &mut Checkpoint, physics: Physics, event_bytes: &[u8]) {
+    let trig = physics.trig;
+    let Checkpoint {
+        game_id,
+        rules,
+        pairs,
+        state,
+        prev_v_l,
+        prev_v_r,
+        prev_dt,
+        score,
+        valid_pairs,
+        stopped,
+        violator,
+        ..
+    } = checkpoint;
+    let game_id = *game_id;
+
+    // Use hardcoded constants for all config values
+    let width = to_fixed_int(WIDTH as i64);
+    let height = to_fixed_int(HEIGHT as i64);
+    let ball_radius = to_fixed_int(BALL_RADIUS as i64);
+    let paddle_height = to_fixed_int(PADDLE_HEIGHT as i64);
+    let paddle_width = to_fixed_int(PADDLE_WIDTH as i64);
+    let paddle_margin = to_fixed_int(PADDLE_MARGIN as i64);
+    let paddle_max_speed = to_fixed_int(PADDLE_MAX_SPEED as i64);
+    let serve_speed = to_fixed_int(SERVE_SPEED as i64);
+    let speed_increment = to_fixed_int(SPEED_INCREMENT as i64);
+    // Returns stop speeding the ball up at the physics' cap. Without one (log v1)
+    // a return past the fixed-point limit rejects the log instead.
+    let max_ball_speed = match physics.max_ball_speed {
+        Some(cap) => to_fixed_int(cap.min(UNCAPPED_BALL_SPEED_LIMIT as u32) as i64),
+        None => to_fixed_int(UNCAPPED_BALL_SPEED_LIMIT as i64),
+    };
+
+    // Optional human-play rules (a zero limit disables the check).
+    // Plain shifts of u32 values stay below 2^48, so any ruleset is safe here.
+    let max_paddle_accel = to_fixed_int(rules.max_paddle_accel as i64);
+    let reaction_delay = to_fixed_int(rules.reaction_ms as i64) / 1000;
+    // Spin beyond twice the maximum bounce angle saturates every bounce
+    // anyway; clamping it keeps the angle a safe i_mul operand.
+    let spin_angle = angle_from_deg(trig, rules.spin_deg.min(2 * MAX_BOUNCE_ANGLE_DEG as u32) as i32);
+    // Game clock limit for timed matches (Q16.16 seconds, 0 = untimed)
+    let time_limit = rules.scoring.time_limit as I;
+    // In the unit the log version's trig works in (radians for v1, degrees for v2)
+    let max_bounce_angle = angle_from_deg(trig, MAX_BOUNCE_ANGLE_DEG);
+
+    let y_min = ball_radius;
+    let y_max = height - ball_radius;
+    let left_face = paddle_margin + paddle_width;
+    let right_face = width - (paddle_margin + paddle_width);
+    let half = i_div(paddle_height, to_fixed_int(2));
+    let pad_ball = half + ball_radius;
+    let left_contact_x = left_face + ball_radius;
+    let right_contact_x = right_face - ball_radius;
+
+    // Event count already bounded by MAX_EVENTS in check_header
+    for pair in event_bytes.chunks_exact(2 * EVENT_BYTES) {
+        *pairs += 1; // Two events (L, R) per pair; serve entropy counts events processed
+        if score.is_over() || stopped.is_some() {
+            continue;
+        }
+        let l_i = decode_event(&pair[..EVENT_BYTES]);
+        let r_i = decode_event(&pair[EVENT_BYTES..]);
+
+        // Compute time to paddle plane
+        let target_x = if state.dir < 0 { left_contact_x } else { right_contact_x };
+
+        // Guard against division by zero (should be prevented by config validation)
+        if state.vx == 0 {
+            *stopped = Some(REASON_ZERO_VELOCITY.into());
+            continue;
+        }
+
+        let dt_to_paddle = i_div(target_x - state.x, state.vx);
+        if !(dt_to_paddle > 0) {
+            *stopped = Some(REASON_INVALID_KINEMATICS.into());
+            continue;
+        }
+
+        // Addition overflow protection provided by overflow-checks = true
+        // Time is bounded by MAX_EVENTS / 2 steps of at most ~3 s (see the overflow bound in fixed.rs)
+        let t_hit = state.t0 + dt_to_paddle;
+
+        // Timed match: the clock runs out before this contact, so the point in
+        // play is not counted (unless a tie sends the match to a golden point)
+        if time_limit > 0 && t_hit > time_limit && !score.clock_expired() && score.expire_clock() {
+            continue;
+        }
+
+        let y_at_hit = reflect1d(state.y, state.vy, dt_to_paddle, y_min, y_max);
+
+        // Reachability
+        let dt = t_hit - state.t0;
+        let max_delta = i_mul(paddle_max_speed, dt);
+        let d_l = i_abs(l_i - state.left_y);
+        let d_r = i_abs(r_i - state.right_y);
+        if d_l > max_delta || d_r > max_delta {
+            *stopped = Some(REASON_PADDLE_TOO_FAST.into());
+            *violator = Violator::of(d_l > max_delta, d_r > max_delta);
+            continue;
+        }
+        // Bounds clamp check
+        let clamp_l = clamp_paddle_y(l_i, half, height);
+        let clamp_r = clamp_paddle_y(r_i, half, height);
+        if clamp_l != l_i || clamp_r != r_i {
+            *stopped = Some(REASON_PADDLE_OUT_OF_BOUNDS.into());
+            *violator = Violator::of(clamp_l != l_i, clamp_r != r_i);
+            continue;
+        }
+
+        // Reaction delay: the receiving paddle may only start moving once the
+        // delay after the last serve/bounce has passed
+        if reaction_delay > 0 {
+            let d_receiver = if state.dir < 0 { d_l } else { d_r };
+            let moving_time = i_max(0, dt - reaction_delay);
+            if d_receiver > i_mul(paddle_max_speed, moving_time) {
+                *stopped = Some(REASON_REACTION_DELAY.into());
+                *violator = Violator::of(state.dir < 0, state.dir >= 0);
+                continue;
+            }
+        }
+
+        // Acceleration: change in average velocity between consecutive
+        // intervals over the time between their midpoints. Velocities are
+        // bounded by the reachability check above, so the divisions cannot overflow.
+        if max_paddle_accel > 0 {
+            let v_l = i_div(l_i - state.left_y, dt);
+            let v_r = i_div(r_i - state.right_y, dt);
+            let midpoint_dt = i_max(1, (*prev_dt + dt) / 2);
+            let a_l = i_abs(i_div(v_l - *prev_v_l, midpoint_dt));
+            let a_r = i_abs(i_div(v_r - *prev_v_r, midpoint_dt));
+            if a_l > max_paddle_accel || a_r > max_paddle_accel {
+                *stopped = Some(REASON_PADDLE_ACCEL.into());
+                *violator = Violator::of(a_l > max_paddle_accel, a_r > max_paddle_accel);
+                continue;
+            }
+            *prev_v_l = v_l;
+            *prev_v_r = v_r;
+            *prev_dt = dt;
+        }
+
+        // Hit/miss in integer domain with cast only for comparison radius bounds
+        let moving_left = state.dir < 0;
+        let contact = if moving_left { l_i } else { r_i };
+        let vy_at_hit = reflect1d_vy(state.y, state.vy, dt_to_paddle, y_min, y_max);
+
+        // Spin: english from the receiving paddle's average velocity over the
+        // interval, as a fraction of PADDLE_MAX_SPEED (at most 1 by the reachability check)
+        let english = if spin_angle > 0 {
+            let receiver_dy = if moving_left { l_i - state.left_y } else { r_i - state.right_y };
+            i_mul(i_div(i_div(receiver_dy, dt), paddle_max_speed), spin_angle)
+        } else {
+            0
+        };
+
+        // Advance kinematics to t_hit
+        state.x = if moving_left { left_contact_x } else { right_contact_x };
+        state.y = y_at_hit;
+        state.t0 = t_hit;
+        state.left_y = l_i;
+        state.right_y = r_i;
+
+        let hit = match physics.collision {
+            Collision::Face => {
+                if i_abs(contact - y_at_hit) <= pad_ball { PaddleContact::Face } else { PaddleContact::Miss }
+            }
+            Collision::Swept => {
+                let face_x = if moving_left { left_face } else { right_face };
+                swept_contact(state, vy_at_hit, contact, half, ball_radius, face_x, paddle_width)
+            }
+        };
+
+        // An uncapped return past the fixed-point limit ends the replay (no
+        // paddle broke a rule, so the pair is not part of a valid prefix)
+        let returned = matches!(hit, PaddleContact::Face | PaddleContact::Corner(_));
+        if returned && physics.max_ball_speed.is_none() && state.speed + speed_increment > max_ball_speed {
+            *stopped = Some(REASON_BALL_TOO_FAST.into());
+            continue;
+        }
+        *valid_pairs += 1;
+
+        if let PaddleContact::Corner(corner) = &hit {
+            // Ball leaves from the corner contact, slightly past the contact plane
+            let (vx, vy, speed, dir) = bounce_corner(
+                trig,
+                state,
+                corner,
+                max_bounce_angle,
+                speed_increment,
+                max_ball_speed,
+            );
+            state.x = corner.x;
+            state.y = corner.y;
+            state.t0 = t_hit + corner.dt;
+            state.vx = vx;
+            state.vy = vy;
+            state.speed = speed;
+            state.dir = dir;
+        } else if let PaddleContact::Face = hit {
+            // Bounce
+            let contact_y = contact;
+            let (vx, vy, speed, dir) = bounce(
+                trig,
+                physics.collision,
+                state,
+                contact_y,
+                half,
+                ball_radius,
+                max_bounce_angle,
+                english,
+                speed_increment,
+                max_ball_speed,
+            );
+            state.vx = vx;
+            state.vy = vy;
+            state.speed = speed;
+            state.dir = dir;
+        } else {
+            // A glance deflects the ball but it still carries on to the goal line
+            if score.point(!moving_left) {
+                continue;
+            }
+            // Serve toward the scorer, or alternating, per the scoring rules
+            let scorer_dir = if moving_left { 1 } else { -1 };
+            let receiver_dir = score.serve_dir(scorer_dir, INITIAL_SERVE_DIRECTION);
+            let mut next = serve(
+                trig,
+                receiver_dir,
+                state.t0,
+                width,
+                height,
+                serve_speed,
+                MAX_BOUNCE_ANGLE_DEG,
+                ANGLE_RANGE,
+                SERVE_ANGLE_MULTIPLIER,
+                *pairs * 2,
+                game_id,
+            );
+            next.left_y = state.left_y;
+            next.right_y = state.right_y;
+            *state = next;
+        }
+    }
+}
+
+/// Public output for a log whose replay ended at `checkpoint`
+///
+/// `events_len` and `hash` describe the whole log, including any torn final
+/// pair that was not replayed.
+pub fn finish(checkpoint: Checkpoint, events_len: u32, hash: [u8; 32]) -> ValidateLogOutput {
+    let Checkpoint {
+        game_id,
+        log_version,
+        rules,
+        state,
+        mut score,
+        valid_pairs,
+        stopped,
+        violator,
+        ..
+    } = checkpoint;
+    let time_limit = rules.scoring.time_limit as I;
+
+    // A timed log may stop at the deadline with the ball still in play: the
+    // clock has run out if the next contact would come after it
+    if !score.is_over() && stopped.is_none() && time_limit > 0 && !score.clock_expired() {
+        let ball_radius = to_fixed_int(BALL_RADIUS as i64);
+        let face_offset = to_fixed_int((PADDLE_MARGIN + PADDLE_WIDTH) as i64);
+        let target_x = if state.dir < 0 {
+            face_offset + ball_radius
+        } else {
+            to_fixed_int(WIDTH as i64) - face_offset - ball_radius
+        };
+        if state.t0 + i_div(target_x - state.x, state.vx) > time_limit {
+            score.expire_clock();
+        }
+    }
+
+    // Forfeit mode: an undecided match with a valid prefix was abandoned,
+    // at the score it stood at when the log stopped or turned invalid. The
+    // output is fair for that prefix only; a violation after it is recorded
+    // with the side that broke the rule.
+    if !score.is_over() && rules.scoring.forfeit && valid_pairs > 0 {
+        score.abandon();
+    }
+    if let Some(msg) = &stopped {
+        if !score.is_over() {
+            return ValidateLogOutput::invalid(msg);
+        }
+    }
+
+    // Validate final score - the match must have a winner (or a draw at the
+    // clock). The replay stops at the winning point, and a game is only won
+    // with a lead, so scores beyond the target cannot occur.
+    if score.clock_expired() && !score.is_over() {
+        return ValidateLogOutput::invalid(REASON_GOLDEN_POINT_MISSING);
+    }
+    if !score.is_over() {
+        // Classic matches keep the reason the baseline guest committed
+        if rules.scoring == ScoringRules::CLASSIC {
+            return ValidateLogOutput::invalid(REASON_NO_WINNER);
+        }
+        return ValidateLogOutput::invalid(REASON_MATCH_NOT_WON);
+    }
+
+    let abandoned = score.end_reason() == Some(EndReason::Abandoned);
+    let mut out = ValidateLogOutput::ok(score, events_len, hash, game_id, log_version, rules);
+    if abandoned {
+        out.reason = stopped;
+        out.violator = violator;
+        out.last_valid_pair = Some(valid_pairs - 1);
+    }
+    out
+}
@@ -1,2 +1,12 @@
 // Re-export shared types from core crate
-pub use core::{compute_log_hash, ValidateLogInput, ValidateLogOutput};
+pub use core::{
+    check_disclosure, check_header, check_session_events, check_session_header, compute_log_hash, compute_segment_hash,
+    cordic_sin_cos, decode_event, log_commitment, merkle_root, salt_from_bytes, session_game_id, table_sin_cos,
+    AbiJournal, AggregateInput,
+    AggregateOutput, Checkpoint, Collision, DisclosedOutput, Disclosure, EndReason, FixState, GameLeaf, GameResult, LeaderboardInput,
+    LeaderboardOutput, MatchScore, Physics, Ruleset, ScoringRules, SegmentOutput, SegmentStart, SessionOutput, Trig, TournamentInput,
+    TournamentOutput, ValidateLogOutput, Violator, EVENT_BYTES, INPUT_HEADER_WORDS, LOG_SALT_BYTES, MAX_BALL_SPEED,
+    MAX_EVENTS, REASON_BALL_TOO_FAST, REASON_GOLDEN_POINT_MISSING, REASON_INVALID_DISCLOSURE, REASON_INVALID_KINEMATICS,
+    REASON_MATCH_NOT_WON, REASON_NO_WINNER, REASON_PADDLE_ACCEL, REASON_PADDLE_OUT_OF_BOUNDS, REASON_PADDLE_TOO_FAST, REASON_REACTION_DELAY, REASON_ZERO_VELOCITY,
+    SESSION_HEADER_WORDS,
+};
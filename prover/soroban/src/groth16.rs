@@ -0,0 +1,114 @@
+// RISC Zero Groth16 receipt verification on the Soroban BN254 host functions
+//
+// A Groth16 receipt proves a claim digest under a control root. The circuit
+// has five public inputs: the control root and the claim digest, each split
+// into two 128-bit halves, and the BN254 control ID. This mirrors
+// `Groth16Receipt::verify_integrity` and RISC Zero's Groth16Verifier.sol.
+
+use crate::verifying_key::{ALPHA, BETA, DELTA, GAMMA, IC};
+use soroban_sdk::crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr};
+use soroban_sdk::{vec, Bytes, BytesN, Env};
+
+/// Length of a Groth16 seal: `a` (G1), `b` (G2) and `c` (G1)
+pub const SEAL_LEN: u32 = 256;
+
+fn sha256(env: &Env, bytes: &Bytes) -> [u8; 32] {
+    env.crypto().sha256(bytes).to_array()
+}
+
+/// risc0 `tagged_struct`: SHA-256 over the tag's digest, the child digests,
+/// the data words (little-endian) and the number of children
+fn tagged_struct(env: &Env, tag: &str, down: &[[u8; 32]], data: &[u32]) -> [u8; 32] {
+    let mut all = Bytes::from_array(env, &sha256(env, &Bytes::from_slice(env, tag.as_bytes())));
+    for digest in down {
+        all.extend_from_array(digest);
+    }
+    for word in data {
+        all.extend_from_array(&word.to_le_bytes());
+    }
+    all.extend_from_array(&(down.len() as u16).to_le_bytes());
+    sha256(env, &all)
+}
+
+/// Digest of the claim that a guest with `image_id` halted normally with `journal`
+///
+/// The same as `ReceiptClaim::ok(image_id, journal).digest()`: no input, a
+/// halted post state, exit code `(0, 0)` and no assumptions.
+pub fn claim_digest(env: &Env, image_id: &BytesN<32>, journal: &Bytes) -> BytesN<32> {
+    let zero = [0u8; 32];
+    let post = tagged_struct(env, "risc0.SystemState", &[zero], &[0]);
+    let output = tagged_struct(env, "risc0.Output", &[sha256(env, journal), zero], &[]);
+    let claim = tagged_struct(
+        env,
+        "risc0.ReceiptClaim",
+        &[zero, image_id.to_array(), post, output],
+        &[0, 0],
+    );
+    BytesN::from_array(env, &claim)
+}
+
+/// Scalar of a digest half: the 16 bytes read as a little-endian integer
+fn half(env: &Env, bytes: &[u8]) -> Fr {
+    let mut be = [0u8; 32];
+    for (i, b) in bytes.iter().rev().enumerate() {
+        be[16 + i] = *b;
+    }
+    Fr::from_bytes(BytesN::from_array(env, &be))
+}
+
+fn g1(env: &Env, bytes: &[u8]) -> Bn254G1Affine {
+    let mut point = [0u8; 64];
+    point.copy_from_slice(bytes);
+    Bn254G1Affine::from_bytes(BytesN::from_array(env, &point))
+}
+
+fn g2(env: &Env, bytes: &[u8]) -> Bn254G2Affine {
+    let mut point = [0u8; 128];
+    point.copy_from_slice(bytes);
+    Bn254G2Affine::from_bytes(BytesN::from_array(env, &point))
+}
+
+/// Whether `seal` proves `claim` under the given control root and BN254 control ID
+///
+/// Returns false for a seal of the wrong length. Points that are not on the
+/// curve abort the invocation in the host functions.
+pub fn verify(
+    env: &Env,
+    seal: &Bytes,
+    claim: &BytesN<32>,
+    control_root: &BytesN<32>,
+    bn254_control_id: &BytesN<32>,
+) -> bool {
+    if seal.len() != SEAL_LEN {
+        return false;
+    }
+    let mut proof = [0u8; SEAL_LEN as usize];
+    seal.copy_into_slice(&mut proof);
+
+    let root = control_root.to_array();
+    let claim = claim.to_array();
+    let mut id = bn254_control_id.to_array();
+    id.reverse();
+    let inputs = [
+        half(env, &root[..16]),
+        half(env, &root[16..]),
+        half(env, &claim[..16]),
+        half(env, &claim[16..]),
+        Fr::from_bytes(BytesN::from_array(env, &id)),
+    ];
+
+    let bn254 = env.crypto().bn254();
+    let mut vk_x = g1(env, &IC[0]);
+    for (input, ic) in inputs.iter().zip(&IC[1..]) {
+        vk_x = bn254.g1_add(&vk_x, &bn254.g1_mul(&g1(env, ic), input));
+    }
+
+    // e(a, b) = e(alpha, beta) e(vk_x, gamma) e(c, delta)
+    let a = g1(env, &proof[..64]);
+    let b = g2(env, &proof[64..192]);
+    let c = g1(env, &proof[192..]);
+    bn254.pairing_check(
+        vec![env, -a, g1(env, &ALPHA), vk_x, c],
+        vec![env, b, g2(env, &BETA), g2(env, &GAMMA), g2(env, &DELTA)],
+    )
+}
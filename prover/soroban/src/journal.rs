@@ -0,0 +1,151 @@
+// Decoding the pong guest journal (`core::ValidateLogOutput`) on chain
+//
+// The contract settles proofs from the pong guest, whose journal is
+// `ValidateLogOutput` in risc0 serde: a sequence of little-endian u32 words,
+// fields in declaration order. `bool` and `u8` take a word each, an `Option`
+// is a 0/1 tag word followed by the value, a unit enum variant is its index, a
+// `String` is its byte length then the bytes padded to a word, and a `Vec` is
+// its length then the elements. The journal is read field by field and must be
+// consumed exactly; host/tests/soroban_test.rs checks this decoder against
+// `risc0_zkvm::serde::to_vec`. Only the fields the contract records are kept;
+// the rest are covered by the seal.
+
+use crate::{Error, Outcome};
+use soroban_sdk::Bytes;
+
+/// Domain separation prefix of the configuration digest (`core::CONFIG_DIGEST_PREFIX`)
+const CONFIG_DIGEST_PREFIX: &[u8; 9] = b"PONGCFGv1";
+
+/// Words a `core::Ruleset` occupies (`core::RULESET_WORDS`)
+///
+/// Its risc0 serde encoding is the same words, in the same order, as
+/// `Ruleset::to_words`.
+const RULESET_WORDS: u32 = 10;
+
+/// Bytes of `ValidateLogOutput::log_hash_sha256`, one word each
+const LOG_HASH_LEN: u32 = 32;
+
+/// The journal fields the contract needs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PongJournal {
+    pub fair: bool,
+    pub left_score: u32,
+    pub right_score: u32,
+    pub game_id: u32,
+    /// `core::config_digest` of the log version and rules the game was played under
+    pub config_digest: [u8; 32],
+    /// None if the journal was rejected or the match abandoned
+    pub outcome: Option<Outcome>,
+}
+
+/// Cursor over the journal's words
+struct Words<'a> {
+    journal: &'a Bytes,
+    offset: u32,
+}
+
+impl Words<'_> {
+    fn next(&mut self) -> Result<u32, Error> {
+        let end = self.offset.checked_add(4).ok_or(Error::MalformedJournal)?;
+        if end > self.journal.len() {
+            return Err(Error::MalformedJournal);
+        }
+        let mut word = [0u8; 4];
+        self.journal.slice(self.offset..end).copy_into_slice(&mut word);
+        self.offset = end;
+        Ok(u32::from_le_bytes(word))
+    }
+
+    fn skip(&mut self, words: u32) -> Result<(), Error> {
+        let end = words
+            .checked_mul(4)
+            .and_then(|len| self.offset.checked_add(len))
+            .ok_or(Error::MalformedJournal)?;
+        if end > self.journal.len() {
+            return Err(Error::MalformedJournal);
+        }
+        self.offset = end;
+        Ok(())
+    }
+
+    /// A `bool`, or the tag of an `Option`
+    fn flag(&mut self) -> Result<bool, Error> {
+        match self.next()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::MalformedJournal),
+        }
+    }
+
+    /// The index of a unit enum variant with `variants` variants
+    fn variant(&mut self, variants: u32) -> Result<u32, Error> {
+        let index = self.next()?;
+        if index >= variants {
+            return Err(Error::MalformedJournal);
+        }
+        Ok(index)
+    }
+}
+
+/// Decode a pong guest journal
+///
+/// `config_digest` is recomputed from the committed log version and ruleset.
+pub fn decode(journal: &Bytes) -> Result<PongJournal, Error> {
+    let env = journal.env();
+    let mut words = Words { journal, offset: 0 };
+
+    let fair = words.flag()?;
+    // reason: Option<String>
+    if words.flag()? {
+        let len = words.next()?;
+        words.skip(len.div_ceil(4))?;
+    }
+    let left_score = words.next()?;
+    let right_score = words.next()?;
+    // events_len, log_hash_sha256
+    words.skip(1 + LOG_HASH_LEN)?;
+    let game_id = words.next()?;
+
+    let mut config = Bytes::from_array(env, CONFIG_DIGEST_PREFIX);
+    config.extend_from_array(&words.next()?.to_be_bytes());
+    for _ in 0..RULESET_WORDS {
+        config.extend_from_array(&words.next()?.to_be_bytes());
+    }
+    let config_digest = env.crypto().sha256(&config).to_array();
+
+    // games: Vec<GameScore>, two words per game
+    let games = words.next()?;
+    words.skip(games.checked_mul(2).ok_or(Error::MalformedJournal)?)?;
+    // end_reason: Option<EndReason>
+    if words.flag()? {
+        words.variant(3)?;
+    }
+    let outcome = match words.flag()? {
+        false => None,
+        true => Some(match words.variant(3)? {
+            0 => Outcome::LeftWin,
+            1 => Outcome::RightWin,
+            _ => Outcome::Draw,
+        }),
+    };
+    // last_valid_pair: Option<u32>
+    if words.flag()? {
+        words.next()?;
+    }
+    // violator: Option<Violator>
+    if words.flag()? {
+        words.variant(3)?;
+    }
+    if words.offset != journal.len() {
+        return Err(Error::MalformedJournal);
+    }
+
+    Ok(PongJournal {
+        fair,
+        left_score,
+        right_score,
+        game_id,
+        config_digest,
+        outcome,
+    })
+}
@@ -0,0 +1,159 @@
+// Soroban contract settling pong results from Groth16 proofs
+//
+// The contract is deployed with the pong guest's image ID, the configuration
+// digest of the one log version and ruleset it settles, and the RISC Zero
+// Groth16 verifier parameters (control root and BN254 control ID) of the
+// release that built it. `submit` takes the seal and journal of a proof from
+// `generate_pong_proof(.., ReceiptKind::Groth16)`, checks the game was played
+// under that configuration and the seal proves that journal for that image ID,
+// and records the result under the journal's game ID. A game ID
+// can be recorded once, so a proof cannot be replayed.
+//
+// Game IDs are chosen by whoever records the game and are not bound to the
+// players, so the first valid proof for an ID claims it: anyone can prove a
+// game of their own under an ID first. The record therefore says which side
+// won that ID's game, not who played it. Callers that need the players must
+// assign IDs derived from them, as brackets and ladders do (`Bracket::game_id`,
+// `Leaderboard::game_id`), and check the ID before trusting a record.
+#![no_std]
+
+mod groth16;
+mod journal;
+mod verifying_key;
+
+pub use groth16::{claim_digest, SEAL_LEN};
+pub use journal::{decode as decode_journal, PongJournal};
+
+use soroban_sdk::{contract, contracterror, contractevent, contractimpl, contracttype, Bytes, BytesN, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// The journal is not a pong guest journal
+    MalformedJournal = 1,
+    /// The seal does not prove the journal for the pong image ID
+    InvalidProof = 2,
+    /// A result was already recorded for this game ID
+    GameAlreadyRecorded = 3,
+    /// The guest rejected the log
+    UnfairGame = 4,
+    /// The match has no result (abandoned)
+    NoResult = 5,
+    /// The game was played under a log version or rules this deployment does not settle
+    WrongConfig = 6,
+}
+
+/// How a match ended, as in the journal
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    LeftWin,
+    RightWin,
+    Draw,
+}
+
+/// A recorded result
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRecord {
+    pub outcome: Outcome,
+    /// Points, or games won in a best-of-N match
+    pub left_score: u32,
+    pub right_score: u32,
+}
+
+/// Verifier configuration, fixed at deployment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Pong guest image ID (the `[u32; 8]` words, little-endian)
+    pub image_id: BytesN<32>,
+    /// `core::config_digest(log_version, &rules)` of the games this deployment settles
+    pub config_digest: BytesN<32>,
+    /// `Groth16ReceiptVerifierParameters::control_root`
+    pub control_root: BytesN<32>,
+    /// `Groth16ReceiptVerifierParameters::bn254_control_id`
+    pub bn254_control_id: BytesN<32>,
+}
+
+#[contracttype]
+enum DataKey {
+    Config,
+    Game(u32),
+}
+
+/// Published when a result is recorded
+#[contractevent(topics = ["pong", "result"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultRecorded {
+    #[topic]
+    pub game_id: u32,
+    pub outcome: Outcome,
+    pub left_score: u32,
+    pub right_score: u32,
+}
+
+#[contract]
+pub struct PongContract;
+
+#[contractimpl]
+impl PongContract {
+    pub fn __constructor(env: Env, config: Config) {
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Verify a Groth16 pong proof and record its result
+    ///
+    /// `seal` is the 256-byte `Groth16Receipt::seal` and `journal` the
+    /// receipt's journal bytes (a full `ValidateLogOutput`, not a disclosed one). Only fair games with a result, played under
+    /// the deployment's configuration, are recorded; the first proof for a
+    /// game ID claims it.
+    pub fn submit(env: Env, seal: Bytes, journal: Bytes) -> Result<GameRecord, Error> {
+        let game = decode_journal(&journal)?;
+        let config = Self::config(env.clone());
+        if game.config_digest != config.config_digest.to_array() {
+            return Err(Error::WrongConfig);
+        }
+        let key = DataKey::Game(game.game_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::GameAlreadyRecorded);
+        }
+        if !game.fair {
+            return Err(Error::UnfairGame);
+        }
+        let outcome = game.outcome.ok_or(Error::NoResult)?;
+
+        let claim = claim_digest(&env, &config.image_id, &journal);
+        if !groth16::verify(&env, &seal, &claim, &config.control_root, &config.bn254_control_id) {
+            return Err(Error::InvalidProof);
+        }
+
+        let record = GameRecord {
+            outcome,
+            left_score: game.left_score,
+            right_score: game.right_score,
+        };
+        // Results are the point of the contract: keep them as long as the network allows
+        let ttl = env.storage().max_ttl();
+        env.storage().persistent().set(&key, &record);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        ResultRecorded {
+            game_id: game.game_id,
+            outcome,
+            left_score: record.left_score,
+            right_score: record.right_score,
+        }
+        .publish(&env);
+        Ok(record)
+    }
+
+    /// The recorded result of a game, if any
+    pub fn result(env: Env, game_id: u32) -> Option<GameRecord> {
+        env.storage().persistent().get(&DataKey::Game(game_id))
+    }
+
+    pub fn config(env: Env) -> Config {
+        env.storage().instance().get(&DataKey::Config).unwrap()
+    }
+}
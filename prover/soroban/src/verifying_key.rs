@@ -0,0 +1,92 @@
+// RISC Zero Groth16 verifying key (BN254), in the Soroban point encodings
+//
+// The same key as `risc0_groth16::verifying_key()` and RISC Zero's
+// Groth16Verifier.sol. G1 points are `x || y` and G2 points
+// `x.c1 || x.c0 || y.c1 || y.c0`, every coordinate 32 bytes big-endian.
+// The key only changes with a new trusted setup ceremony; the control root and
+// BN254 control ID, which change with every RISC Zero release, are contract
+// parameters instead.
+
+/// Alpha (G1)
+pub const ALPHA: [u8; 64] = [
+    0x2d, 0x4d, 0x9a, 0xa7, 0xe3, 0x02, 0xd9, 0xdf, 0x41, 0x74, 0x9d, 0x55, 0x07, 0x94, 0x9d, 0x05,
+    0xdb, 0xea, 0x33, 0xfb, 0xb1, 0x6c, 0x64, 0x3b, 0x22, 0xf5, 0x99, 0xa2, 0xbe, 0x6d, 0xf2, 0xe2,
+    0x14, 0xbe, 0xdd, 0x50, 0x3c, 0x37, 0xce, 0xb0, 0x61, 0xd8, 0xec, 0x60, 0x20, 0x9f, 0xe3, 0x45,
+    0xce, 0x89, 0x83, 0x0a, 0x19, 0x23, 0x03, 0x01, 0xf0, 0x76, 0xca, 0xff, 0x00, 0x4d, 0x19, 0x26,
+];
+
+/// Beta (G2)
+pub const BETA: [u8; 128] = [
+    0x09, 0x67, 0x03, 0x2f, 0xcb, 0xf7, 0x76, 0xd1, 0xaf, 0xc9, 0x85, 0xf8, 0x88, 0x77, 0xf1, 0x82,
+    0xd3, 0x84, 0x80, 0xa6, 0x53, 0xf2, 0xde, 0xca, 0xa9, 0x79, 0x4c, 0xbc, 0x3b, 0xf3, 0x06, 0x0c,
+    0x0e, 0x18, 0x78, 0x47, 0xad, 0x4c, 0x79, 0x83, 0x74, 0xd0, 0xd6, 0x73, 0x2b, 0xf5, 0x01, 0x84,
+    0x7d, 0xd6, 0x8b, 0xc0, 0xe0, 0x71, 0x24, 0x1e, 0x02, 0x13, 0xbc, 0x7f, 0xc1, 0x3d, 0xb7, 0xab,
+    0x30, 0x4c, 0xfb, 0xd1, 0xe0, 0x8a, 0x70, 0x4a, 0x99, 0xf5, 0xe8, 0x47, 0xd9, 0x3f, 0x8c, 0x3c,
+    0xaa, 0xfd, 0xde, 0xc4, 0x6b, 0x7a, 0x0d, 0x37, 0x9d, 0xa6, 0x9a, 0x4d, 0x11, 0x23, 0x46, 0xa7,
+    0x17, 0x39, 0xc1, 0xb1, 0xa4, 0x57, 0xa8, 0xc7, 0x31, 0x31, 0x23, 0xd2, 0x4d, 0x2f, 0x91, 0x92,
+    0xf8, 0x96, 0xb7, 0xc6, 0x3e, 0xea, 0x05, 0xa9, 0xd5, 0x7f, 0x06, 0x54, 0x7a, 0xd0, 0xce, 0xc8,
+];
+
+/// Gamma (G2)
+pub const GAMMA: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+/// Delta (G2)
+pub const DELTA: [u8; 128] = [
+    0x03, 0xb0, 0x3c, 0xd5, 0xef, 0xfa, 0x95, 0xac, 0x9b, 0xee, 0x94, 0xf1, 0xf5, 0xef, 0x90, 0x71,
+    0x57, 0xbd, 0xa4, 0x81, 0x2c, 0xcf, 0x0b, 0x4c, 0x91, 0xf4, 0x2b, 0xb6, 0x29, 0xf8, 0x3a, 0x1c,
+    0x1a, 0xa0, 0x85, 0xff, 0x28, 0x17, 0x9a, 0x12, 0xd9, 0x22, 0xdb, 0xa0, 0x54, 0x70, 0x57, 0xcc,
+    0xaa, 0xe9, 0x4b, 0x9d, 0x69, 0xcf, 0xaa, 0x4e, 0x60, 0x40, 0x1f, 0xea, 0x7f, 0x3e, 0x03, 0x33,
+    0x11, 0x0c, 0x10, 0x13, 0x4f, 0x20, 0x0b, 0x19, 0xf6, 0x49, 0x08, 0x46, 0xd5, 0x18, 0xc9, 0xae,
+    0xa8, 0x68, 0x36, 0x6e, 0xfb, 0x72, 0x28, 0xca, 0x5c, 0x91, 0xd2, 0x94, 0x0d, 0x03, 0x07, 0x62,
+    0x1e, 0x60, 0xf3, 0x1f, 0xcb, 0xf7, 0x57, 0xe8, 0x37, 0xe8, 0x67, 0x17, 0x83, 0x18, 0x83, 0x2d,
+    0x0b, 0x2d, 0x74, 0xd5, 0x9e, 0x2f, 0xea, 0x1c, 0x71, 0x42, 0xdf, 0x18, 0x7d, 0x3f, 0xc6, 0xd3,
+];
+
+/// Public input commitments (G1): the constant term, then one per public input
+pub const IC: [[u8; 64]; 6] = [
+    [
+        0x12, 0xac, 0x9a, 0x25, 0xdc, 0xd5, 0xe1, 0xa8, 0x32, 0xa9, 0x06, 0x1a, 0x08, 0x2c, 0x15, 0xdd,
+        0x1d, 0x61, 0xaa, 0x9c, 0x4d, 0x55, 0x35, 0x05, 0x73, 0x9d, 0x0f, 0x5d, 0x65, 0xdc, 0x3b, 0xe4,
+        0x02, 0x5a, 0xa7, 0x44, 0x58, 0x1e, 0xbe, 0x7a, 0xd9, 0x17, 0x31, 0x91, 0x1c, 0x89, 0x85, 0x69,
+        0x10, 0x6f, 0xf5, 0xa2, 0xd3, 0x0f, 0x3e, 0xee, 0x2b, 0x23, 0xc6, 0x0e, 0xe9, 0x80, 0xac, 0xd4,
+    ],
+    [
+        0x07, 0x07, 0xb9, 0x20, 0xbc, 0x97, 0x8c, 0x02, 0xf2, 0x92, 0xfa, 0xe2, 0x03, 0x6e, 0x05, 0x7b,
+        0xe5, 0x42, 0x94, 0x11, 0x4c, 0xcc, 0x3c, 0x87, 0x69, 0xd8, 0x83, 0xf6, 0x88, 0xa1, 0x42, 0x3f,
+        0x2e, 0x32, 0xa0, 0x94, 0xb7, 0x58, 0x95, 0x54, 0xf7, 0xbc, 0x35, 0x7b, 0xf6, 0x34, 0x81, 0xac,
+        0xd2, 0xd5, 0x55, 0x55, 0xc2, 0x03, 0x38, 0x37, 0x82, 0xa4, 0x65, 0x07, 0x87, 0xff, 0x66, 0x42,
+    ],
+    [
+        0x0b, 0xca, 0x36, 0xe2, 0xcb, 0xe6, 0x39, 0x4b, 0x3e, 0x24, 0x97, 0x51, 0x85, 0x3f, 0x96, 0x15,
+        0x11, 0x01, 0x1c, 0x71, 0x48, 0xe3, 0x36, 0xf4, 0xfd, 0x97, 0x46, 0x44, 0x85, 0x0f, 0xc3, 0x47,
+        0x2e, 0xde, 0x7c, 0x9a, 0xcf, 0x48, 0xcf, 0x3a, 0x37, 0x29, 0xfa, 0x3d, 0x68, 0x71, 0x4e, 0x2a,
+        0x84, 0x35, 0xd4, 0xfa, 0x6d, 0xb8, 0xf7, 0xf4, 0x09, 0xc1, 0x53, 0xb1, 0xfc, 0xdf, 0x9b, 0x8b,
+    ],
+    [
+        0x1b, 0x8a, 0xf9, 0x99, 0xdb, 0xfb, 0xb3, 0x92, 0x7c, 0x09, 0x1c, 0xc2, 0xaa, 0xf2, 0x01, 0xe4,
+        0x88, 0xcb, 0xac, 0xc3, 0xe2, 0xc6, 0xb6, 0xfb, 0x5a, 0x25, 0xf9, 0x11, 0x2e, 0x04, 0xf2, 0xa7,
+        0x2b, 0x91, 0xa2, 0x6a, 0xa9, 0x2e, 0x1b, 0x6f, 0x57, 0x22, 0x94, 0x9f, 0x19, 0x2a, 0x81, 0xc8,
+        0x50, 0xd5, 0x86, 0xd8, 0x1a, 0x60, 0x15, 0x7f, 0x3e, 0x9c, 0xf0, 0x4f, 0x67, 0x9c, 0xcc, 0xd6,
+    ],
+    [
+        0x2b, 0x5f, 0x49, 0x4e, 0xd6, 0x74, 0x23, 0x5b, 0x8a, 0xc1, 0x75, 0x0b, 0xdf, 0xd5, 0xa7, 0x61,
+        0x5f, 0x00, 0x2d, 0x4a, 0x1d, 0xce, 0xfe, 0xdd, 0xd0, 0x6e, 0xda, 0x5a, 0x07, 0x6c, 0xcd, 0x0d,
+        0x2f, 0xe5, 0x20, 0xad, 0x20, 0x20, 0xaa, 0xb9, 0xcb, 0xba, 0x81, 0x7f, 0xcb, 0xb9, 0xa8, 0x63,
+        0xb8, 0xa7, 0x6f, 0xf8, 0x8f, 0x14, 0xf9, 0x12, 0xc5, 0xe7, 0x16, 0x65, 0xb2, 0xad, 0x5e, 0x82,
+    ],
+    [
+        0x0f, 0x1c, 0x3c, 0x0d, 0x5d, 0x9d, 0xa0, 0xfa, 0x03, 0x66, 0x68, 0x43, 0xcd, 0xe4, 0xe8, 0x2e,
+        0x86, 0x9b, 0xa5, 0x25, 0x2f, 0xce, 0x3c, 0x25, 0xd5, 0x94, 0x03, 0x20, 0xb1, 0xc4, 0xd4, 0x93,
+        0x21, 0x4b, 0xfc, 0xff, 0x74, 0xf4, 0x25, 0xf6, 0xfe, 0x8c, 0x0d, 0x07, 0xb3, 0x07, 0x48, 0x2d,
+        0x8b, 0xc8, 0xbb, 0x2f, 0x36, 0x08, 0xf6, 0x82, 0x87, 0xaa, 0x01, 0xbd, 0x0b, 0x69, 0xe8, 0x09,
+    ],
+];
@@ -0,0 +1,13 @@
+// Image IDs of the pong guest (`methods/guest`) and the segment-join guest
+// (`join-methods/guest`).
+//
+// Taken from the guests as they are built, so the current release always
+// matches the guests the prover was built with. Past releases are written
+// into `GUEST_REGISTRY` as literals; `pong-prover image-id` prints the IDs to
+// record when a release is cut.
+
+/// Image ID of the pong guest program
+pub const PONG_GUEST_ID: [u32; 8] = methods::GUEST_CODE_FOR_ZK_PROOF_ID;
+
+/// Image ID of the segment-join guest program
+pub const PONG_JOIN_ID: [u32; 8] = join_methods::JOIN_SEGMENTS_ID;
@@ -0,0 +1,83 @@
+// Journal layouts of past guest releases
+//
+// The full-log guest commits `ValidateLogOutput` with risc0 serde, which is
+// not self-describing: a field added to the output (or to the ruleset inside
+// it) changes the layout, and a journal is only readable as the layout of the
+// release that committed it. Each release in the registry names its layout,
+// and `JournalLayout::decode` reads it and fills in the fields it lacks the
+// same way `PongProof` fills them for proof files written before they existed.
+
+use core::{DisclosedOutput, Ruleset, ValidateLogOutput};
+use risc0_zkvm::Journal;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Layout of the journal a guest release commits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JournalLayout {
+    /// Result, score, event count, log hash and game ID (the baseline guest)
+    V1,
+    /// `ValidateLogOutput` as it is today; partial disclosure journals
+    V2,
+}
+
+impl JournalLayout {
+    /// Layout committed by the guest built from this tree (`ValidateLogOutput`)
+    pub const CURRENT: JournalLayout = JournalLayout::V2;
+
+    /// Decode a full journal committed in this layout
+    pub fn decode(self, journal: &Journal) -> Result<ValidateLogOutput, Box<dyn std::error::Error>> {
+        Ok(match self {
+            JournalLayout::V1 => read::<JournalV1>(journal)?.into(),
+            JournalLayout::V2 => read::<ValidateLogOutput>(journal)?,
+        })
+    }
+
+    /// Decode a partial disclosure journal committed in this layout
+    pub fn decode_disclosed(self, journal: &Journal) -> Result<DisclosedOutput, Box<dyn std::error::Error>> {
+        match self {
+            JournalLayout::V2 => read::<DisclosedOutput>(journal),
+            _ => Err(format!("Journal layout {:?} has no partial disclosure", self).into()),
+        }
+    }
+}
+
+fn read<T: DeserializeOwned>(journal: &Journal) -> Result<T, Box<dyn std::error::Error>> {
+    journal
+        .decode()
+        .map_err(|e| format!("Failed to decode journal: {}", e).into())
+}
+
+/// Layout V1 (guest version 1)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct JournalV1 {
+    pub fair: bool,
+    pub reason: Option<String>,
+    pub left_score: u32,
+    pub right_score: u32,
+    pub events_len: u32,
+    pub log_hash_sha256: [u8; 32],
+    pub game_id: u32,
+}
+
+impl From<JournalV1> for ValidateLogOutput {
+    fn from(v: JournalV1) -> Self {
+        ValidateLogOutput {
+            fair: v.fair,
+            reason: v.reason,
+            left_score: v.left_score,
+            right_score: v.right_score,
+            events_len: v.events_len,
+            log_hash_sha256: v.log_hash_sha256,
+            game_id: v.game_id,
+            // Only v1 logs were replayed before the version was committed
+            log_version: 1,
+            rules: Ruleset::STANDARD,
+            games: Vec::new(),
+            end_reason: None,
+            outcome: None,
+            last_valid_pair: None,
+            violator: None,
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Lightweight verification of pong proofs.
+//!
+//! Depends only on the verify side of `risc0-zkvm`, so game servers and bots
+//! can check `PongProof`s and saved proof files without pulling in the
+//! proving stack. The guests are built (not run) for their image IDs.
+
+use core::{EndReason, GameScore, Outcome, Ruleset, ValidateLogOutput, Violator};
+use risc0_zkvm::Receipt;
+use serde::{Deserialize, Serialize};
+
+mod image_id;
+pub mod journal;
+pub mod registry;
+
+pub use image_id::{PONG_GUEST_ID, PONG_JOIN_ID};
+pub use journal::JournalLayout;
+pub use registry::{GuestRelease, CURRENT_GUEST_VERSION, GUEST_REGISTRY};
+
+/// Receipt type for proof generation
+///
+/// Variants are ordered from least to most compressed, so `a < b` means a
+/// receipt of kind `a` can be compressed into kind `b`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum ReceiptKind {
+    /// Composite receipt - fastest to generate, largest size (multiple MB)
+    Composite,
+    /// Succinct receipt - STARK proof, medium size (~200 KB)
+    #[default]
+    Succinct,
+    /// Groth16 receipt - SNARK proof, smallest size (~200-300 bytes)
+    Groth16,
+}
+
+impl std::str::FromStr for ReceiptKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "composite" => Ok(ReceiptKind::Composite),
+            "succinct" => Ok(ReceiptKind::Succinct),
+            "groth16" => Ok(ReceiptKind::Groth16),
+            _ => Err(format!(
+                "Invalid receipt kind: '{}'. Must be 'composite', 'succinct', or 'groth16'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ReceiptKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReceiptKind::Composite => write!(f, "composite"),
+            ReceiptKind::Succinct => write!(f, "succinct"),
+            ReceiptKind::Groth16 => write!(f, "groth16"),
+        }
+    }
+}
+
+/// Output from pong game validation proof
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PongProof {
+    /// The cryptographic receipt (ZK proof)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated
+    pub receipt_kind: ReceiptKind,
+
+    /// Game result: left player score
+    pub left_score: u32,
+
+    /// Game result: right player score
+    pub right_score: u32,
+
+    /// SHA-256 hash of game log
+    pub log_hash: [u8; 32],
+
+    /// Number of events processed
+    pub events_len: u32,
+
+    /// Whether the game was fair
+    pub fair: bool,
+
+    /// Error reason if unfair
+    pub reason: Option<String>,
+
+    /// Game ID - for replay protection
+    pub game_id: u32,
+
+    /// Version of the guest that produced the receipt (see [`registry`])
+    #[serde(default = "legacy_guest_version")]
+    pub guest_version: u32,
+
+    /// Log version the game was replayed under (0 if rejected before replay)
+    #[serde(default = "legacy_log_version")]
+    pub log_version: u32,
+
+    /// Rules the game was validated under (proofs without the field used the standard rules)
+    #[serde(default)]
+    pub rules: Ruleset,
+
+    /// Score of each game in the match (empty in proofs written before it was recorded)
+    #[serde(default)]
+    pub games: Vec<GameScore>,
+
+    /// Why the match ended (score, clock or abandoned), its outcome, and for
+    /// an abandoned match the last event pair of its valid prefix and whose
+    /// violation ended it
+    #[serde(default)]
+    pub end_reason: Option<EndReason>,
+    #[serde(default)]
+    pub outcome: Option<Outcome>,
+    #[serde(default)]
+    pub last_valid_pair: Option<u32>,
+    #[serde(default)]
+    pub violator: Option<Violator>,
+}
+
+/// Proofs written before guest versions were recorded all came from version 1
+fn legacy_guest_version() -> u32 {
+    1
+}
+
+/// Proofs written before log versions were recorded all replayed v1 logs
+fn legacy_log_version() -> u32 {
+    1
+}
+
+/// Saved proof file format
+///
+/// Wraps a [`PongProof`] with flattened metadata for display, as written by
+/// the `pong-prover` CLI.
+#[derive(Serialize, Deserialize)]
+pub struct SavedProof {
+    /// The complete proof with receipt
+    pub proof: PongProof,
+    /// Metadata for display
+    pub left_score: u32,
+    pub right_score: u32,
+    pub log_hash: String,
+    pub events_len: u32,
+    pub fair: bool,
+    pub reason: Option<String>,
+    pub game_id: u32,
+    pub receipt_kind: String,
+    pub receipt_size_bytes: usize,
+    #[serde(default = "legacy_guest_version")]
+    pub guest_version: u32,
+    #[serde(default = "legacy_log_version")]
+    pub log_version: u32,
+    #[serde(default)]
+    pub rules: Ruleset,
+    #[serde(default)]
+    pub games: Vec<GameScore>,
+    #[serde(default)]
+    pub end_reason: Option<EndReason>,
+    #[serde(default)]
+    pub outcome: Option<Outcome>,
+    #[serde(default)]
+    pub last_valid_pair: Option<u32>,
+    #[serde(default)]
+    pub violator: Option<Violator>,
+}
+
+impl SavedProof {
+    /// Wrap a proof, deriving the display metadata from it
+    pub fn new(proof: PongProof) -> Result<Self, Box<dyn std::error::Error>> {
+        let receipt_bytes = bincode::serialize(&proof.receipt)?;
+        Ok(Self {
+            left_score: proof.left_score,
+            right_score: proof.right_score,
+            log_hash: hex::encode(proof.log_hash),
+            events_len: proof.events_len,
+            fair: proof.fair,
+            reason: proof.reason.clone(),
+            game_id: proof.game_id,
+            receipt_kind: proof.receipt_kind.to_string(),
+            receipt_size_bytes: receipt_bytes.len(),
+            guest_version: proof.guest_version,
+            log_version: proof.log_version,
+            rules: proof.rules,
+            games: proof.games.clone(),
+            end_reason: proof.end_reason,
+            outcome: proof.outcome,
+            last_valid_pair: proof.last_valid_pair,
+            violator: proof.violator,
+            proof,
+        })
+    }
+}
+
+/// Write a saved proof to `path` as pretty-printed JSON
+pub fn save_proof(proof: &SavedProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_string_pretty(proof)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Read a saved proof from a JSON file at `path`
+pub fn load_proof(path: &str) -> Result<SavedProof, Box<dyn std::error::Error>> {
+    let json = std::fs::read_to_string(path)?;
+    let proof: SavedProof = serde_json::from_str(&json)?;
+    Ok(proof)
+}
+
+/// Decode the public `ValidateLogOutput` committed in a receipt's journal by `release`.
+///
+/// Journals of older releases are read in their own layout (see [`journal`]).
+/// This does not verify the receipt; the result can only be trusted after
+/// [`verify_receipt`] succeeds.
+pub fn decode_journal(
+    receipt: &Receipt,
+    release: &GuestRelease,
+) -> Result<ValidateLogOutput, Box<dyn std::error::Error>> {
+    release.journal.decode(&receipt.journal)
+}
+
+/// Verify a receipt against any registered, non-revoked guest release and decode its journal.
+///
+/// # Returns
+/// * `Ok((GuestRelease, ValidateLogOutput))` - The release that produced the receipt and the verified game result
+/// * `Err` - If the receipt is invalid or was not produced by a registered guest
+pub fn verify_receipt(
+    receipt: &Receipt,
+) -> Result<(GuestRelease, ValidateLogOutput), Box<dyn std::error::Error>> {
+    let release = registry::verify_registered(GUEST_REGISTRY, receipt)?;
+    Ok((*release, decode_journal(receipt, release)?))
+}
+
+/// Verify a `PongProof` against [`GUEST_REGISTRY`] and check that its result fields match the journal.
+///
+/// The fields on `PongProof` are convenience copies; a proof whose copies
+/// disagree with the verified journal is rejected rather than trusted.
+pub fn verify(proof: &PongProof) -> Result<ValidateLogOutput, Box<dyn std::error::Error>> {
+    verify_with_registry(proof, GUEST_REGISTRY)
+}
+
+/// Like [`verify`], but against a caller supplied list of guest releases.
+pub fn verify_with_registry(
+    proof: &PongProof,
+    registry: &[GuestRelease],
+) -> Result<ValidateLogOutput, Box<dyn std::error::Error>> {
+    let release = registry::verify_registered(registry, &proof.receipt)?;
+    if proof.guest_version != release.version {
+        return Err(format!(
+            "Proof claims guest version {} but was produced by version {}",
+            proof.guest_version, release.version
+        )
+        .into());
+    }
+
+    let out = decode_journal(&proof.receipt, release)?;
+
+    if proof.left_score != out.left_score
+        || proof.right_score != out.right_score
+        || proof.log_hash != out.log_hash_sha256
+        || proof.events_len != out.events_len
+        || proof.fair != out.fair
+        || proof.reason != out.reason
+        || proof.game_id != out.game_id
+        || proof.log_version != out.log_version
+        || proof.rules != out.rules
+        || proof.games != out.games
+        || proof.end_reason != out.end_reason
+        || proof.outcome != out.outcome
+        || proof.last_valid_pair != out.last_valid_pair
+        || proof.violator != out.violator
+    {
+        return Err("Proof metadata does not match the verified journal".into());
+    }
+    if out.fair && !release.supports_log_version(out.log_version) {
+        return Err(format!(
+            "Guest version {} does not support log version {}",
+            release.version, out.log_version
+        )
+        .into());
+    }
+
+    Ok(out)
+}
+
+/// Verify a saved proof file's proof and check its display metadata against the journal.
+pub fn verify_saved_proof(saved: &SavedProof) -> Result<ValidateLogOutput, Box<dyn std::error::Error>> {
+    let out = verify(&saved.proof)?;
+
+    if saved.left_score != out.left_score
+        || saved.right_score != out.right_score
+        || saved.log_hash != hex::encode(out.log_hash_sha256)
+        || saved.events_len != out.events_len
+        || saved.fair != out.fair
+        || saved.reason != out.reason
+        || saved.game_id != out.game_id
+        || saved.log_version != out.log_version
+        || saved.rules != out.rules
+        || saved.games != out.games
+        || saved.end_reason != out.end_reason
+        || saved.outcome != out.outcome
+        || saved.last_valid_pair != out.last_valid_pair
+        || saved.violator != out.violator
+        || saved.guest_version != saved.proof.guest_version
+    {
+        return Err("Saved proof metadata does not match the verified journal".into());
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,146 @@
+use crate::journal::JournalLayout;
+use crate::{PONG_GUEST_ID, PONG_JOIN_ID};
+use risc0_zkvm::sha::{Digest, Digestible};
+use risc0_zkvm::Receipt;
+
+/// Version of the guest built from this tree
+///
+/// Bump this (and add the previous release to [`GUEST_REGISTRY`] with its
+/// image ID) whenever a guest change is shipped, including changes to its
+/// input or journal.
+pub const CURRENT_GUEST_VERSION: u32 = 2;
+
+/// Image ID of a release that was not written down when it shipped
+///
+/// The baseline guest (version 1) predates recording image IDs here. Build
+/// the baseline commit and write `methods`' `GUEST_CODE_FOR_ZK_PROOF_ID` into
+/// its entry; until then no receipt matches it.
+pub const UNRECORDED_IMAGE_ID: [u32; 8] = [0; 8];
+
+/// A released build of the pong guest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuestRelease {
+    /// Guest version, recorded in every `PongProof` it produced
+    pub version: u32,
+    /// Image ID of the guest program
+    pub image_id: [u32; 8],
+    /// Image ID of the segment-join guest built alongside it, whose receipts commit the same journal
+    pub join_image_id: Option<[u32; 8]>,
+    /// Compact log versions this guest accepts
+    pub log_versions: &'static [u32],
+    /// Layout of the journal it commits
+    pub journal: JournalLayout,
+    /// Human readable summary of the game rules the guest enforces
+    pub rules: &'static str,
+    /// Revoked releases (e.g. a physics bug that allowed unfair games) no longer verify
+    pub revoked: bool,
+}
+
+impl GuestRelease {
+    /// Whether this release accepts compact logs of version `v`
+    pub fn supports_log_version(&self, v: u32) -> bool {
+        self.log_versions.contains(&v)
+    }
+
+    /// Whether `image_id` is this release's full-log guest or its join guest
+    ///
+    /// An [`UNRECORDED_IMAGE_ID`] matches nothing.
+    pub fn has_image_id(&self, image_id: Digest) -> bool {
+        let matches = |id: [u32; 8]| id != UNRECORDED_IMAGE_ID && Digest::from(id) == image_id;
+        matches(self.image_id) || self.join_image_id.is_some_and(matches)
+    }
+}
+
+/// Every guest release whose proofs may still be in circulation, oldest first
+///
+/// Entries are never removed; a release with a known flaw is marked `revoked`
+/// instead so its proofs are rejected with a clear error. A release is cut for
+/// every shipped guest change, so each entry's `rules` describes the game as
+/// of that release.
+pub const GUEST_REGISTRY: &[GuestRelease] = &[
+    // Baseline: serde input, log v1 only
+    GuestRelease {
+        version: 1,
+        image_id: UNRECORDED_IMAGE_ID,
+        join_image_id: None,
+        log_versions: &[1],
+        rules: "first to 3 points; CORDIC trig, face collision",
+        journal: JournalLayout::V1,
+        revoked: false,
+    },
+    // Guest built from this tree
+    GuestRelease {
+        version: CURRENT_GUEST_VERSION,
+        image_id: PONG_GUEST_ID,
+        join_image_id: Some(PONG_JOIN_ID),
+        log_versions: &[1, 2],
+        rules: "scoring (incl. clock, draws, forfeits and golden point), paddle limits and spin per ruleset; log v1: CORDIC trig, face collision, overflowing rallies rejected; log v2: lookup-table trig, swept paddle collision with edges, ball speed capped",
+        journal: JournalLayout::CURRENT,
+        revoked: false,
+    },
+];
+
+/// Versions of the releases in `registry` whose image IDs are not written down
+pub fn unrecorded_versions(registry: &[GuestRelease]) -> Vec<u32> {
+    registry
+        .iter()
+        .filter(|r| r.image_id == UNRECORDED_IMAGE_ID || r.join_image_id == Some(UNRECORDED_IMAGE_ID))
+        .map(|r| r.version)
+        .collect()
+}
+
+/// Look up a release by guest version
+pub fn release(registry: &[GuestRelease], version: u32) -> Option<&GuestRelease> {
+    registry.iter().find(|r| r.version == version)
+}
+
+/// Look up a release by image ID (of either its full-log or its join guest)
+pub fn release_for_image_id(registry: &[GuestRelease], image_id: Digest) -> Option<&GuestRelease> {
+    registry.iter().find(|r| r.has_image_id(image_id))
+}
+
+/// Image ID of the guest that produced `receipt`, as stated in its claim
+///
+/// The claim is not trusted until the receipt has been verified against it.
+pub fn claimed_image_id(receipt: &Receipt) -> Result<Digest, Box<dyn std::error::Error>> {
+    let claim = receipt
+        .claim()
+        .map_err(|e| format!("Failed to read receipt claim: {}", e))?;
+    let value = claim
+        .as_value()
+        .map_err(|_| "Receipt claim is pruned; cannot determine image ID")?;
+    Ok(value.pre.digest())
+}
+
+/// Verify a receipt against whichever registered, non-revoked release produced it.
+///
+/// # Returns
+/// * `Ok(GuestRelease)` - The release whose image ID the receipt verified against
+/// * `Err` - If the image ID is unknown, the release is revoked, or verification fails
+pub fn verify_registered<'a>(
+    registry: &'a [GuestRelease],
+    receipt: &Receipt,
+) -> Result<&'a GuestRelease, Box<dyn std::error::Error>> {
+    let image_id = claimed_image_id(receipt)?;
+    let release = release_for_image_id(registry, image_id).ok_or_else(|| {
+        let unrecorded = unrecorded_versions(registry);
+        if unrecorded.is_empty() {
+            format!("Receipt was produced by an unregistered guest (image ID {})", image_id)
+        } else {
+            format!(
+                "Receipt was produced by an unregistered guest (image ID {}); guest versions {:?} have no recorded image ID",
+                image_id, unrecorded
+            )
+        }
+    })?;
+
+    if release.revoked {
+        return Err(format!("Guest version {} has been revoked", release.version).into());
+    }
+
+    receipt
+        .verify(image_id)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    Ok(release)
+}
@@ -29,6 +29,13 @@ export const SERVE_SPEED = 500
 /** Speed increment per bounce (pixels/second) */
 export const SPEED_INCREMENT = 50
 
+/**
+ * Fastest ball a v1 log may reach (pixels/second). v1 does not cap the ball
+ * speed; a return that would take it past this limit leaves the prover's
+ * fixed-point range, so the log is rejected instead.
+ */
+export const UNCAPPED_BALL_SPEED_LIMIT = 32443
+
 /** Maximum bounce angle off paddle (degrees) */
 export const MAX_BOUNCE_ANGLE_DEG = 60
 
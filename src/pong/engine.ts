@@ -30,6 +30,7 @@ import {
   PADDLE_MAX_SPEED,
   SERVE_SPEED,
   SPEED_INCREMENT,
+  UNCAPPED_BALL_SPEED_LIMIT,
   MAX_BOUNCE_ANGLE_DEG,
   POINTS_TO_WIN,
   INITIAL_SERVE_DIRECTION,
@@ -785,6 +786,7 @@ export function validateLog(log: CompactLog): ValidateResult {
     const paddleMarginI = toFixed(PADDLE_MARGIN)
     const serveSpeedI = toFixed(SERVE_SPEED)
     const speedIncrementI = toFixed(SPEED_INCREMENT)
+    const ballSpeedLimitI = toFixed(UNCAPPED_BALL_SPEED_LIMIT)
     const maxBounceAngleI = degToRadFixed(MAX_BOUNCE_ANGLE_DEG)
 
     const yMinI = ballRadiusI
@@ -920,6 +922,11 @@ export function validateLog(log: CompactLog): ValidateResult {
       state.leftY = loggedLI
       state.rightY = loggedRI
 
+      // v1 does not cap the ball speed: a return past the prover's fixed-point limit is rejected
+      if (hit && iAdd(state.speed, speedIncrementI) > ballSpeedLimitI) {
+        return { fair: false, reason: 'Ball too fast - the rally outran the fixed-point range', leftScore, rightScore }
+      }
+
       if (hit) {
         const contactYI = movingLeft ? loggedLI : loggedRI
         const halfI3 = iDiv(paddleHeightI, toFixed(2))